@@ -65,6 +65,7 @@ const TEST_COMMANDS: Record<string, string> = {
   rust: [
     'cargo nextest run -p statsig-rust --features testing --retries=5',
     'cargo nextest run -p statsig-rust --features "with_zstd,testing" --retries=5',
+    'cargo nextest run -p statsig-rust --features "with_grpc,testing" --retries=5 --test grpc_specs_adapter_tests --test forward_proxy_server_tests',
   ].join(' && '),
 
   go: [
@@ -7,8 +7,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 fn create_user() -> StatsigUser {
-    StatsigUser {
-      data: Arc::new(UserData {
+    StatsigUser::new(UserData {
         user_id: Some(dyn_value!("a_user")),
         email: Some(dyn_value!("daniel@statsig.com")),
         ip: Some(dyn_value!("127.0.0.1")),
@@ -28,8 +27,7 @@ fn create_user() -> StatsigUser {
             "test_private_attribute".to_string(),
             dyn_value!("test_private_attribute_value"),
         )])),
-      })
-    }
+    })
 }
 
 async fn setup() -> (StatsigUser, Statsig) {
@@ -61,7 +59,7 @@ fn all_gate_checks() {
             .data
             .try_read()
             .expect("Failed to lock spec store data")
-            .values
+            .values()
             .feature_gates
             .keys()
             .map(|s| s.to_string())
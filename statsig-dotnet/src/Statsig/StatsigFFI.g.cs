@@ -127,9 +127,18 @@ namespace Statsig
         [DllImport(__DllName, EntryPoint = "statsig_get_experiment", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
         internal static extern byte* statsig_get_experiment(ulong statsig_ref, ulong user_ref, byte* experiment_name, byte* options_json);
 
+        [DllImport(__DllName, EntryPoint = "statsig_get_experiment_by_group_name", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern byte* statsig_get_experiment_by_group_name(ulong statsig_ref, byte* experiment_name, byte* group_name);
+
         [DllImport(__DllName, EntryPoint = "statsig_manually_log_experiment_exposure", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
         internal static extern void statsig_manually_log_experiment_exposure(ulong statsig_ref, ulong user_ref, byte* experiment_name);
 
+        [DllImport(__DllName, EntryPoint = "statsig_get_cmab_ranked_groups", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern byte* statsig_get_cmab_ranked_groups(ulong statsig_ref, ulong user_ref, byte* cmab_name);
+
+        [DllImport(__DllName, EntryPoint = "statsig_log_cmab_exposure_for_group", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
+        internal static extern void statsig_log_cmab_exposure_for_group(ulong statsig_ref, ulong user_ref, byte* cmab_name, byte* group_id);
+
         [DllImport(__DllName, EntryPoint = "statsig_get_layer", CallingConvention = CallingConvention.Cdecl, ExactSpelling = true)]
         internal static extern byte* statsig_get_layer(ulong statsig_ref, ulong user_ref, byte* layer_name, byte* options_json);
 
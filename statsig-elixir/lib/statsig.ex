@@ -3,6 +3,18 @@ defmodule Statsig do
 
   alias Statsig.NativeBindings
 
+  # Evaluation NIFs that run on a dirty IO scheduler, used when an adapter can block evaluations
+  @dirty_io_evaluations %{
+    check_gate: :check_gate_dirty_io,
+    get_feature_gate: :get_feature_gate_dirty_io,
+    get_dynamic_config: :get_dynamic_config_dirty_io,
+    get_experiment: :get_experiment_dirty_io,
+    get_cmab_ranked_groups: :get_cmab_ranked_groups_dirty_io,
+    get_layer: :get_layer_dirty_io,
+    get_prompt: :get_prompt_dirty_io,
+    get_client_init_response_as_string: :get_client_init_response_as_string_dirty_io
+  }
+
   def start_link(sdk_key, options) do
     GenServer.start_link(__MODULE__, {sdk_key, options}, name: __MODULE__)
   end
@@ -10,7 +22,7 @@ defmodule Statsig do
   def init({sdk_key, statsig_options}) do
     try do
       instance = NativeBindings.new(sdk_key, statsig_options,get_system_info())
-      {:ok, instance}
+      {:ok, %{instance: instance, blocking_adapters: has_blocking_adapters?(statsig_options)}}
     rescue
       exception -> {:error, Exception.message(exception)}
     catch
@@ -20,6 +32,10 @@ defmodule Statsig do
   end
 
   def handle_call(:get_instance, _from, state) do
+    {:reply, state.instance, state}
+  end
+
+  def handle_call(:get_state, _from, state) do
     {:reply, state, state}
   end
 
@@ -41,9 +57,7 @@ defmodule Statsig do
 
   def check_gate(gate_name, statsig_user, options \\nil) do
     try do
-      instance = get_statsig_instance()
-
-      {:ok, NativeBindings.check_gate(instance, gate_name, statsig_user, options)}
+      {:ok, evaluate(:check_gate, [gate_name, statsig_user, options])}
     rescue
       exception -> {:error, Exception.message(exception)}
     catch
@@ -54,9 +68,7 @@ defmodule Statsig do
 
   def get_feature_gate(gate_name, statsig_user, options \\nil) do
     try do
-      instance = get_statsig_instance()
-
-      case NativeBindings.get_feature_gate(instance, gate_name, statsig_user, options) do
+      case evaluate(:get_feature_gate, [gate_name, statsig_user, options]) do
         {:error, e} -> {:error, e}
         gate -> {:ok, gate}
       end
@@ -70,9 +82,7 @@ defmodule Statsig do
 
   def get_dynamic_config(config_name, statsig_user, options \\nil) do
     try do
-      instance = get_statsig_instance()
-
-      case NativeBindings.get_dynamic_config(instance, config_name, statsig_user, options) do
+      case evaluate(:get_dynamic_config, [config_name, statsig_user, options]) do
         {:error, e} -> {:error, e}
         config -> {:ok, config}
       end
@@ -85,10 +95,26 @@ defmodule Statsig do
   end
 
   def get_experiment(experiment_name, statsig_user, options \\nil) do
+    try do
+      options = encode_user_persisted_values(options)
+
+      case evaluate(:get_experiment, [experiment_name, statsig_user, options]) do
+        {:error, e} -> {:error, e}
+        exp -> {:ok, exp}
+      end
+    rescue
+      exception -> {:error, Exception.message(exception)}
+    catch
+      :exit, reason -> {:error, {:exit, reason}}
+      exception -> {:error, Exception.message(exception)}
+    end
+  end
+
+  def get_experiment_by_group_name(experiment_name, group_name) do
     try do
       instance = get_statsig_instance()
 
-      case NativeBindings.get_experiment(instance, experiment_name, statsig_user, options) do
+      case NativeBindings.get_experiment_by_group_name(instance, experiment_name, group_name) do
         {:error, e} -> {:error, e}
         exp -> {:ok, exp}
       end
@@ -100,11 +126,37 @@ defmodule Statsig do
     end
   end
 
-  def get_layer(layer_name, statsig_user, options \\nil) do
+  def get_cmab_ranked_groups(cmab_name, statsig_user) do
+    try do
+      case evaluate(:get_cmab_ranked_groups, [cmab_name, statsig_user]) do
+        {:error, e} -> {:error, e}
+        groups -> {:ok, groups}
+      end
+    rescue
+      exception -> {:error, Exception.message(exception)}
+    catch
+      :exit, reason -> {:error, {:exit, reason}}
+      exception -> {:error, Exception.message(exception)}
+    end
+  end
+
+  def log_cmab_exposure_for_group(cmab_name, statsig_user, group_id) do
     try do
       instance = get_statsig_instance()
+      NativeBindings.log_cmab_exposure_for_group(instance, cmab_name, statsig_user, group_id)
+    rescue
+      exception -> {:error, Exception.message(exception)}
+    catch
+      :exit, reason -> {:error, {:exit, reason}}
+      exception -> {:error, Exception.message(exception)}
+    end
+  end
+
+  def get_layer(layer_name, statsig_user, options \\nil) do
+    try do
+      options = encode_user_persisted_values(options)
 
-      case NativeBindings.get_layer(instance, layer_name, statsig_user, options) do
+      case evaluate(:get_layer, [layer_name, statsig_user, options]) do
         {:error, e} -> {:error, e}
         layer -> {:ok, layer}
       end
@@ -118,8 +170,7 @@ defmodule Statsig do
 
   def get_prompt(prompt_name, statsig_user, options \\nil) do
     try do
-      instance = get_statsig_instance()
-      case NativeBindings.get_prompt(instance, prompt_name, statsig_user, options) do
+      case evaluate(:get_prompt, [prompt_name, statsig_user, options]) do
         {:error, e} -> {:error, e}
         layer -> {:ok, layer}
       end
@@ -133,9 +184,7 @@ defmodule Statsig do
 
   def get_client_init_response_as_string(statsig_user, options \\nil) do
     try do
-      instance = get_statsig_instance()
-
-      case NativeBindings.get_client_init_response_as_string(instance, statsig_user, options) do
+      case evaluate(:get_client_init_response_as_string, [statsig_user, options]) do
         {:error, e} -> {:error, e}
         response -> {:ok, response}
       end
@@ -222,4 +271,32 @@ defmodule Statsig do
       }
     end
   end
+
+  # sticky values cross the NIF boundary serialized
+  defp encode_user_persisted_values(%{user_persisted_values: values} = options)
+       when is_map(values) do
+    %{options | user_persisted_values: Jason.encode!(values)}
+  end
+
+  defp encode_user_persisted_values(options), do: options
+
+  # Dirty IO schedulers are a small shared pool, so evaluations only move there when an adapter
+  # can make them wait on an Elixir process. Everything else stays on normal schedulers.
+  defp evaluate(function, args) do
+    %{instance: instance, blocking_adapters: blocking_adapters} =
+      GenServer.call(__MODULE__, :get_state)
+
+    function =
+      if blocking_adapters, do: Map.fetch!(@dirty_io_evaluations, function), else: function
+
+    apply(NativeBindings, function, [instance | args])
+  end
+
+  # adapters the SDK waits on while evaluating; data stores are only called in the background
+  defp has_blocking_adapters?(nil), do: false
+
+  defp has_blocking_adapters?(options) do
+    Map.get(options, :persistent_storage) != nil or Map.get(options, :override_adapter) != nil or
+      Map.get(options, :observability_client) != nil
+  end
 end
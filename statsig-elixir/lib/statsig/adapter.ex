@@ -0,0 +1,79 @@
+defmodule Statsig.Adapter do
+  @moduledoc """
+  Process that runs an adapter module for the native SDK. The module implements one of
+  `Statsig.PersistentStorage`, `Statsig.DataStore`, `Statsig.OverrideAdapter` or
+  `Statsig.ObservabilityClient`, and the pid is passed in `Statsig.Options`:
+
+      {:ok, storage} = Statsig.Adapter.start_link(MyApp.StatsigStorage)
+      Statsig.start_link(sdk_key, %Statsig.Options{persistent_storage: storage})
+
+  The SDK sends `{:statsig_adapter_call, call_id, method, args_json}` for callbacks it needs a
+  result from and `{:statsig_adapter_cast, method, args_json}` for the rest. Arguments and results
+  cross the NIF boundary as JSON. A call that is not answered within
+  `Statsig.Options.adapter_timeout_ms` (1000ms by default) is abandoned and the SDK carries on
+  as if the callback failed, so a slow adapter never holds a scheduler.
+
+  Callbacks run one at a time in this process, in the order the SDK issued them. Evaluations
+  made from within a callback skip calls back into this same process, as if they returned nil.
+
+  With a `persistent_storage`, `override_adapter` or `observability_client` configured,
+  evaluations run on dirty IO schedulers, since they may wait on this process. Without them,
+  evaluations stay on normal schedulers and do not compete for the smaller dirty IO pool.
+  """
+  use GenServer
+  require Logger
+
+  alias Statsig.NativeBindings
+
+  def start_link(module, opts \\ []) do
+    GenServer.start_link(__MODULE__, module, opts)
+  end
+
+  def init(module) do
+    case Code.ensure_loaded(module) do
+      {:module, module} -> {:ok, module}
+      {:error, reason} -> {:stop, {:invalid_adapter, module, reason}}
+    end
+  end
+
+  def handle_info({:statsig_adapter_call, call_id, method, args_json}, module) do
+    result =
+      with {:ok, value} <- invoke(module, method, args_json),
+           {:ok, json} <- Jason.encode(value) do
+        json
+      else
+        _ -> nil
+      end
+
+    NativeBindings.adapter_reply(call_id, result)
+    {:noreply, module}
+  end
+
+  def handle_info({:statsig_adapter_cast, method, args_json}, module) do
+    invoke(module, method, args_json)
+    {:noreply, module}
+  end
+
+  def handle_info(_message, module) do
+    {:noreply, module}
+  end
+
+  defp invoke(module, method, args_json) do
+    args = Jason.decode!(args_json)
+
+    # optional callbacks that are not implemented are treated as returning nil
+    if function_exported?(module, method, length(args)) do
+      {:ok, apply(module, method, args)}
+    else
+      {:ok, nil}
+    end
+  rescue
+    exception ->
+      Logger.error("[Statsig] #{inspect(module)}.#{method} failed: #{Exception.message(exception)}")
+      :error
+  catch
+    kind, reason ->
+      Logger.error("[Statsig] #{inspect(module)}.#{method} failed: #{inspect({kind, reason})}")
+      :error
+  end
+end
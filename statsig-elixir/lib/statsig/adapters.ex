@@ -0,0 +1,101 @@
+defmodule Statsig.PersistentStorage do
+  @moduledoc """
+  Storage for sticky experiment assignments, run through `Statsig.Adapter`.
+
+  Keys have the form `"<unit id>:<id type>"` (see `storage_key/2`). Sticky values are plain maps
+  and should be stored and returned as received. To evaluate with the stored assignments, pass
+  the result of `load/1` as `user_persisted_values` in the experiment or layer options.
+  """
+
+  @callback load(key :: String.t()) :: %{String.t() => map()} | nil
+  @callback save(key :: String.t(), config_name :: String.t(), sticky_values :: map()) :: any()
+  @callback delete(key :: String.t(), config_name :: String.t()) :: any()
+
+  @doc "The storage key of `user` for `id_type`, or nil if the user has no such id."
+  def storage_key(%Statsig.User{} = user, id_type) do
+    id =
+      if String.downcase(id_type) == "userid" do
+        user.user_id
+      else
+        custom_ids = user.custom_ids || %{}
+        Map.get(custom_ids, id_type) || Map.get(custom_ids, String.downcase(id_type))
+      end
+
+    if id in [nil, ""], do: nil, else: "#{id}:#{id_type}"
+  end
+end
+
+defmodule Statsig.DataStore do
+  @moduledoc """
+  External source for specs and id lists (e.g. Redis), run through `Statsig.Adapter`.
+  Failed or timed out calls fall back to the network like any other data store error.
+  """
+
+  @type response :: %{result: String.t() | nil, time: non_neg_integer() | nil}
+
+  @callback initialize() :: any()
+  @callback shutdown() :: any()
+  @callback get(key :: String.t()) :: response() | nil
+  @callback set(key :: String.t(), value :: String.t(), time :: non_neg_integer() | nil) :: any()
+  @callback support_polling_updates_for(path :: String.t()) :: boolean()
+end
+
+defmodule Statsig.OverrideAdapter do
+  @moduledoc """
+  Source of overrides, consulted on every evaluation and run through `Statsig.Adapter`.
+
+  Lookups receive the user as a map with the SDK's field names (`"userID"`, `"customIDs"`, ...)
+  and return nil for no override. Gates return a boolean, everything else returns the value map.
+  Because each lookup is a round trip to the adapter process, keep them cheap.
+  """
+
+  @callback get_gate_override(user :: map(), gate_name :: String.t()) :: boolean() | nil
+  @callback get_dynamic_config_override(user :: map(), config_name :: String.t()) :: map() | nil
+  @callback get_experiment_override(user :: map(), experiment_name :: String.t()) :: map() | nil
+  @callback get_layer_override(user :: map(), layer_name :: String.t()) :: map() | nil
+
+  @callback override_gate(name :: String.t(), value :: boolean(), id :: String.t() | nil) :: any()
+  @callback override_dynamic_config(name :: String.t(), value :: map(), id :: String.t() | nil) ::
+              any()
+  @callback override_experiment(name :: String.t(), value :: map(), id :: String.t() | nil) ::
+              any()
+  @callback override_experiment_by_group_name(
+              name :: String.t(),
+              group_name :: String.t(),
+              id :: String.t() | nil
+            ) :: any()
+  @callback override_layer(name :: String.t(), value :: map(), id :: String.t() | nil) :: any()
+  @callback remove_gate_override(name :: String.t(), id :: String.t() | nil) :: any()
+  @callback remove_dynamic_config_override(name :: String.t(), id :: String.t() | nil) :: any()
+  @callback remove_experiment_override(name :: String.t(), id :: String.t() | nil) :: any()
+  @callback remove_layer_override(name :: String.t(), id :: String.t() | nil) :: any()
+  @callback remove_all_overrides() :: any()
+
+  @optional_callbacks override_gate: 3,
+                      override_dynamic_config: 3,
+                      override_experiment: 3,
+                      override_experiment_by_group_name: 3,
+                      override_layer: 3,
+                      remove_gate_override: 2,
+                      remove_dynamic_config_override: 2,
+                      remove_experiment_override: 2,
+                      remove_layer_override: 2,
+                      remove_all_overrides: 0
+end
+
+defmodule Statsig.ObservabilityClient do
+  @moduledoc """
+  Receives SDK metrics (e.g. to forward them to StatsD), run through `Statsig.Adapter`.
+  """
+
+  @type tags :: %{String.t() => String.t()} | nil
+
+  @callback init() :: any()
+  @callback increment(metric_name :: String.t(), value :: number(), tags :: tags()) :: any()
+  @callback gauge(metric_name :: String.t(), value :: number(), tags :: tags()) :: any()
+  @callback dist(metric_name :: String.t(), value :: number(), tags :: tags()) :: any()
+  @callback error(tag :: String.t(), error :: String.t()) :: any()
+  @callback should_enable_high_cardinality_for_this_tag(tag :: String.t()) :: boolean()
+
+  @optional_callbacks should_enable_high_cardinality_for_this_tag: 1
+end
@@ -0,0 +1,28 @@
+defmodule Statsig.CMABRankedGroup do
+  @moduledoc """
+  A CMAB group and its score for a user, as returned by `Statsig.get_cmab_ranked_groups/2`
+  """
+
+  defstruct [
+    :score,
+    :variant_name,
+    :rule_id,
+    :value,
+    :cmab_name
+  ]
+
+  @type t :: %__MODULE__{
+          score: float(),
+          variant_name: String.t(),
+          rule_id: String.t(),
+          value: String.t() | nil,
+          cmab_name: String.t()
+        }
+
+  def get_param_value(group, param_name) do
+    case group.value && Jason.decode!(group.value) do
+      %{^param_name => value} -> value
+      _ -> nil
+    end
+  end
+end
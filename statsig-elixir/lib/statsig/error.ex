@@ -0,0 +1,22 @@
+defmodule Statsig.Error do
+  @moduledoc """
+  Structured SDK error. `code` and `category` are stable and match the values exposed by every
+  other Statsig server core binding, so callers can branch on them instead of on `message`.
+  """
+
+  defstruct [
+    :code,
+    :code_value,
+    :category,
+    :message,
+    :context
+  ]
+
+  @type t :: %__MODULE__{
+          code: String.t(),
+          code_value: non_neg_integer(),
+          category: String.t(),
+          message: String.t(),
+          context: %{optional(String.t()) => String.t()}
+        }
+end
@@ -20,14 +20,31 @@ defmodule Statsig.NativeBindings do
   def get_feature_gate(_statsig, _gate_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
   def get_dynamic_config(_statsig, _config_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
   def get_experiment(_statsig, _experiment_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def get_experiment_by_group_name(_statsig, _experiment_name, _group_name), do: :erlang.nif_error(:nif_not_loaded)
   def get_layer(_statsig, _layer_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def get_cmab_ranked_groups(_statsig, _cmab_name, _statsig_user), do: :erlang.nif_error(:nif_not_loaded)
+  def log_cmab_exposure_for_group(_statsig, _cmab_name, _statsig_user, _group_id), do: :erlang.nif_error(:nif_not_loaded)
   def get_prompt(_statsig, _prompt_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
   def get_client_init_response_as_string(_statsig, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+
+  # Dirty IO variants of the evaluations, used when an adapter can block them
+  def check_gate_dirty_io(_statsig, _gate_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def get_feature_gate_dirty_io(_statsig, _gate_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def get_dynamic_config_dirty_io(_statsig, _config_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def get_experiment_dirty_io(_statsig, _experiment_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def get_layer_dirty_io(_statsig, _layer_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def get_cmab_ranked_groups_dirty_io(_statsig, _cmab_name, _statsig_user), do: :erlang.nif_error(:nif_not_loaded)
+  def get_prompt_dirty_io(_statsig, _prompt_name, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+  def get_client_init_response_as_string_dirty_io(_statsig, _statsig_user, _options), do: :erlang.nif_error(:nif_not_loaded)
+
   def log_event(_statsig, _statsig_user, _event_name,_value, _metadata), do: :erlang.nif_error(:nif_not_loaded)
   def log_event_with_number(_statsig, _statsig_user, _event_name,_value, _metadata), do: :erlang.nif_error(:nif_not_loaded)
   def flush(_statsig), do: :erlang.nif_error(:nif_not_loaded)
   def shutdown(_statsig), do: :erlang.nif_error(:nif_not_loaded)
 
+  # Adapter Related Functions
+  def adapter_reply(_call_id, _result), do: :erlang.nif_error(:nif_not_loaded)
+
   # Layer Related Functions
   def layer_get_name(_layer), do: :erlang.nif_error(:nif_not_loaded)
   def layer_get_rule_id(_layer), do: :erlang.nif_error(:nif_not_loaded)
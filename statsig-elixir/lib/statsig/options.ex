@@ -24,11 +24,18 @@ defmodule Statsig.Options do
     disable_country_lookup: nil,
     disable_network: nil,
     disable_user_agent_parsing: nil,
+
+    # pids of Statsig.Adapter processes
+    persistent_storage: nil,
+    data_store: nil,
+    override_adapter: nil,
+    observability_client: nil,
+    adapter_timeout_ms: nil,
   ]
 end
 
 defmodule Statsig.ExperimentEvaluationOptions do
-  defstruct disable_exposure_logging: false
+  defstruct disable_exposure_logging: false, user_persisted_values: nil
 end
 
 defmodule Statsig.FeatureGateEvaluationOptions do
@@ -36,7 +43,7 @@ defmodule Statsig.FeatureGateEvaluationOptions do
 end
 
 defmodule Statsig.LayerEvaluationOptions do
-  defstruct disable_exposure_logging: false
+  defstruct disable_exposure_logging: false, user_persisted_values: nil
 end
 
 defmodule Statsig.DynamicConfigEvaluationOptions do
@@ -0,0 +1,60 @@
+use crate::elixir_bridge_nfi::ElixirBridge;
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use statsig_rust::data_store_interface::{DataStoreResponse, DataStoreTrait, RequestPath};
+use statsig_rust::StatsigErr;
+
+/// `DataStoreTrait` backed by an Elixir module implementing `Statsig.DataStore`.
+pub struct DataStoreNfi {
+    bridge: ElixirBridge,
+}
+
+impl DataStoreNfi {
+    pub fn new(bridge: ElixirBridge) -> Self {
+        DataStoreNfi { bridge }
+    }
+
+    async fn call(&self, method: &'static str, args: Value) -> Result<Value, StatsigErr> {
+        self.bridge
+            .call_async(method, args)
+            .await
+            .ok_or_else(|| StatsigErr::DataStoreFailure(format!("'{method}' failed or timed out")))
+    }
+}
+
+#[async_trait]
+impl DataStoreTrait for DataStoreNfi {
+    async fn initialize(&self) -> Result<(), StatsigErr> {
+        self.call("initialize", json!([])).await.map(|_| ())
+    }
+
+    async fn shutdown(&self) -> Result<(), StatsigErr> {
+        self.call("shutdown", json!([])).await.map(|_| ())
+    }
+
+    async fn get(&self, key: &str) -> Result<DataStoreResponse, StatsigErr> {
+        let response = self.call("get", json!([key])).await?;
+
+        Ok(DataStoreResponse {
+            result: response
+                .get("result")
+                .and_then(|r| r.as_str())
+                .map(|r| r.to_string()),
+            time: response.get("time").and_then(|t| t.as_u64()),
+        })
+    }
+
+    async fn set(&self, key: &str, value: &str, time: Option<u64>) -> Result<(), StatsigErr> {
+        self.call("set", json!([key, value, time]))
+            .await
+            .map(|_| ())
+    }
+
+    async fn support_polling_updates_for(&self, path: RequestPath) -> bool {
+        self.bridge
+            .call_async("support_polling_updates_for", json!([path.to_string()]))
+            .await
+            .and_then(|supported| supported.as_bool())
+            .unwrap_or(false)
+    }
+}
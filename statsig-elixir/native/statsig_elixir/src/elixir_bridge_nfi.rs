@@ -0,0 +1,223 @@
+use parking_lot::Mutex;
+use rustler::{Atom, Encoder, Env, LocalPid, OwnedEnv, Term};
+use serde_json::Value;
+use statsig_rust::{log_e, log_w};
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, OnceLock};
+use std::time::Duration;
+
+const TAG: &str = "ElixirBridge";
+pub const DEFAULT_ADAPTER_TIMEOUT_MS: u64 = 1000;
+
+mod atoms {
+    rustler::atoms! {
+        statsig_adapter_call,
+        statsig_adapter_cast,
+    }
+}
+
+type ReplyFn = Box<dyn FnOnce(Option<String>) + Send>;
+
+struct OutgoingMessage {
+    pid: LocalPid,
+    call_id: Option<u64>,
+    method: &'static str,
+    args: String,
+}
+
+impl OutgoingMessage {
+    fn encode<'a>(&self, env: Env<'a>) -> Term<'a> {
+        let method = match Atom::from_str(env, self.method) {
+            Ok(method) => method,
+            Err(_) => return rustler::types::atom::error().encode(env),
+        };
+
+        match self.call_id {
+            Some(call_id) => (
+                atoms::statsig_adapter_call(),
+                call_id,
+                method,
+                self.args.as_str(),
+            )
+                .encode(env),
+            None => (atoms::statsig_adapter_cast(), method, self.args.as_str()).encode(env),
+        }
+    }
+}
+
+static NEXT_CALL_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    static CALLING_PID: Cell<Option<LocalPid>> = const { Cell::new(None) };
+}
+
+/// Runs `f` on behalf of the process that called the current NIF, so adapter calls made by the
+/// SDK while `f` runs can tell when they would wait on that same process.
+pub fn with_calling_pid<T>(pid: LocalPid, f: impl FnOnce() -> T) -> T {
+    let prev = CALLING_PID.replace(Some(pid));
+    let result = f();
+    CALLING_PID.set(prev);
+    result
+}
+
+fn pending_calls() -> &'static Mutex<HashMap<u64, ReplyFn>> {
+    static PENDING_CALLS: OnceLock<Mutex<HashMap<u64, ReplyFn>>> = OnceLock::new();
+    PENDING_CALLS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+// enif_send from an OwnedEnv is only allowed on threads the VM does not manage, and adapters are
+// invoked from NIF calls as well as from the tokio runtime, so all messages go through one thread.
+fn outbox() -> &'static mpsc::Sender<OutgoingMessage> {
+    static OUTBOX: OnceLock<mpsc::Sender<OutgoingMessage>> = OnceLock::new();
+    OUTBOX.get_or_init(|| {
+        let (tx, rx) = mpsc::channel::<OutgoingMessage>();
+        let spawned = std::thread::Builder::new()
+            .name("statsig-elixir-bridge".to_string())
+            .spawn(move || {
+                let mut owned_env = OwnedEnv::new();
+                for message in rx {
+                    let sent = owned_env.send_and_clear(&message.pid, |env| message.encode(env));
+                    if sent.is_err() {
+                        log_w!(TAG, "Adapter process is not alive ({})", message.method);
+                        if let Some(call_id) = message.call_id {
+                            resolve_call(call_id, None);
+                        }
+                    }
+                }
+            });
+
+        if let Err(e) = spawned {
+            log_e!(TAG, "Failed to spawn bridge thread: {}", e);
+        }
+        tx
+    })
+}
+
+fn register_call(reply: ReplyFn) -> u64 {
+    let call_id = NEXT_CALL_ID.fetch_add(1, Ordering::Relaxed);
+    pending_calls().lock().insert(call_id, reply);
+    call_id
+}
+
+fn resolve_call(call_id: u64, result: Option<String>) {
+    // the caller may have timed out already, in which case the reply is dropped
+    let reply = pending_calls().lock().remove(&call_id);
+    if let Some(reply) = reply {
+        reply(result);
+    }
+}
+
+/// Replies to a `{:statsig_adapter_call, call_id, method, args}` message.
+/// `result` is the JSON encoded return value, or nil if the callback failed.
+#[rustler::nif]
+pub fn adapter_reply(call_id: u64, result: Option<String>) {
+    resolve_call(call_id, result);
+}
+
+/// Forwards adapter callbacks to an Elixir process (see `Statsig.Adapter`) as messages.
+///
+/// Arguments and return values are JSON encoded. Calls that expect a result wait at most
+/// `timeout`, so a slow or dead process degrades to the adapter's default behaviour instead of
+/// holding the calling thread. NIFs that can reach `call` run on dirty IO schedulers.
+pub struct ElixirBridge {
+    pid: LocalPid,
+    timeout: Duration,
+}
+
+impl ElixirBridge {
+    pub fn new(pid: LocalPid, timeout_ms: Option<u64>) -> Self {
+        ElixirBridge {
+            pid,
+            timeout: Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_ADAPTER_TIMEOUT_MS)),
+        }
+    }
+
+    pub fn cast(&self, method: &'static str, args: Value) {
+        self.send(None, method, args);
+    }
+
+    pub fn call(&self, method: &'static str, args: Value) -> Option<Value> {
+        // the adapter process is blocked in this NIF call, so it could never reply
+        if CALLING_PID.get() == Some(self.pid) {
+            log_w!(
+                TAG,
+                "Skipping {}, it was triggered from within the adapter process",
+                method
+            );
+            return None;
+        }
+
+        let (tx, rx) = mpsc::sync_channel(1);
+        let call_id = register_call(Box::new(move |result| {
+            let _ = tx.send(result);
+        }));
+
+        self.send(Some(call_id), method, args);
+
+        match rx.recv_timeout(self.timeout) {
+            Ok(result) => parse_result(method, result),
+            Err(_) => {
+                pending_calls().lock().remove(&call_id);
+                log_w!(
+                    TAG,
+                    "Timed out after {:?} waiting on {}",
+                    self.timeout,
+                    method
+                );
+                None
+            }
+        }
+    }
+
+    pub async fn call_async(&self, method: &'static str, args: Value) -> Option<Value> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let call_id = register_call(Box::new(move |result| {
+            let _ = tx.send(result);
+        }));
+
+        self.send(Some(call_id), method, args);
+
+        match tokio::time::timeout(self.timeout, rx).await {
+            Ok(Ok(result)) => parse_result(method, result),
+            _ => {
+                pending_calls().lock().remove(&call_id);
+                log_w!(
+                    TAG,
+                    "Timed out after {:?} waiting on {}",
+                    self.timeout,
+                    method
+                );
+                None
+            }
+        }
+    }
+
+    fn send(&self, call_id: Option<u64>, method: &'static str, args: Value) {
+        let message = OutgoingMessage {
+            pid: self.pid,
+            call_id,
+            method,
+            args: args.to_string(),
+        };
+
+        if outbox().send(message).is_err() {
+            log_e!(TAG, "Bridge thread is not running, dropping {}", method);
+            if let Some(call_id) = call_id {
+                resolve_call(call_id, None);
+            }
+        }
+    }
+}
+
+fn parse_result(method: &str, result: Option<String>) -> Option<Value> {
+    let result = result?;
+    match serde_json::from_str(&result) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            log_e!(TAG, "Failed to parse result of {}: {}", method, e);
+            None
+        }
+    }
+}
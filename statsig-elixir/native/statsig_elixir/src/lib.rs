@@ -1,3 +1,8 @@
+mod data_store_nfi;
+mod elixir_bridge_nfi;
+mod observability_client_nfi;
+mod override_adapter_nfi;
+mod persistent_storage_nfi;
 mod statsig_nfi;
 mod statsig_options_nfi;
 mod statsig_types_nfi;
@@ -0,0 +1,50 @@
+use crate::elixir_bridge_nfi::ElixirBridge;
+use serde_json::json;
+use statsig_rust::{ObservabilityClient, OpsStatsEventObserver};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// `ObservabilityClient` backed by an Elixir module implementing `Statsig.ObservabilityClient`.
+/// Metrics are fire-and-forget, only the high cardinality check waits for a reply.
+pub struct ObservabilityClientNfi {
+    bridge: ElixirBridge,
+}
+
+impl ObservabilityClientNfi {
+    pub fn new(bridge: ElixirBridge) -> Self {
+        ObservabilityClientNfi { bridge }
+    }
+}
+
+impl ObservabilityClient for ObservabilityClientNfi {
+    fn init(&self) {
+        self.bridge.cast("init", json!([]));
+    }
+
+    fn increment(&self, metric_name: String, value: f64, tags: Option<HashMap<String, String>>) {
+        self.bridge
+            .cast("increment", json!([metric_name, value, tags]));
+    }
+
+    fn gauge(&self, metric_name: String, value: f64, tags: Option<HashMap<String, String>>) {
+        self.bridge.cast("gauge", json!([metric_name, value, tags]));
+    }
+
+    fn dist(&self, metric_name: String, value: f64, tags: Option<HashMap<String, String>>) {
+        self.bridge.cast("dist", json!([metric_name, value, tags]));
+    }
+
+    fn error(&self, tag: String, error: String) {
+        self.bridge.cast("error", json!([tag, error]));
+    }
+
+    fn should_enable_high_cardinality_for_this_tag(&self, tag: String) -> Option<bool> {
+        self.bridge
+            .call("should_enable_high_cardinality_for_this_tag", json!([tag]))
+            .and_then(|enabled| enabled.as_bool())
+    }
+
+    fn to_ops_stats_event_observer(self: Arc<Self>) -> Arc<dyn OpsStatsEventObserver> {
+        self
+    }
+}
@@ -0,0 +1,173 @@
+use crate::elixir_bridge_nfi::ElixirBridge;
+use serde_json::{json, Value};
+use statsig_rust::evaluation::dynamic_returnable::DynamicReturnable;
+use statsig_rust::evaluation::evaluator_result::EvaluatorResult;
+use statsig_rust::event_logging::exposable_string::ExposableString;
+use statsig_rust::specs_response::spec_types::Spec;
+use statsig_rust::{log_e, OverrideAdapter, StatsigUser};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+const TAG: &str = "OverrideAdapterNfi";
+const OVERRIDE_REASON: &str = "LocalOverride";
+
+fn override_rule_id() -> &'static ExposableString {
+    static OVERRIDE_RULE_ID: OnceLock<ExposableString> = OnceLock::new();
+    OVERRIDE_RULE_ID.get_or_init(|| ExposableString::from_str_ref("override"))
+}
+
+/// `OverrideAdapter` backed by an Elixir module implementing `Statsig.OverrideAdapter`.
+///
+/// Lookups wait for the module's reply (bounded by the adapter timeout), a nil reply means
+/// no override. Mutations are forwarded without waiting.
+pub struct OverrideAdapterNfi {
+    bridge: ElixirBridge,
+}
+
+impl OverrideAdapterNfi {
+    pub fn new(bridge: ElixirBridge) -> Self {
+        OverrideAdapterNfi { bridge }
+    }
+
+    fn get_override(&self, method: &'static str, user: &StatsigUser, name: &str) -> Option<Value> {
+        let user = match serde_json::to_value(user.get_user_data()) {
+            Ok(user) => user,
+            Err(e) => {
+                log_e!(TAG, "Failed to serialize user: {}", e);
+                return None;
+            }
+        };
+
+        self.bridge
+            .call(method, json!([user, name]))
+            .filter(|value| !value.is_null())
+    }
+
+    fn get_json_override(
+        &self,
+        method: &'static str,
+        user: &StatsigUser,
+        name: &str,
+        result: &mut EvaluatorResult<'_>,
+    ) -> bool {
+        let value = match self.get_override(method, user, name) {
+            Some(Value::Object(value)) => value,
+            Some(_) => {
+                log_e!(TAG, "Expected a map from {} for {}", method, name);
+                return false;
+            }
+            None => return false,
+        };
+
+        let value: HashMap<String, Value> = value.into_iter().collect();
+        result.json_value = Some(DynamicReturnable::from_map(value));
+        mark_result_as_override(result);
+        true
+    }
+}
+
+impl OverrideAdapter for OverrideAdapterNfi {
+    fn get_gate_override(
+        &self,
+        user: &StatsigUser,
+        gate_name: &str,
+        result: &mut EvaluatorResult<'_>,
+    ) -> bool {
+        match self
+            .get_override("get_gate_override", user, gate_name)
+            .and_then(|value| value.as_bool())
+        {
+            Some(value) => {
+                result.bool_value = value;
+                mark_result_as_override(result);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn get_dynamic_config_override(
+        &self,
+        user: &StatsigUser,
+        dynamic_config_name: &str,
+        result: &mut EvaluatorResult<'_>,
+    ) -> bool {
+        self.get_json_override(
+            "get_dynamic_config_override",
+            user,
+            dynamic_config_name,
+            result,
+        )
+    }
+
+    fn get_experiment_override(
+        &self,
+        user: &StatsigUser,
+        experiment_name: &str,
+        result: &mut EvaluatorResult<'_>,
+        _opt_spec: Option<&Spec>,
+    ) -> bool {
+        self.get_json_override("get_experiment_override", user, experiment_name, result)
+    }
+
+    fn get_layer_override(
+        &self,
+        user: &StatsigUser,
+        layer_name: &str,
+        result: &mut EvaluatorResult<'_>,
+    ) -> bool {
+        self.get_json_override("get_layer_override", user, layer_name, result)
+    }
+
+    fn override_gate(&self, key: &str, value: bool, id: Option<&str>) {
+        self.bridge.cast("override_gate", json!([key, value, id]));
+    }
+
+    fn override_dynamic_config(&self, key: &str, value: HashMap<String, Value>, id: Option<&str>) {
+        self.bridge
+            .cast("override_dynamic_config", json!([key, value, id]));
+    }
+
+    fn override_experiment(&self, key: &str, value: HashMap<String, Value>, id: Option<&str>) {
+        self.bridge
+            .cast("override_experiment", json!([key, value, id]));
+    }
+
+    fn override_experiment_by_group_name(&self, key: &str, group_name: &str, id: Option<&str>) {
+        self.bridge.cast(
+            "override_experiment_by_group_name",
+            json!([key, group_name, id]),
+        );
+    }
+
+    fn override_layer(&self, key: &str, value: HashMap<String, Value>, id: Option<&str>) {
+        self.bridge.cast("override_layer", json!([key, value, id]));
+    }
+
+    fn remove_gate_override(&self, key: &str, id: Option<&str>) {
+        self.bridge.cast("remove_gate_override", json!([key, id]));
+    }
+
+    fn remove_dynamic_config_override(&self, key: &str, id: Option<&str>) {
+        self.bridge
+            .cast("remove_dynamic_config_override", json!([key, id]));
+    }
+
+    fn remove_experiment_override(&self, key: &str, id: Option<&str>) {
+        self.bridge
+            .cast("remove_experiment_override", json!([key, id]));
+    }
+
+    fn remove_layer_override(&self, key: &str, id: Option<&str>) {
+        self.bridge.cast("remove_layer_override", json!([key, id]));
+    }
+
+    fn remove_all_overrides(&self) {
+        self.bridge.cast("remove_all_overrides", json!([]));
+    }
+}
+
+fn mark_result_as_override(result: &mut EvaluatorResult<'_>) {
+    result.override_reason = Some(OVERRIDE_REASON);
+    result.rule_id = Some(override_rule_id());
+}
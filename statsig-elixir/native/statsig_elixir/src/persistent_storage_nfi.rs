@@ -0,0 +1,41 @@
+use crate::elixir_bridge_nfi::ElixirBridge;
+use serde_json::json;
+use statsig_rust::{log_e, PersistentStorage, StickyValues, UserPersistedValues};
+
+const TAG: &str = "PersistentStorageNfi";
+
+/// `PersistentStorage` backed by an Elixir module implementing `Statsig.PersistentStorage`.
+pub struct PersistentStorageNfi {
+    bridge: ElixirBridge,
+}
+
+impl PersistentStorageNfi {
+    pub fn new(bridge: ElixirBridge) -> Self {
+        PersistentStorageNfi { bridge }
+    }
+}
+
+impl PersistentStorage for PersistentStorageNfi {
+    fn load(&self, key: String) -> Option<UserPersistedValues> {
+        let values = self.bridge.call("load", json!([key]))?;
+        if values.is_null() {
+            return None;
+        }
+
+        match serde_json::from_value(values) {
+            Ok(values) => Some(values),
+            Err(e) => {
+                log_e!(TAG, "Failed to parse persisted values: {}", e);
+                None
+            }
+        }
+    }
+
+    fn save(&self, key: &str, config_name: &str, data: StickyValues) {
+        self.bridge.cast("save", json!([key, config_name, data]));
+    }
+
+    fn delete(&self, key: &str, config_name: &str) {
+        self.bridge.cast("delete", json!([key, config_name]));
+    }
+}
@@ -8,11 +8,13 @@ use std::{
 };
 
 use crate::{
+    elixir_bridge_nfi::with_calling_pid,
+    observability_client_nfi::ObservabilityClientNfi,
     statsig_options_nfi::StatsigOptions,
     statsig_types_nfi::{
-        AllowedPrimitive, ClientInitResponseOptions, DynamicConfig, DynamicConfigEvaluationOptions,
-        Experiment, ExperimentEvaluationOptions, FeatureGate, FeatureGateEvaluationOptions,
-        LayerEvaluationOptions,
+        AllowedPrimitive, CMABRankedGroup, ClientInitResponseOptions, DynamicConfig,
+        DynamicConfigEvaluationOptions, Experiment, ExperimentEvaluationOptions, FeatureGate,
+        FeatureGateEvaluationOptions, LayerEvaluationOptions, StatsigError,
     },
     statsig_user_nfi::StatsigUser,
 };
@@ -20,6 +22,8 @@ use serde_json::Value;
 
 struct StatsigResource {
     pub statsig_core: RwLock<Arc<Statsig>>,
+    // the options only hold a weak reference
+    _observability_client: Option<Arc<ObservabilityClientNfi>>,
 }
 
 #[allow(non_local_definitions)]
@@ -41,6 +45,189 @@ impl LayerResource {
     }
 }
 
+// Evaluations come in two variants. The `_dirty_io` ones are for instances whose adapters can
+// block an evaluation while waiting on an Elixir process, see `Statsig.Adapter`.
+
+#[rustler::nif]
+pub fn get_feature_gate(
+    statsig: ResourceArc<StatsigResource>,
+    gate_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<FeatureGateEvaluationOptions>,
+) -> Result<FeatureGate, Error> {
+    get_feature_gate_impl(statsig, gate_name, statsig_user, options)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_feature_gate_dirty_io(
+    env: Env,
+    statsig: ResourceArc<StatsigResource>,
+    gate_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<FeatureGateEvaluationOptions>,
+) -> Result<FeatureGate, Error> {
+    with_calling_pid(env.pid(), || {
+        get_feature_gate_impl(statsig, gate_name, statsig_user, options)
+    })
+}
+
+#[rustler::nif]
+pub fn check_gate(
+    statsig: ResourceArc<StatsigResource>,
+    gate_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<FeatureGateEvaluationOptions>,
+) -> Result<bool, Error> {
+    check_gate_impl(statsig, gate_name, statsig_user, options)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn check_gate_dirty_io(
+    env: Env,
+    statsig: ResourceArc<StatsigResource>,
+    gate_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<FeatureGateEvaluationOptions>,
+) -> Result<bool, Error> {
+    with_calling_pid(env.pid(), || {
+        check_gate_impl(statsig, gate_name, statsig_user, options)
+    })
+}
+
+#[rustler::nif]
+pub fn get_dynamic_config(
+    statsig: ResourceArc<StatsigResource>,
+    config_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<DynamicConfigEvaluationOptions>,
+) -> Result<DynamicConfig, Error> {
+    get_dynamic_config_impl(statsig, config_name, statsig_user, options)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_dynamic_config_dirty_io(
+    env: Env,
+    statsig: ResourceArc<StatsigResource>,
+    config_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<DynamicConfigEvaluationOptions>,
+) -> Result<DynamicConfig, Error> {
+    with_calling_pid(env.pid(), || {
+        get_dynamic_config_impl(statsig, config_name, statsig_user, options)
+    })
+}
+
+#[rustler::nif]
+pub fn get_experiment(
+    statsig: ResourceArc<StatsigResource>,
+    experiment_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<ExperimentEvaluationOptions>,
+) -> Result<Experiment, Error> {
+    get_experiment_impl(statsig, experiment_name, statsig_user, options)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_experiment_dirty_io(
+    env: Env,
+    statsig: ResourceArc<StatsigResource>,
+    experiment_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<ExperimentEvaluationOptions>,
+) -> Result<Experiment, Error> {
+    with_calling_pid(env.pid(), || {
+        get_experiment_impl(statsig, experiment_name, statsig_user, options)
+    })
+}
+
+#[rustler::nif]
+pub fn get_cmab_ranked_groups(
+    statsig: ResourceArc<StatsigResource>,
+    cmab_name: &str,
+    statsig_user: StatsigUser,
+) -> Result<Vec<CMABRankedGroup>, Error> {
+    get_cmab_ranked_groups_impl(statsig, cmab_name, statsig_user)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_cmab_ranked_groups_dirty_io(
+    env: Env,
+    statsig: ResourceArc<StatsigResource>,
+    cmab_name: &str,
+    statsig_user: StatsigUser,
+) -> Result<Vec<CMABRankedGroup>, Error> {
+    with_calling_pid(env.pid(), || {
+        get_cmab_ranked_groups_impl(statsig, cmab_name, statsig_user)
+    })
+}
+
+#[rustler::nif]
+pub fn get_layer(
+    statsig: ResourceArc<StatsigResource>,
+    layer_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<LayerEvaluationOptions>,
+) -> Result<ResourceArc<LayerResource>, Error> {
+    get_layer_impl(statsig, layer_name, statsig_user, options)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_layer_dirty_io(
+    env: Env,
+    statsig: ResourceArc<StatsigResource>,
+    layer_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<LayerEvaluationOptions>,
+) -> Result<ResourceArc<LayerResource>, Error> {
+    with_calling_pid(env.pid(), || {
+        get_layer_impl(statsig, layer_name, statsig_user, options)
+    })
+}
+
+#[rustler::nif]
+pub fn get_prompt(
+    statsig: ResourceArc<StatsigResource>,
+    prompt_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<LayerEvaluationOptions>,
+) -> Result<ResourceArc<LayerResource>, Error> {
+    get_prompt_impl(statsig, prompt_name, statsig_user, options)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_prompt_dirty_io(
+    env: Env,
+    statsig: ResourceArc<StatsigResource>,
+    prompt_name: &str,
+    statsig_user: StatsigUser,
+    options: Option<LayerEvaluationOptions>,
+) -> Result<ResourceArc<LayerResource>, Error> {
+    with_calling_pid(env.pid(), || {
+        get_prompt_impl(statsig, prompt_name, statsig_user, options)
+    })
+}
+
+#[rustler::nif]
+pub fn get_client_init_response_as_string(
+    statsig: ResourceArc<StatsigResource>,
+    statsig_user: StatsigUser,
+    options: Option<ClientInitResponseOptions>,
+) -> Result<String, Error> {
+    get_client_init_response_as_string_impl(statsig, statsig_user, options)
+}
+
+#[rustler::nif(schedule = "DirtyIo")]
+pub fn get_client_init_response_as_string_dirty_io(
+    env: Env,
+    statsig: ResourceArc<StatsigResource>,
+    statsig_user: StatsigUser,
+    options: Option<ClientInitResponseOptions>,
+) -> Result<String, Error> {
+    with_calling_pid(env.pid(), || {
+        get_client_init_response_as_string_impl(statsig, statsig_user, options)
+    })
+}
+
 #[rustler::nif(schedule = "DirtyCpu")]
 pub fn new(
     sdk_key: String,
@@ -48,13 +235,22 @@ pub fn new(
     system_metadata: HashMap<String, String>,
 ) -> Result<ResourceArc<StatsigResource>, Error> {
     update_metadata(system_metadata);
-    let statsig = Statsig::new(&sdk_key, options.map(|op| Arc::new(op.into())));
+    let (options, observability_client) = match options {
+        Some(options) => {
+            let (options, observability_client) = options.into_statsig_options();
+            (Some(Arc::new(options)), observability_client)
+        }
+        None => (None, None),
+    };
+
+    let statsig = Statsig::new(&sdk_key, options);
     Ok(ResourceArc::new(StatsigResource {
         statsig_core: RwLock::new(Arc::new(statsig)),
+        _observability_client: observability_client,
     }))
 }
 
-#[rustler::nif]
+#[rustler::nif(schedule = "DirtyIo")]
 pub fn initialize(statsig: ResourceArc<StatsigResource>) -> Result<(), Error> {
     match statsig.statsig_core.read() {
         Ok(read) => {
@@ -66,15 +262,14 @@ pub fn initialize(statsig: ResourceArc<StatsigResource>) -> Result<(), Error> {
 
             match rt_handle.block_on(statsig.initialize()) {
                 Ok(_) => Ok(()),
-                Err(_) => Err(Error::RaiseAtom("failed to init")),
+                Err(e) => Err(Error::Term(Box::new(StatsigError::from(e)))),
             }
         }
         Err(_) => Err(Error::RaiseAtom("failed to init")),
     }
 }
 
-#[rustler::nif]
-pub fn get_feature_gate(
+fn get_feature_gate_impl(
     statsig: ResourceArc<StatsigResource>,
     gate_name: &str,
     statsig_user: StatsigUser,
@@ -92,8 +287,7 @@ pub fn get_feature_gate(
     }
 }
 
-#[rustler::nif]
-pub fn check_gate(
+fn check_gate_impl(
     statsig: ResourceArc<StatsigResource>,
     gate_name: &str,
     statsig_user: StatsigUser,
@@ -109,8 +303,7 @@ pub fn check_gate(
     }
 }
 
-#[rustler::nif]
-pub fn get_dynamic_config(
+fn get_dynamic_config_impl(
     statsig: ResourceArc<StatsigResource>,
     config_name: &str,
     statsig_user: StatsigUser,
@@ -128,8 +321,7 @@ pub fn get_dynamic_config(
     }
 }
 
-#[rustler::nif]
-pub fn get_experiment(
+fn get_experiment_impl(
     statsig: ResourceArc<StatsigResource>,
     experiment_name: &str,
     statsig_user: StatsigUser,
@@ -148,7 +340,51 @@ pub fn get_experiment(
 }
 
 #[rustler::nif]
-pub fn get_layer(
+pub fn get_experiment_by_group_name(
+    statsig: ResourceArc<StatsigResource>,
+    experiment_name: &str,
+    group_name: &str,
+) -> Result<Experiment, Error> {
+    match statsig.statsig_core.read() {
+        Ok(read_guard) => Ok(read_guard
+            .get_experiment_by_group_name(experiment_name, group_name)
+            .into()),
+        Err(_) => Err(Error::RaiseAtom("Failed to get Statsig")),
+    }
+}
+
+fn get_cmab_ranked_groups_impl(
+    statsig: ResourceArc<StatsigResource>,
+    cmab_name: &str,
+    statsig_user: StatsigUser,
+) -> Result<Vec<CMABRankedGroup>, Error> {
+    match statsig.statsig_core.read() {
+        Ok(read_guard) => Ok(read_guard
+            .get_cmab_ranked_groups(&statsig_user.into(), cmab_name)
+            .into_iter()
+            .map(CMABRankedGroup::from)
+            .collect()),
+        Err(_) => Err(Error::RaiseAtom("Failed to get Statsig")),
+    }
+}
+
+#[rustler::nif]
+pub fn log_cmab_exposure_for_group(
+    statsig: ResourceArc<StatsigResource>,
+    cmab_name: &str,
+    statsig_user: StatsigUser,
+    group_id: String,
+) -> Result<(), Error> {
+    match statsig.statsig_core.read() {
+        Ok(read_guard) => {
+            read_guard.log_cmab_exposure_for_group(&statsig_user.into(), cmab_name, group_id);
+            Ok(())
+        }
+        Err(_) => Err(Error::RaiseAtom("Failed to get Statsig")),
+    }
+}
+
+fn get_layer_impl(
     statsig: ResourceArc<StatsigResource>,
     layer_name: &str,
     statsig_user: StatsigUser,
@@ -167,8 +403,7 @@ pub fn get_layer(
     }
 }
 
-#[rustler::nif]
-pub fn get_prompt(
+fn get_prompt_impl(
     statsig: ResourceArc<StatsigResource>,
     prompt_name: &str,
     statsig_user: StatsigUser,
@@ -226,8 +461,7 @@ pub fn log_event_with_number(
     }
 }
 
-#[rustler::nif(schedule = "DirtyCpu")]
-pub fn get_client_init_response_as_string(
+fn get_client_init_response_as_string_impl(
     statsig: ResourceArc<StatsigResource>,
     statsig_user: StatsigUser,
     options: Option<ClientInitResponseOptions>,
@@ -266,7 +500,7 @@ pub fn flush(statsig: ResourceArc<StatsigResource>) -> Result<(), Error> {
     }
 }
 
-#[rustler::nif]
+#[rustler::nif(schedule = "DirtyIo")]
 pub fn shutdown(statsig: ResourceArc<StatsigResource>) -> Result<(), Error> {
     match statsig.statsig_core.read() {
         Ok(read_guard) => {
@@ -280,7 +514,7 @@ pub fn shutdown(statsig: ResourceArc<StatsigResource>) -> Result<(), Error> {
             rt_handle.block_on(async move {
                 match statsig.shutdown().await {
                     Ok(_) => Ok(()),
-                    Err(_) => Err(Error::RaiseAtom("Failed to shutdown")),
+                    Err(e) => Err(Error::Term(Box::new(StatsigError::from(e)))),
                 }
             })
         }
@@ -1,6 +1,14 @@
-use rustler::NifStruct;
+use crate::data_store_nfi::DataStoreNfi;
+use crate::elixir_bridge_nfi::ElixirBridge;
+use crate::observability_client_nfi::ObservabilityClientNfi;
+use crate::override_adapter_nfi::OverrideAdapterNfi;
+use crate::persistent_storage_nfi::PersistentStorageNfi;
+use rustler::{LocalPid, NifStruct};
 use serde::Serialize;
+use statsig_rust::data_store_interface::DataStoreTrait;
 use statsig_rust::statsig_options::StatsigOptions as StatsigOptionsActual;
+use statsig_rust::{ObservabilityClient, OverrideAdapter, PersistentStorage};
+use std::sync::Arc;
 
 #[derive(NifStruct, Serialize)]
 #[module = "Statsig.Options"]
@@ -28,11 +36,34 @@ pub struct StatsigOptions {
 
     pub wait_for_country_lookup_init: Option<bool>,
     pub wait_for_user_agent_init: Option<bool>,
+
+    // pids of Statsig.Adapter processes
+    #[serde(skip)]
+    pub persistent_storage: Option<LocalPid>,
+    #[serde(skip)]
+    pub data_store: Option<LocalPid>,
+    #[serde(skip)]
+    pub override_adapter: Option<LocalPid>,
+    #[serde(skip)]
+    pub observability_client: Option<LocalPid>,
+    pub adapter_timeout_ms: Option<u64>,
 }
 
-impl From<StatsigOptions> for StatsigOptionsActual {
-    fn from(config: StatsigOptions) -> Self {
-        StatsigOptionsActual {
+impl StatsigOptions {
+    /// The observability client is only weakly referenced by the options,
+    /// so the strong reference is returned for the caller to keep alive.
+    pub fn into_statsig_options(
+        self,
+    ) -> (StatsigOptionsActual, Option<Arc<ObservabilityClientNfi>>) {
+        let config = self;
+        let timeout_ms = config.adapter_timeout_ms;
+        let bridge = |pid: LocalPid| ElixirBridge::new(pid, timeout_ms);
+
+        let observability_client = config
+            .observability_client
+            .map(|pid| Arc::new(ObservabilityClientNfi::new(bridge(pid))));
+
+        let options = StatsigOptionsActual {
             environment: config.environment,
             enable_id_lists: config.enable_id_lists,
             id_lists_url: config.id_lists_url,
@@ -48,7 +79,22 @@ impl From<StatsigOptions> for StatsigOptionsActual {
             disable_user_agent_parsing: config.disable_user_agent_parsing,
             wait_for_country_lookup_init: config.wait_for_country_lookup_init,
             wait_for_user_agent_init: config.wait_for_user_agent_init,
+            persistent_storage: config.persistent_storage.map(|pid| {
+                Arc::new(PersistentStorageNfi::new(bridge(pid))) as Arc<dyn PersistentStorage>
+            }),
+            data_store: config
+                .data_store
+                .map(|pid| Arc::new(DataStoreNfi::new(bridge(pid))) as Arc<dyn DataStoreTrait>),
+            override_adapter: config.override_adapter.map(|pid| {
+                Arc::new(OverrideAdapterNfi::new(bridge(pid))) as Arc<dyn OverrideAdapter>
+            }),
+            observability_client: observability_client.as_ref().map(|client| {
+                let client: Arc<dyn ObservabilityClient> = client.clone();
+                Arc::downgrade(&client)
+            }),
             ..StatsigOptionsActual::default()
-        }
+        };
+
+        (options, observability_client)
     }
 }
@@ -10,8 +10,37 @@ use statsig_rust::statsig_types::{
     DynamicConfig as DynamicConfigActual, Experiment as ExperimentActual,
     FeatureGate as FeatureGateActual,
 };
-use statsig_rust::DynamicValue;
+use statsig_rust::{
+    log_e, CMABRankedGroup as CMABRankedGroupActual, DynamicValue, StatsigErr, UserPersistedValues,
+};
 use statsig_rust::{ClientInitResponseOptions as ClientInitResponseOptionsActual, HashAlgorithm};
+use std::collections::HashMap;
+
+const TAG: &str = "StatsigTypesNfi";
+
+#[derive(NifStruct)]
+#[module = "Statsig.Error"]
+pub struct StatsigError {
+    pub code: String,
+    pub code_value: u16,
+    pub category: String,
+    pub message: String,
+    pub context: HashMap<String, String>,
+}
+
+impl From<StatsigErr> for StatsigError {
+    fn from(err: StatsigErr) -> Self {
+        let info = err.to_info();
+        StatsigError {
+            code: info.code.as_str().to_string(),
+            code_value: info.code_value,
+            category: info.category.as_str().to_string(),
+            message: info.message,
+            context: info.context.to_map(),
+        }
+    }
+}
+
 #[derive(NifStruct)]
 #[module = "Statsig.Experiment"]
 pub struct Experiment {
@@ -34,6 +63,28 @@ impl From<ExperimentActual> for Experiment {
     }
 }
 
+#[derive(NifStruct)]
+#[module = "Statsig.CMABRankedGroup"]
+pub struct CMABRankedGroup {
+    pub score: f64,
+    pub variant_name: String,
+    pub rule_id: String,
+    pub value: Option<String>, // serialized value
+    pub cmab_name: String,
+}
+
+impl From<CMABRankedGroupActual> for CMABRankedGroup {
+    fn from(group: CMABRankedGroupActual) -> Self {
+        CMABRankedGroup {
+            score: group.score,
+            variant_name: group.variant_name,
+            rule_id: group.rule_id.unperformant_to_string(),
+            value: group.value.and_then(|v| serde_json::to_string(&v).ok()),
+            cmab_name: group.cmab_name,
+        }
+    }
+}
+
 #[derive(NifStruct)]
 #[module = "Statsig.DynamicConfig"]
 pub struct DynamicConfig {
@@ -132,13 +183,26 @@ impl From<AllowedPrimitive> for DynamicValue {
 #[module = "Statsig.ExperimentEvaluationOptions"]
 pub struct ExperimentEvaluationOptions {
     pub disable_exposure_logging: bool,
+    pub user_persisted_values: Option<String>, // serialized values
 }
 
 impl From<ExperimentEvaluationOptions> for ExperimentEvaluationOptionsActual {
     fn from(option: ExperimentEvaluationOptions) -> Self {
         ExperimentEvaluationOptionsActual {
             disable_exposure_logging: option.disable_exposure_logging,
-            ..Default::default()
+            user_persisted_values: option
+                .user_persisted_values
+                .and_then(|values| parse_user_persisted_values(&values)),
+        }
+    }
+}
+
+fn parse_user_persisted_values(values: &str) -> Option<UserPersistedValues> {
+    match serde_json::from_str(values) {
+        Ok(values) => Some(values),
+        Err(e) => {
+            log_e!(TAG, "Failed to parse user persisted values: {}", e);
+            None
         }
     }
 }
@@ -161,13 +225,16 @@ impl From<FeatureGateEvaluationOptions> for FeatureGateEvaluationOptionsActual {
 #[module = "Statsig.LayerEvaluationOptions"]
 pub struct LayerEvaluationOptions {
     pub disable_exposure_logging: bool,
+    pub user_persisted_values: Option<String>, // serialized values
 }
 
 impl From<LayerEvaluationOptions> for LayerEvaluationOptionsActual {
     fn from(option: LayerEvaluationOptions) -> Self {
         LayerEvaluationOptionsActual {
             disable_exposure_logging: option.disable_exposure_logging,
-            ..Default::default()
+            user_persisted_values: option
+                .user_persisted_values
+                .and_then(|values| parse_user_persisted_values(&values)),
         }
     }
 }
@@ -0,0 +1,66 @@
+defmodule StatsigAdapterTest do
+  use ExUnit.Case, async: false
+
+  alias Statsig.Options
+  alias Statsig.User
+
+  defmodule TestOverrides do
+    @behaviour Statsig.OverrideAdapter
+
+    def get_gate_override(%{"userID" => "overridden_user"}, "a_gate"), do: true
+    def get_gate_override(_user, _gate_name), do: nil
+    def get_dynamic_config_override(_user, "a_config"), do: %{"color" => "blue"}
+    def get_dynamic_config_override(_user, _config_name), do: nil
+    def get_experiment_override(_user, _experiment_name), do: nil
+    def get_layer_override(_user, _layer_name), do: nil
+  end
+
+  defmodule SlowOverrides do
+    @behaviour Statsig.OverrideAdapter
+
+    def get_gate_override(_user, _gate_name) do
+      Process.sleep(1_000)
+      true
+    end
+
+    def get_dynamic_config_override(_user, _config_name), do: nil
+    def get_experiment_override(_user, _experiment_name), do: nil
+    def get_layer_override(_user, _layer_name), do: nil
+  end
+
+  defp start_statsig(options) do
+    options = %Options{options | disable_network: true}
+    start_supervised!(%{id: Statsig, start: {Statsig, :start_link, ["secret-key", options]}})
+    Statsig.initialize()
+  end
+
+  test "override adapter lookups are answered by the adapter process" do
+    {:ok, adapter} = Statsig.Adapter.start_link(TestOverrides)
+    start_statsig(%Options{override_adapter: adapter})
+
+    assert {:ok, true} = Statsig.check_gate("a_gate", %User{user_id: "overridden_user"})
+    assert {:ok, false} = Statsig.check_gate("a_gate", %User{user_id: "other_user"})
+
+    {:ok, config} = Statsig.get_dynamic_config("a_config", %User{user_id: "other_user"})
+    assert Statsig.DynamicConfig.get_param_value(config, "color") == "blue"
+  end
+
+  test "slow adapters time out instead of blocking evaluation" do
+    {:ok, adapter} = Statsig.Adapter.start_link(SlowOverrides)
+    start_statsig(%Options{override_adapter: adapter, adapter_timeout_ms: 50})
+
+    {elapsed_us, result} =
+      :timer.tc(fn -> Statsig.check_gate("a_gate", %User{user_id: "a_user"}) end)
+
+    assert result == {:ok, false}
+    assert elapsed_us < 500_000
+  end
+
+  test "storage keys match the sdk's format" do
+    user = %User{user_id: "a_user", custom_ids: %{"companyID" => "a_company"}}
+
+    assert Statsig.PersistentStorage.storage_key(user, "userID") == "a_user:userID"
+    assert Statsig.PersistentStorage.storage_key(user, "companyID") == "a_company:companyID"
+    assert Statsig.PersistentStorage.storage_key(user, "teamID") == nil
+  end
+end
@@ -0,0 +1,70 @@
+defmodule StatsigCMABTest do
+  use ExUnit.Case, async: false
+
+  alias Statsig.CMABRankedGroup
+  alias Statsig.Options
+  alias Statsig.User
+
+  defmodule FileDataStore do
+    @behaviour Statsig.DataStore
+
+    @specs_dir Path.expand("../../statsig-rust/tests/data", __DIR__)
+
+    def initialize, do: :ok
+    def shutdown, do: :ok
+    def set(_key, _value, _time), do: :ok
+    def support_polling_updates_for(_path), do: false
+
+    def get(key) do
+      file = if key =~ "download_config_specs", do: specs_file(), else: nil
+
+      case file do
+        nil -> nil
+        file -> %{result: File.read!(Path.join(@specs_dir, file)), time: 1000}
+      end
+    end
+
+    defp specs_file, do: Application.get_env(:statsig_elixir, :test_specs_file)
+  end
+
+  defp start_statsig(specs_file) do
+    Application.put_env(:statsig_elixir, :test_specs_file, specs_file)
+    {:ok, data_store} = Statsig.Adapter.start_link(FileDataStore)
+    options = %Options{data_store: data_store, disable_network: true}
+    start_supervised!(%{id: Statsig, start: {Statsig, :start_link, ["secret-key", options]}})
+    Statsig.initialize()
+  end
+
+  test "cmab groups are ranked by score" do
+    start_statsig("dcs_with_cmab.json")
+
+    {:ok, groups} = Statsig.get_cmab_ranked_groups("test_cmab", %User{user_id: "a_user"})
+
+    assert Enum.map(groups, & &1.variant_name) == ["Green", "Blue"]
+    [%CMABRankedGroup{} = best | _] = groups
+    assert best.score == 2.0
+    assert best.rule_id == "green_group_id"
+    assert best.cmab_name == "test_cmab"
+    assert CMABRankedGroup.get_param_value(best, "button_color") == "green"
+
+    assert {:ok, []} = Statsig.get_cmab_ranked_groups("not_a_cmab", %User{user_id: "a_user"})
+  end
+
+  test "exposures can be logged for a cmab group" do
+    start_statsig("dcs_with_cmab.json")
+
+    result = Statsig.log_cmab_exposure_for_group("test_cmab", %User{user_id: "a_user"}, "blue_group_id")
+    refute match?({:error, _}, result)
+  end
+
+  test "experiments can be looked up by group name" do
+    start_statsig("eval_proj_dcs.json")
+
+    {:ok, experiment} =
+      Statsig.get_experiment_by_group_name("test_experiment_no_targeting", "Control")
+
+    assert experiment.group_name == "Control"
+    assert experiment.rule_id == "54QJztEPRLXK7ZCvXeY9q4"
+    assert Statsig.Experiment.get_param_value(experiment, "value") == "control"
+  end
+end
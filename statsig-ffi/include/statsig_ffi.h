@@ -61,6 +61,25 @@ void observability_client_set_ref(uint64_t ob_client_ref, uint64_t id);
 
 void observability_client_release(uint64_t ob_client_ref);
 
+/**
+ * Subscribes `callback` to `event_name` ("*" for all events).
+ *
+ * The callback is invoked on the evaluating thread with the given `listener_ref` and the event
+ * as a JSON string. The string is owned by Rust and only valid for the duration of the callback.
+ *
+ * Returns the subscription id, which must be released with `free_string`.
+ */
+char *statsig_subscribe(uint64_t statsig_ref,
+                        const char *event_name,
+                        void (*callback)(uint64_t listener_ref, const char *event_json),
+                        uint64_t listener_ref);
+
+void statsig_unsubscribe(uint64_t statsig_ref, const char *event_name);
+
+void statsig_unsubscribe_by_id(uint64_t statsig_ref, const char *subscription_id);
+
+void statsig_unsubscribe_all(uint64_t statsig_ref);
+
 void specs_update_listener_release(uint64_t listener_ref);
 
 void specs_update_listener_did_receive_specs_update(uint64_t listener_ref,
@@ -181,10 +200,23 @@ char *statsig_get_experiment(uint64_t statsig_ref,
                              const char *experiment_name,
                              const char *options_json);
 
+char *statsig_get_experiment_by_group_name(uint64_t statsig_ref,
+                                           const char *experiment_name,
+                                           const char *group_name);
+
 void statsig_manually_log_experiment_exposure(uint64_t statsig_ref,
                                               uint64_t user_ref,
                                               const char *experiment_name);
 
+char *statsig_get_cmab_ranked_groups(uint64_t statsig_ref,
+                                     uint64_t user_ref,
+                                     const char *cmab_name);
+
+void statsig_log_cmab_exposure_for_group(uint64_t statsig_ref,
+                                         uint64_t user_ref,
+                                         const char *cmab_name,
+                                         const char *group_id);
+
 char *statsig_get_layer(uint64_t statsig_ref,
                         uint64_t user_ref,
                         const char *layer_name,
@@ -105,7 +105,11 @@ pub fn convert_java_client_init_response_options_to_rust(
         dynamic_config_filter: None,
         layer_filter: None,
         param_store_filter: None,
+        exclude_filter: None,
+        entity_type_filter: None,
+        target_app_id_filter: None,
         response_format,
+        signing_key: None,
     })
 }
 
@@ -1,5 +1,6 @@
 mod jni_utils;
 mod statsig_data_store_jni;
+mod statsig_event_emitter_jni;
 mod statsig_jni;
 mod statsig_observability_client_jni;
 mod statsig_options_jni;
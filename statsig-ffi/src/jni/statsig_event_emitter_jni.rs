@@ -0,0 +1,128 @@
+use crate::jni::jni_utils::{jstring_to_string, string_to_jstring};
+use crate::{get_instance_or_noop_c, get_instance_or_return_c};
+use jni::objects::{GlobalRef, JClass, JObject, JString, JValue};
+use jni::sys::{jlong, jstring};
+use jni::{JNIEnv, JavaVM};
+use statsig_rust::sdk_event_emitter::SubscriptionID;
+use statsig_rust::{log_e, Statsig};
+
+const TAG: &str = "StatsigEventEmitterJNI";
+
+#[no_mangle]
+pub extern "system" fn Java_com_statsig_StatsigJNI_statsigSubscribe(
+    mut env: JNIEnv,
+    _class: JClass,
+    statsig_ref: jlong,
+    event_name: JString,
+    callback: JObject,
+) -> jstring {
+    let statsig = get_instance_or_return_c!(Statsig, &(statsig_ref as u64), std::ptr::null_mut());
+
+    let event_name = match jstring_to_string(&mut env, event_name) {
+        Some(name) => name,
+        None => return std::ptr::null_mut(),
+    };
+
+    let java_vm = match env.get_java_vm() {
+        Ok(vm) => vm,
+        Err(e) => {
+            log_e!(TAG, "Failed to get Java VM: {:?}", e);
+            return std::ptr::null_mut();
+        }
+    };
+
+    let callback = match env.new_global_ref(callback) {
+        Ok(callback) => callback,
+        Err(e) => {
+            log_e!(TAG, "Failed to create global ref for callback: {:?}", e);
+            return std::ptr::null_mut();
+        }
+    };
+
+    let sub_id = statsig.event_emitter.subscribe(&event_name, move |event| {
+        match serde_json::to_string(&event.to_json_map()) {
+            Ok(event_json) => fire_event_callback(&java_vm, &callback, event_json),
+            Err(e) => log_e!(TAG, "Failed to serialize event: {}", e),
+        }
+    });
+
+    string_to_jstring(&mut env, sub_id.encode())
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_statsig_StatsigJNI_statsigUnsubscribe(
+    mut env: JNIEnv,
+    _class: JClass,
+    statsig_ref: jlong,
+    event_name: JString,
+) {
+    let statsig = get_instance_or_noop_c!(Statsig, &(statsig_ref as u64));
+
+    if let Some(event_name) = jstring_to_string(&mut env, event_name) {
+        statsig.event_emitter.unsubscribe(&event_name);
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_statsig_StatsigJNI_statsigUnsubscribeById(
+    mut env: JNIEnv,
+    _class: JClass,
+    statsig_ref: jlong,
+    subscription_id: JString,
+) {
+    let statsig = get_instance_or_noop_c!(Statsig, &(statsig_ref as u64));
+
+    let subscription_id = match jstring_to_string(&mut env, subscription_id) {
+        Some(id) => id,
+        None => return,
+    };
+
+    match SubscriptionID::decode(&subscription_id) {
+        Some(sub_id) => statsig.event_emitter.unsubscribe_by_id(&sub_id),
+        None => log_e!(TAG, "Invalid subscription ID: {}", subscription_id),
+    }
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_statsig_StatsigJNI_statsigUnsubscribeAll(
+    _env: JNIEnv,
+    _class: JClass,
+    statsig_ref: jlong,
+) {
+    let statsig = get_instance_or_noop_c!(Statsig, &(statsig_ref as u64));
+    statsig.event_emitter.unsubscribe_all();
+}
+
+/// Events are emitted on the evaluating thread. For Java threads (already inside a native call)
+/// attaching is a no-op, runtime threads are attached for the duration of the call.
+fn fire_event_callback(vm: &JavaVM, callback: &GlobalRef, event_json: String) {
+    let mut env = match vm.attach_current_thread() {
+        Ok(env) => env,
+        Err(e) => {
+            log_e!(TAG, "Failed to attach current thread: {}", e);
+            return;
+        }
+    };
+
+    // local frame, so refs created here do not pile up in the caller's native frame
+    let result = env.with_local_frame(4, |env| -> jni::errors::Result<()> {
+        let j_event = env.new_string(event_json)?;
+        env.call_method(
+            callback.as_obj(),
+            "onEvent",
+            "(Ljava/lang/String;)V",
+            &[JValue::Object(&j_event)],
+        )?;
+        Ok(())
+    });
+
+    if let Err(e) = result {
+        log_e!(TAG, "Failed to call event callback: {:?}", e);
+    }
+
+    // an exception thrown by the listener must not surface from the evaluation that emitted it
+    if env.exception_check().unwrap_or(false) {
+        let _ = env.exception_describe();
+        let _ = env.exception_clear();
+    }
+}
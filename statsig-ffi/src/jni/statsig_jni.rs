@@ -20,7 +20,7 @@ use jni::{
     objects::{JClass, JObject, JString, JValue},
     JavaVM,
 };
-use statsig_rust::{log_d, log_e, InstanceRegistry, Statsig, StatsigUser};
+use statsig_rust::{log_d, log_e, InitializeDetails, InstanceRegistry, Statsig, StatsigUser};
 
 const TAG: &str = "StatsigJNI";
 
@@ -155,48 +155,38 @@ pub extern "system" fn Java_com_statsig_StatsigJNI_statsigInitializeWithDetails(
             }
         };
 
-        match result {
-            Ok(details) => {
-                let json_str = match serde_json::to_string(&details) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        log_e!(TAG, "Failed to serialize initialization details: {:?}", e);
-                        return;
-                    }
-                };
-
-                let jstr = match env.new_string(json_str) {
-                    Ok(s) => s,
-                    Err(e) => {
-                        log_e!(TAG, "Failed to create JString: {:?}", e);
-                        return;
-                    }
-                };
-
-                let obj = JObject::from(jstr);
-                let arg = JValue::Object(&obj);
-                let _ = env.call_method(
-                    global_callback.as_obj(),
-                    "complete",
-                    "(Ljava/lang/Object;)Z",
-                    &[arg],
-                );
-            }
+        let details = match result {
+            Ok(details) => details,
             Err(e) => {
                 log_e!(TAG, "initialize with details failed: {:?}", e);
+                InitializeDetails::from_error("initialize_failed", Some(e))
+            }
+        };
 
-                let error_str = format!("Initialization failed: {e:?}");
-                if let Ok(jstr) = env.new_string(error_str) {
-                    let obj = JObject::from(jstr);
-                    let _ = env.call_method(
-                        global_callback.as_obj(),
-                        "complete",
-                        "(Ljava/lang/Object;)Z",
-                        &[JValue::Object(&obj)],
-                    );
-                }
+        let json_str = match serde_json::to_string(&details) {
+            Ok(s) => s,
+            Err(e) => {
+                log_e!(TAG, "Failed to serialize initialization details: {:?}", e);
+                return;
             }
-        }
+        };
+
+        let jstr = match env.new_string(json_str) {
+            Ok(s) => s,
+            Err(e) => {
+                log_e!(TAG, "Failed to create JString: {:?}", e);
+                return;
+            }
+        };
+
+        let obj = JObject::from(jstr);
+        let arg = JValue::Object(&obj);
+        let _ = env.call_method(
+            global_callback.as_obj(),
+            "complete",
+            "(Ljava/lang/Object;)Z",
+            &[arg],
+        );
     });
 }
 
@@ -354,6 +344,31 @@ pub extern "system" fn Java_com_statsig_StatsigJNI_statsigGetExperiment(
     serialize_json_to_jstring(&mut env, &result)
 }
 
+#[no_mangle]
+pub extern "system" fn Java_com_statsig_StatsigJNI_statsigGetExperimentByGroupName(
+    mut env: JNIEnv,
+    _class: jclass,
+    statsig_ref: jlong,
+    exper_name: JString,
+    group_name: JString,
+) -> jstring {
+    let statsig = get_instance_or_return_c!(Statsig, &(statsig_ref as u64), std::ptr::null_mut());
+
+    let exper_name: String = match env.get_string(&exper_name) {
+        Ok(s) => s.into(),
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let group_name: String = match env.get_string(&group_name) {
+        Ok(s) => s.into(),
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let result = statsig.get_experiment_by_group_name(&exper_name, &group_name);
+
+    serialize_json_to_jstring(&mut env, &result)
+}
+
 #[no_mangle]
 pub extern "system" fn Java_com_statsig_StatsigJNI_statsigLogExperimentExposure(
     mut env: JNIEnv,
@@ -0,0 +1,81 @@
+use std::ffi::{c_char, CString};
+use std::ptr::null_mut;
+
+use statsig_rust::sdk_event_emitter::SubscriptionID;
+use statsig_rust::{log_e, unwrap_or_else, Statsig};
+
+use crate::ffi_utils::{c_char_to_string, string_to_c_char};
+use crate::{get_instance_or_noop_c, get_instance_or_return_c};
+
+const TAG: &str = "SdkEventEmitterC";
+
+/// Subscribes `callback` to `event_name` ("*" for all events).
+///
+/// The callback is invoked on the evaluating thread with the given `listener_ref` and the event
+/// as a JSON string. The string is owned by Rust and only valid for the duration of the callback.
+///
+/// Returns the subscription id, which must be released with `free_string`.
+#[no_mangle]
+pub extern "C" fn statsig_subscribe(
+    statsig_ref: u64,
+    event_name: *const c_char,
+    callback: extern "C" fn(listener_ref: u64, event_json: *const c_char),
+    listener_ref: u64,
+) -> *mut c_char {
+    let statsig = get_instance_or_return_c!(Statsig, &statsig_ref, null_mut());
+
+    let event_name = unwrap_or_else!(c_char_to_string(event_name), {
+        log_e!(TAG, "Failed to convert 'event_name' to Rust string");
+        return null_mut();
+    });
+
+    let sub_id = statsig.event_emitter.subscribe(&event_name, move |event| {
+        let event_json = match serde_json::to_string(&event.to_json_map()) {
+            Ok(json) => json,
+            Err(e) => {
+                log_e!(TAG, "Failed to serialize event: {}", e);
+                return;
+            }
+        };
+
+        match CString::new(event_json) {
+            Ok(event_json) => callback(listener_ref, event_json.as_ptr()),
+            Err(e) => log_e!(TAG, "Failed to convert event to C string: {}", e),
+        }
+    });
+
+    string_to_c_char(sub_id.encode())
+}
+
+#[no_mangle]
+pub extern "C" fn statsig_unsubscribe(statsig_ref: u64, event_name: *const c_char) {
+    let statsig = get_instance_or_noop_c!(Statsig, &statsig_ref);
+
+    let event_name = unwrap_or_else!(c_char_to_string(event_name), {
+        log_e!(TAG, "Failed to convert 'event_name' to Rust string");
+        return;
+    });
+
+    statsig.event_emitter.unsubscribe(&event_name);
+}
+
+#[no_mangle]
+pub extern "C" fn statsig_unsubscribe_by_id(statsig_ref: u64, subscription_id: *const c_char) {
+    let statsig = get_instance_or_noop_c!(Statsig, &statsig_ref);
+
+    let subscription_id = unwrap_or_else!(c_char_to_string(subscription_id), {
+        log_e!(TAG, "Failed to convert 'subscription_id' to Rust string");
+        return;
+    });
+
+    match SubscriptionID::decode(&subscription_id) {
+        Some(sub_id) => statsig.event_emitter.unsubscribe_by_id(&sub_id),
+        None => log_e!(TAG, "Invalid subscription ID: {}", subscription_id),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn statsig_unsubscribe_all(statsig_ref: u64) {
+    let statsig = get_instance_or_noop_c!(Statsig, &statsig_ref);
+    statsig.event_emitter.unsubscribe_all();
+}
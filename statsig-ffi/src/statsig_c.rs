@@ -5,8 +5,8 @@ use serde_json::Value;
 use statsig_rust::{
     log_d, log_e, unwrap_or_else, unwrap_or_noop, unwrap_or_return, ClientInitResponseOptions,
     DynamicConfigEvaluationOptions, ExperimentEvaluationOptions, FeatureGateEvaluationOptions,
-    InstanceRegistry, LayerEvaluationOptions, ParameterStoreEvaluationOptions, Statsig,
-    StatsigOptions, StatsigUser,
+    InitializeDetails, InstanceRegistry, LayerEvaluationOptions, ParameterStoreEvaluationOptions,
+    Statsig, StatsigOptions, StatsigUser,
 };
 use std::collections::HashMap;
 use std::os::raw::c_char;
@@ -77,8 +77,7 @@ pub extern "C" fn statsig_initialize_with_details(
             Ok(d) => d,
             Err(e) => {
                 log_e!(TAG, "Failed to init statsig with details: {}", e);
-                callback(null_mut());
-                return;
+                InitializeDetails::from_error("initialize_failed", Some(e))
             }
         };
 
@@ -112,7 +111,7 @@ pub extern "C" fn statsig_initialize_with_details_blocking(statsig_ref: u64) ->
         Ok(d) => d,
         Err(e) => {
             log_e!(TAG, "Failed to initialize statsig with details: {}", e);
-            return null_mut();
+            InitializeDetails::from_error("initialize_failed", Some(e))
         }
     };
 
@@ -786,6 +785,22 @@ pub extern "C" fn statsig_get_experiment(
     string_to_c_char(result)
 }
 
+#[no_mangle]
+pub extern "C" fn statsig_get_experiment_by_group_name(
+    statsig_ref: u64,
+    experiment_name: *const c_char,
+    group_name: *const c_char,
+) -> *mut c_char {
+    let statsig = get_instance_or_return_c!(Statsig, &statsig_ref, null_mut());
+    let experiment_name = unwrap_or_return!(c_char_to_string(experiment_name), null_mut());
+    let group_name = unwrap_or_return!(c_char_to_string(group_name), null_mut());
+
+    let experiment = statsig.get_experiment_by_group_name(&experiment_name, &group_name);
+
+    let result = json!(experiment).to_string();
+    string_to_c_char(result)
+}
+
 #[no_mangle]
 pub extern "C" fn statsig_manually_log_experiment_exposure(
     statsig_ref: u64,
@@ -799,6 +814,41 @@ pub extern "C" fn statsig_manually_log_experiment_exposure(
     statsig.manually_log_experiment_exposure(&user, &experiment_name);
 }
 
+// ------------------------
+// CMAB Functions
+// ------------------------
+
+#[no_mangle]
+pub extern "C" fn statsig_get_cmab_ranked_groups(
+    statsig_ref: u64,
+    user_ref: u64,
+    cmab_name: *const c_char,
+) -> *mut c_char {
+    let statsig = get_instance_or_return_c!(Statsig, &statsig_ref, null_mut());
+    let user = get_instance_or_return_c!(StatsigUser, &user_ref, null_mut());
+    let cmab_name = unwrap_or_return!(c_char_to_string(cmab_name), null_mut());
+
+    let groups = statsig.get_cmab_ranked_groups(&user, &cmab_name);
+
+    let result = json!(groups).to_string();
+    string_to_c_char(result)
+}
+
+#[no_mangle]
+pub extern "C" fn statsig_log_cmab_exposure_for_group(
+    statsig_ref: u64,
+    user_ref: u64,
+    cmab_name: *const c_char,
+    group_id: *const c_char,
+) {
+    let statsig = get_instance_or_noop_c!(Statsig, &statsig_ref);
+    let user = get_instance_or_noop_c!(StatsigUser, &user_ref);
+    let cmab_name = unwrap_or_noop!(c_char_to_string(cmab_name));
+    let group_id = unwrap_or_noop!(c_char_to_string(group_id));
+
+    statsig.log_cmab_exposure_for_group(&user, &cmab_name, group_id);
+}
+
 // ------------------------
 // Layer Functions
 // ------------------------
@@ -9,4 +9,15 @@ type ClientInitResponseOptions struct {
 	ExperimentFilter      *[]string           `json:"experiment_filter,omitempty"`
 	DynamicConfigFilter   *[]string           `json:"dynamic_config_filter,omitempty"`
 	LayerFilter           *[]string           `json:"layer_filter,omitempty"`
+	ParamStoreFilter      *[]string           `json:"param_store_filter,omitempty"`
+	ExcludeFilter         *[]string           `json:"exclude_filter,omitempty"`
+	EntityTypeFilter      *[]string           `json:"entity_type_filter,omitempty"`
+	TargetAppIDFilter     *[]string           `json:"target_app_id_filter,omitempty"`
+	SigningKey            *GCIRSigningKey     `json:"signing_key,omitempty"`
+}
+
+// Algorithm is "ed25519" (base64 encoded 32 byte private key) or "hmac_sha256" (shared secret)
+type GCIRSigningKey struct {
+	Algorithm string `json:"algorithm"`
+	Key       string `json:"key"`
 }
@@ -2,3 +2,4 @@ pub mod mock_forward_proxy;
 pub mod statsig_forward_proxy;
 pub mod statsig_grpc_client;
 pub mod statsig_grpc_err;
+pub mod statsig_grpc_server;
@@ -1,7 +1,5 @@
-use lazy_static::lazy_static;
 use parking_lot::Mutex;
-use std::net::SocketAddr;
-use std::sync::atomic::{AtomicI32, Ordering};
+use std::net::{SocketAddr, TcpListener};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc::{Receiver, Sender};
@@ -18,10 +16,6 @@ pub mod api {
 use api::statsig_forward_proxy_server::{StatsigForwardProxy, StatsigForwardProxyServer};
 use api::{ConfigSpecRequest, ConfigSpecResponse};
 
-lazy_static! {
-    static ref PORT_ID: AtomicI32 = AtomicI32::new(50051);
-}
-
 #[tokio::main]
 pub async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mock_server = MockForwardProxy::spawn().await;
@@ -52,8 +46,11 @@ pub struct MockForwardProxy {
 
 impl MockForwardProxy {
     pub async fn spawn() -> Arc<MockForwardProxy> {
-        let port = PORT_ID.fetch_add(1, Ordering::SeqCst);
-        let proxy_address: SocketAddr = format!("127.0.0.1:{port}").parse().unwrap();
+        // a free port, so proxies in concurrently running test processes do not collide
+        let proxy_address = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
 
         let forward_proxy = Arc::new(MockForwardProxy {
             proxy_address,
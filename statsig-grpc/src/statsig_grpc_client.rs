@@ -7,11 +7,34 @@ use std::time::Duration;
 use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity};
 use tonic::Streaming;
 
+/// HTTP/2 connection settings used when (re)building the channel.
+#[derive(Clone, Debug)]
+pub struct StatsigGrpcChannelConfig {
+    pub connect_timeout: Duration,
+    pub tcp_keepalive: Option<Duration>,
+    pub http2_keep_alive_interval: Duration,
+    pub keep_alive_timeout: Duration,
+    pub keep_alive_while_idle: bool,
+}
+
+impl Default for StatsigGrpcChannelConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(5),
+            tcp_keepalive: Some(Duration::from_secs(30)),
+            http2_keep_alive_interval: Duration::from_secs(30),
+            keep_alive_timeout: Duration::from_secs(20),
+            keep_alive_while_idle: true,
+        }
+    }
+}
+
 pub struct StatsigGrpcClient {
     sdk_key: String,
     proxy_api: String,
     grpc_client: Mutex<Option<StatsigForwardProxyClient<Channel>>>,
     tls_config: Option<ClientTlsConfig>,
+    channel_config: StatsigGrpcChannelConfig,
 }
 
 impl StatsigGrpcClient {
@@ -36,9 +59,16 @@ impl StatsigGrpcClient {
                 proxy_api,
             ),
             grpc_client: Mutex::new(None),
+            channel_config: StatsigGrpcChannelConfig::default(),
         }
     }
 
+    #[must_use]
+    pub fn with_channel_config(mut self, channel_config: StatsigGrpcChannelConfig) -> Self {
+        self.channel_config = channel_config;
+        self
+    }
+
     pub async fn connect_client(&self) -> Result<(), StatsigGrpcErr> {
         self.get_or_setup_grpc_client().await.map(|_| ())
     }
@@ -167,10 +197,11 @@ impl StatsigGrpcClient {
 
         let mut channel_builder = Channel::from_shared(self.proxy_api.clone())
             .map_err(|e| StatsigGrpcErr::FailedToConnect(e.to_string()))?
-            .connect_timeout(Duration::from_secs(5))
-            .tcp_keepalive(Some(Duration::from_secs(30)))
-            .keep_alive_while_idle(true)
-            .http2_keep_alive_interval(Duration::from_secs(30));
+            .connect_timeout(self.channel_config.connect_timeout)
+            .tcp_keepalive(self.channel_config.tcp_keepalive)
+            .keep_alive_while_idle(self.channel_config.keep_alive_while_idle)
+            .http2_keep_alive_interval(self.channel_config.http2_keep_alive_interval)
+            .keep_alive_timeout(self.channel_config.keep_alive_timeout);
 
         if let Some(tls_config) = self.tls_config.clone() {
             channel_builder = channel_builder
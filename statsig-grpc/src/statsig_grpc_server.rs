@@ -0,0 +1,153 @@
+use crate::statsig_forward_proxy::statsig_forward_proxy_server::{
+    StatsigForwardProxy, StatsigForwardProxyServer,
+};
+use crate::statsig_forward_proxy::{ConfigSpecRequest, ConfigSpecResponse};
+use crate::statsig_grpc_err::StatsigGrpcErr;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::sync::{mpsc, watch};
+use tonic::codegen::tokio_stream::wrappers::ReceiverStream;
+use tonic::transport::Server;
+use tonic::{Request, Response, Status};
+
+const STREAM_BUFFER_SIZE: usize = 4;
+
+/// Serves the `StatsigForwardProxy` RPCs from specs published by the embedding process,
+/// so `StatsigGrpcSpecsAdapter`s in other services can use it as their `specs_url`.
+///
+/// Only requests for the configured sdk key are answered.
+pub struct StatsigGrpcServer {
+    sdk_key: String,
+    specs_tx: watch::Sender<Option<ConfigSpecResponse>>,
+}
+
+impl StatsigGrpcServer {
+    pub fn new(sdk_key: &str) -> Arc<Self> {
+        let (specs_tx, _) = watch::channel(None);
+        Arc::new(Self {
+            sdk_key: sdk_key.to_string(),
+            specs_tx,
+        })
+    }
+
+    /// Replaces the served specs and pushes them to every open stream.
+    /// Specs older than the ones already served are ignored.
+    pub fn publish_specs(&self, spec: String, last_updated: u64) {
+        self.specs_tx.send_if_modified(|current| {
+            if current
+                .as_ref()
+                .is_some_and(|c| c.last_updated >= last_updated)
+            {
+                return false;
+            }
+
+            *current = Some(ConfigSpecResponse {
+                spec,
+                last_updated,
+                // specs are served as plain JSON, never dictionary compressed
+                zstd_dict_id: None,
+            });
+            true
+        });
+    }
+
+    pub fn get_last_updated(&self) -> Option<u64> {
+        let current = self.specs_tx.borrow();
+        (*current).as_ref().map(|s| s.last_updated)
+    }
+
+    /// The tonic service, for mounting next to other services on an existing `Server`.
+    pub fn into_service(self: Arc<Self>) -> StatsigForwardProxyServer<StatsigGrpcServerService> {
+        StatsigForwardProxyServer::new(StatsigGrpcServerService { server: self })
+    }
+
+    pub async fn serve_with_shutdown<F>(
+        self: Arc<Self>,
+        address: SocketAddr,
+        signal: F,
+    ) -> Result<(), StatsigGrpcErr>
+    where
+        F: Future<Output = ()> + Send,
+    {
+        Server::builder()
+            .add_service(self.into_service())
+            .serve_with_shutdown(address, signal)
+            .await
+            .map_err(|e| StatsigGrpcErr::CustomErr(format!("gRPC server failed: {e}")))
+    }
+
+    fn validate_request(&self, request: &ConfigSpecRequest) -> Option<Status> {
+        (request.sdk_key != self.sdk_key).then(|| Status::permission_denied("Unknown sdk key"))
+    }
+
+    fn get_current_specs(&self) -> Option<ConfigSpecResponse> {
+        self.specs_tx.borrow().clone()
+    }
+}
+
+pub struct StatsigGrpcServerService {
+    server: Arc<StatsigGrpcServer>,
+}
+
+#[tonic::async_trait]
+impl StatsigForwardProxy for StatsigGrpcServerService {
+    async fn get_config_spec(
+        &self,
+        request: Request<ConfigSpecRequest>,
+    ) -> Result<Response<ConfigSpecResponse>, Status> {
+        if let Some(status) = self.server.validate_request(request.get_ref()) {
+            return Err(status);
+        }
+
+        match self.server.get_current_specs() {
+            Some(specs) => Ok(Response::new(specs)),
+            None => Err(Status::unavailable("Specs are not available yet")),
+        }
+    }
+
+    type StreamConfigSpecStream = ReceiverStream<Result<ConfigSpecResponse, Status>>;
+
+    async fn stream_config_spec(
+        &self,
+        request: Request<ConfigSpecRequest>,
+    ) -> Result<Response<Self::StreamConfigSpecStream>, Status> {
+        let request = request.into_inner();
+        if let Some(status) = self.server.validate_request(&request) {
+            return Err(status);
+        }
+
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER_SIZE);
+        let mut specs_rx = self.server.specs_tx.subscribe();
+        let mut since_time = request.since_time.unwrap_or(0);
+
+        tokio::spawn(async move {
+            loop {
+                let next = match &*specs_rx.borrow_and_update() {
+                    Some(specs) if specs.last_updated > since_time => Some(specs.clone()),
+                    _ => None,
+                };
+
+                if let Some(specs) = next {
+                    since_time = specs.last_updated;
+                    if tx.send(Ok(specs)).await.is_err() {
+                        // client disconnected
+                        break;
+                    }
+                }
+
+                tokio::select! {
+                    changed = specs_rx.changed() => {
+                        if changed.is_err() {
+                            let _ = tx.send(Err(Status::unavailable("Server is shutting down"))).await;
+                            break;
+                        }
+                    }
+                    _ = tx.closed() => break,
+                }
+            }
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
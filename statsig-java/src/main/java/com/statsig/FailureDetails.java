@@ -1,12 +1,16 @@
 package com.statsig;
 
 import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonProperty;
 
 @JsonIgnoreProperties(ignoreUnknown = true)
 public class FailureDetails {
   public String reason;
   public Object error;
 
+  @JsonProperty("error_info")
+  public StatsigErrorInfo errorInfo;
+
   /** Default constructor for Jackson deserialization. */
   public FailureDetails() {}
 
@@ -17,4 +21,8 @@ public class FailureDetails {
   public Object getError() {
     return error;
   }
+
+  public StatsigErrorInfo getErrorInfo() {
+    return errorInfo;
+  }
 }
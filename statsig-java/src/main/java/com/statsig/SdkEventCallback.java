@@ -0,0 +1,22 @@
+package com.statsig;
+
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.statsig.internal.JacksonUtil;
+import java.util.Map;
+
+/** Called from native code with the event serialized as JSON. */
+class SdkEventCallback {
+  private final SdkEventListener listener;
+
+  SdkEventCallback(SdkEventListener listener) {
+    this.listener = listener;
+  }
+
+  void onEvent(String eventJson) {
+    Map<String, Object> event =
+        JacksonUtil.fromJson(eventJson, new TypeReference<Map<String, Object>>() {});
+    if (event != null) {
+      listener.onEvent(event);
+    }
+  }
+}
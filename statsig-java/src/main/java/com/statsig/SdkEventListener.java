@@ -0,0 +1,14 @@
+package com.statsig;
+
+import java.util.Map;
+
+/**
+ * Receives SDK events (e.g. "gate_evaluated") subscribed to through {@link
+ * Statsig#subscribe(String, SdkEventListener)}.
+ *
+ * <p>Listeners are invoked synchronously on the thread that triggered the event, so they should
+ * return quickly and must not subscribe or unsubscribe from within the callback.
+ */
+public interface SdkEventListener {
+  void onEvent(Map<String, Object> event);
+}
@@ -157,6 +157,11 @@ public class Statsig {
     return Experiment.fromJson(experJson);
   }
 
+  public Experiment getExperimentByGroupName(String experimentName, String groupName) {
+    String experJson = StatsigJNI.statsigGetExperimentByGroupName(ref, experimentName, groupName);
+    return Experiment.fromJson(experJson);
+  }
+
   public void manuallyLogExperimentExposure(StatsigUser user, String experimentName) {
     StatsigJNI.statsigLogExperimentExposure(ref, user.getRef(), experimentName);
   }
@@ -574,6 +579,43 @@ public class Statsig {
     StatsigJNI.statsigIdentify(ref, user.getRef());
   }
 
+  /**
+   * Subscribes to an SDK event, such as "gate_evaluated", or "*" for all events.
+   *
+   * <p>The listener is called on the thread that triggered the event. Exceptions thrown by the
+   * listener are printed and otherwise ignored.
+   *
+   * @param eventName The name of the event to subscribe to
+   * @param listener The listener to call with the event
+   * @return The subscription ID, for use with {@link #unsubscribeById(String)}
+   */
+  public String subscribe(String eventName, SdkEventListener listener) {
+    return StatsigJNI.statsigSubscribe(ref, eventName, new SdkEventCallback(listener));
+  }
+
+  /**
+   * Removes all listeners for the specified event.
+   *
+   * @param eventName The name of the event to unsubscribe from
+   */
+  public void unsubscribe(String eventName) {
+    StatsigJNI.statsigUnsubscribe(ref, eventName);
+  }
+
+  /**
+   * Removes a single listener.
+   *
+   * @param subscriptionId The ID returned by {@link #subscribe(String, SdkEventListener)}
+   */
+  public void unsubscribeById(String subscriptionId) {
+    StatsigJNI.statsigUnsubscribeById(ref, subscriptionId);
+  }
+
+  /** Removes all listeners for all events. */
+  public void unsubscribeAll() {
+    StatsigJNI.statsigUnsubscribeAll(ref);
+  }
+
   void logLayerParamExposure(String layerJson, String param) {
     StatsigJNI.statsigLogLayerParamExposure(ref, layerJson, param);
   }
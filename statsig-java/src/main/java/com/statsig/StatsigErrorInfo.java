@@ -0,0 +1,44 @@
+package com.statsig;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Map;
+
+/**
+ * Stable description of an SDK error. {@code code} and {@code category} match the values exposed
+ * by every other Statsig server core binding.
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class StatsigErrorInfo {
+  public String code;
+
+  @JsonProperty("code_value")
+  public int codeValue;
+
+  public String category;
+  public String message;
+  public Map<String, Object> context;
+
+  /** Default constructor for Jackson deserialization. */
+  public StatsigErrorInfo() {}
+
+  public String getCode() {
+    return code;
+  }
+
+  public int getCodeValue() {
+    return codeValue;
+  }
+
+  public String getCategory() {
+    return category;
+  }
+
+  public String getMessage() {
+    return message;
+  }
+
+  public Map<String, Object> getContext() {
+    return context;
+  }
+}
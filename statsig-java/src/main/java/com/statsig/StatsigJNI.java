@@ -57,6 +57,9 @@ class StatsigJNI {
   public static native String statsigGetExperiment(
       long statsigRef, long userRef, String experimentName, GetExperimentOptions options);
 
+  public static native String statsigGetExperimentByGroupName(
+      long statsigRef, String experimentName, String groupName);
+
   public static native void statsigLogExperimentExposure(
       long statsigRef, long userRef, String experimentName);
 
@@ -196,6 +199,15 @@ class StatsigJNI {
 
   public static native void statsigIdentify(long statsigRef, long userRef);
 
+  public static native String statsigSubscribe(
+      long statsigRef, String eventName, SdkEventCallback callback);
+
+  public static native void statsigUnsubscribe(long statsigRef, String eventName);
+
+  public static native void statsigUnsubscribeById(long statsigRef, String subscriptionId);
+
+  public static native void statsigUnsubscribeAll(long statsigRef);
+
   // --------------------------------------------------------------------------------------------------- [StatsigUser]
 
   public static native long statsigUserCreate(
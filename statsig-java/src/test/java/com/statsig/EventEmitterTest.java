@@ -0,0 +1,74 @@
+package com.statsig;
+
+import static org.junit.jupiter.api.Assertions.*;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+import java.util.Map;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+
+public class EventEmitterTest {
+  private Statsig statsig;
+  private StatsigUser user;
+
+  @BeforeEach
+  public void setUp() {
+    StatsigOptions opt = new StatsigOptions.Builder().setDisableNetwork(true).build();
+    statsig = new Statsig("secret-key", opt);
+    statsig.overrideGate("test_gate", true);
+    user = new StatsigUser.Builder().setUserID("123").build();
+  }
+
+  @Test
+  public void testSubscribeToGateEvaluations() {
+    List<Map<String, Object>> events = Collections.synchronizedList(new ArrayList<>());
+    statsig.subscribe("gate_evaluated", events::add);
+
+    statsig.checkGate(user, "test_gate");
+
+    assertEquals(1, events.size());
+    assertEquals("gate_evaluated", events.get(0).get("event_name"));
+    assertEquals("test_gate", events.get(0).get("gate_name"));
+    assertEquals(true, events.get(0).get("value"));
+  }
+
+  @Test
+  public void testUnsubscribeById() {
+    List<Map<String, Object>> kept = new ArrayList<>();
+    List<Map<String, Object>> removed = new ArrayList<>();
+
+    statsig.subscribe("gate_evaluated", kept::add);
+    String subscriptionId = statsig.subscribe("*", removed::add);
+    statsig.unsubscribeById(subscriptionId);
+
+    statsig.checkGate(user, "test_gate");
+
+    assertEquals(1, kept.size());
+    assertEquals(0, removed.size());
+  }
+
+  @Test
+  public void testUnsubscribeAll() {
+    List<Map<String, Object>> events = new ArrayList<>();
+    statsig.subscribe("gate_evaluated", events::add);
+    statsig.subscribe("*", events::add);
+
+    statsig.unsubscribeAll();
+    statsig.checkGate(user, "test_gate");
+
+    assertEquals(0, events.size());
+  }
+
+  @Test
+  public void testListenerExceptionsDoNotBreakEvaluation() {
+    statsig.subscribe(
+        "gate_evaluated",
+        event -> {
+          throw new RuntimeException("Bad listener");
+        });
+
+    assertTrue(statsig.checkGate(user, "test_gate"));
+  }
+}
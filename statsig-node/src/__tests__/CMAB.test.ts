@@ -0,0 +1,76 @@
+import * as fs from 'node:fs';
+import * as path from 'node:path';
+
+import { Statsig, StatsigOptions, StatsigUser } from '../../build/index.js';
+import { MockScrapi } from './MockScrapi';
+
+describe('CMAB', () => {
+  let statsig: Statsig;
+  let scrapi: MockScrapi;
+
+  beforeAll(async () => {
+    scrapi = await MockScrapi.create();
+
+    const dcs = fs.readFileSync(
+      path.join(
+        __dirname,
+        '../../../statsig-rust/tests/data/dcs_with_cmab.json',
+      ),
+      'utf8',
+    );
+
+    scrapi.mock('/v2/download_config_specs', dcs, {
+      status: 200,
+      method: 'GET',
+    });
+
+    scrapi.mock('/v1/log_event', '{"success": true}', {
+      status: 202,
+      method: 'POST',
+    });
+
+    const options: StatsigOptions = {
+      specsUrl: scrapi.getUrlForPath('/v2/download_config_specs'),
+      logEventUrl: scrapi.getUrlForPath('/v1/log_event'),
+    };
+
+    statsig = new Statsig('secret-123', options);
+    await statsig.initialize();
+  });
+
+  afterAll(async () => {
+    await statsig.shutdown();
+    scrapi.close();
+  });
+
+  it('ranks the groups by score', () => {
+    const user = StatsigUser.withUserID('a-user');
+    const groups = statsig.getCMABRankedGroups(user, 'test_cmab');
+
+    expect(groups.map((g) => g.variantName)).toEqual(['Green', 'Blue']);
+    expect(groups[0].score).toEqual(2);
+    expect(groups[0].ruleID).toEqual('green_group_id');
+    expect(groups[0].cmabName).toEqual('test_cmab');
+    expect(groups[0].value).toEqual({ button_color: 'green' });
+  });
+
+  it('returns no groups for unknown cmabs', () => {
+    const user = StatsigUser.withUserID('a-user');
+    expect(statsig.getCMABRankedGroups(user, 'not_a_cmab')).toEqual([]);
+  });
+
+  it('logs an exposure for the chosen group', async () => {
+    const user = StatsigUser.withUserID('a-user');
+    statsig.logCMABExposureForGroup(user, 'test_cmab', 'blue_group_id');
+    await statsig.flushEvents();
+
+    const events = scrapi.requests
+      .filter((r) => r.path === '/v1/log_event')
+      .flatMap((r) => r.body.events)
+      .filter((e: any) => e.eventName === 'statsig::config_exposure');
+
+    expect(events).toHaveLength(1);
+    expect(events[0].metadata.config).toEqual('test_cmab');
+    expect(events[0].metadata.ruleID).toEqual('blue_group_id');
+  });
+});
@@ -12,6 +12,9 @@ pub struct ClientInitResponseOptions {
     pub dynamic_config_filter: Option<HashSet<String>>,
     pub layer_filter: Option<HashSet<String>>,
     pub param_store_filter: Option<HashSet<String>>,
+    pub exclude_filter: Option<Vec<String>>,
+    pub entity_type_filter: Option<HashSet<String>>,
+    pub target_app_id_filter: Option<HashSet<String>>,
 }
 
 impl From<ClientInitResponseOptions> for ClientInitResponseOptionsActual {
@@ -30,7 +33,11 @@ impl From<ClientInitResponseOptions> for ClientInitResponseOptionsActual {
             dynamic_config_filter: options.dynamic_config_filter,
             layer_filter: options.layer_filter,
             param_store_filter: options.param_store_filter,
+            exclude_filter: options.exclude_filter,
+            entity_type_filter: options.entity_type_filter,
+            target_app_id_filter: options.target_app_id_filter,
             response_format: None,
+            signing_key: None,
         }
     }
 }
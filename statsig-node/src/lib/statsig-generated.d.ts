@@ -73,6 +73,8 @@ export declare class StatsigNapiInternal {
   manuallyLogDynamicConfigExposure(user: StatsigUser, configName: string): void
   manuallyLogExperimentExposure(user: StatsigUser, experimentName: string): void
   manuallyLogLayerParamExposure(user: StatsigUser, layerName: string, paramName: string): void
+  getCMABRankedGroups(user: StatsigUser, cmabName: string): Array<CMABRankedGroup>
+  logCMABExposureForGroup(user: StatsigUser, cmabName: string, groupId: string): void
   overrideGate(gateName: string, value: boolean, id?: string | undefined | null): void
   overrideDynamicConfig(configName: string, value: Record<string, any>, id?: string | undefined | null): void
   overrideExperiment(experimentName: string, value: Record<string, any>, id?: string | undefined | null): void
@@ -121,6 +123,14 @@ export declare function __internal__testObservabilityClient(client: Observabilit
 
 export declare function __internal__testPersistentStorage(store: PersistentStorage, action: string, key?: string | undefined | null, configName?: string | undefined | null, data?: any | undefined | null): Promise<Record<string, string> | null>
 
+export interface CMABRankedGroup {
+  score: number
+  variantName: string
+  ruleID: string
+  value?: Record<string, any>
+  cmabName: string
+}
+
 export interface ClientInitResponseOptions {
   hashAlgorithm?: string
   clientSdkKey?: string
@@ -130,6 +140,9 @@ export interface ClientInitResponseOptions {
   dynamicConfigFilter?: Set<string>
   layerFilter?: Set<string>
   paramStoreFilter?: Set<string>
+  excludeFilter?: Array<string>
+  entityTypeFilter?: Set<string>
+  targetAppIdFilter?: Set<string>
 }
 
 export interface DataStore {
@@ -253,6 +266,7 @@ export interface StatsigOptions {
   configCompressionMode?: 'gzip' | 'dictionary'
   overrideAdapterConfig?: Array<OverrideAdapterConfig>
   serviceName?: string
+  sharedSpecsPath?: string
   persistentStorage?: PersistentStorage
   specAdaptersConfig?: Array<SpecAdapterConfig>
   specsSyncIntervalMs?: number
@@ -265,6 +279,10 @@ export interface StatsigOptions {
 export interface StatsigResult {
   isSuccess: boolean
   error?: string
+  errorCode?: string
+  errorCodeValue?: number
+  errorCategory?: string
+  errorContext?: Record<string, string>
 }
 
 export interface StatsigUserArgs {
@@ -9,7 +9,9 @@ use crate::statsig_core_api_options_napi::{
 use crate::statsig_metadata_napi;
 use crate::statsig_options_napi::StatsigOptions;
 use crate::statsig_result::StatsigResult;
-use crate::statsig_types_napi::{DynamicConfig, Experiment, FeatureGate, Layer, ParameterStore};
+use crate::statsig_types_napi::{
+    CMABRankedGroup, DynamicConfig, Experiment, FeatureGate, Layer, ParameterStore,
+};
 use crate::statsig_user_napi::StatsigUser;
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
@@ -65,16 +67,10 @@ impl StatsigNapiInternal {
     pub async fn initialize(&self) -> StatsigResult {
         if let Err(e) = self.inner.initialize().await {
             log_e!(TAG, "Failed to initialize Statsig: {}", e);
-            return StatsigResult {
-                is_success: false,
-                error: Some(e.to_string()),
-            };
+            return StatsigResult::from_err(&e);
         }
 
-        StatsigResult {
-            is_success: true,
-            error: None,
-        }
+        StatsigResult::success()
     }
 
     #[napi]
@@ -101,7 +97,7 @@ impl StatsigNapiInternal {
         let timeout = Duration::from_millis(timeout_ms.unwrap_or(3000) as u64);
         let result = match self.inner.shutdown_with_timeout(timeout).await {
             Ok(_) => StatsigResult::success(),
-            Err(e) => StatsigResult::error(e.to_string()),
+            Err(e) => StatsigResult::from_err(&e),
         };
 
         // held until the shutdown is complete
@@ -343,6 +339,30 @@ impl StatsigNapiInternal {
             .manually_log_layer_parameter_exposure(user.as_inner(), &layer_name, param_name);
     }
 
+    #[napi(js_name = "getCMABRankedGroups")]
+    pub fn get_cmab_ranked_groups(
+        &self,
+        user: &StatsigUser,
+        cmab_name: String,
+    ) -> Vec<CMABRankedGroup> {
+        self.inner
+            .get_cmab_ranked_groups(user.as_inner(), &cmab_name)
+            .into_iter()
+            .map(|group| group.into())
+            .collect()
+    }
+
+    #[napi(js_name = "logCMABExposureForGroup")]
+    pub fn log_cmab_exposure_for_group(
+        &self,
+        user: &StatsigUser,
+        cmab_name: String,
+        group_id: String,
+    ) {
+        self.inner
+            .log_cmab_exposure_for_group(user.as_inner(), &cmab_name, group_id);
+    }
+
     #[napi]
     pub fn override_gate(&self, gate_name: String, value: bool, id: Option<String>) {
         self.inner.override_gate(&gate_name, value, id.as_deref());
@@ -104,6 +104,7 @@ pub struct StatsigOptions {
     pub config_compression_mode: Option<String>,
     pub override_adapter_config: Option<Vec<OverrideAdapterConfig>>,
     pub service_name: Option<String>,
+    pub shared_specs_path: Option<String>,
     #[napi(ts_type = "PersistentStorage")]
     pub persistent_storage: Option<PersistentStorageNapi>,
 
@@ -161,6 +162,7 @@ impl StatsigOptions {
                 .spec_adapters_config
                 .map(|configs| configs.into_iter().map(|c| c.into()).collect()),
             service_name: self.service_name,
+            shared_specs_path: self.shared_specs_path,
             disable_all_logging: self.disable_all_logging,
             disable_network: self.disable_network,
             event_logging_max_queue_size: self.event_logging_max_queue_size,
@@ -209,6 +211,7 @@ impl From<SpecAdapterConfig> for SpecAdapterConfigActual {
             client_cert_path: val.client_cert_path,
             client_key_path: val.client_key_path,
             domain_name: val.domain_name,
+            grpc_stream_config: None,
         }
     }
 }
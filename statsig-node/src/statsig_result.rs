@@ -1,9 +1,15 @@
 use napi_derive::*;
+use statsig_rust::StatsigErr;
+use std::collections::HashMap;
 
 #[napi(object)]
 pub struct StatsigResult {
     pub is_success: bool,
     pub error: Option<String>,
+    pub error_code: Option<String>,
+    pub error_code_value: Option<u16>,
+    pub error_category: Option<String>,
+    pub error_context: Option<HashMap<String, String>>,
 }
 
 impl StatsigResult {
@@ -11,13 +17,22 @@ impl StatsigResult {
         Self {
             is_success: true,
             error: None,
+            error_code: None,
+            error_code_value: None,
+            error_category: None,
+            error_context: None,
         }
     }
 
-    pub fn error(error: String) -> Self {
+    pub fn from_err(err: &StatsigErr) -> Self {
+        let code = err.code();
         Self {
             is_success: false,
-            error: Some(error),
+            error: Some(err.to_string()),
+            error_code: Some(code.as_str().to_string()),
+            error_code_value: Some(code.as_u16()),
+            error_category: Some(code.category().as_str().to_string()),
+            error_context: Some(err.context().to_map()),
         }
     }
 }
@@ -8,7 +8,8 @@ use statsig_rust::{
         FeatureGate as FeatureGateActual, Layer as LayerActual,
         ParameterStore as ParameterStoreActual,
     },
-    EvaluationDetails as EvaluationDetailsActual, SecondaryExposure as SecondaryExposureActual,
+    CMABRankedGroup as CMABRankedGroupActual, EvaluationDetails as EvaluationDetailsActual,
+    SecondaryExposure as SecondaryExposureActual,
 };
 
 use crate::statsig_user_napi::StatsigUser;
@@ -48,6 +49,28 @@ impl From<SecondaryExposureActual> for SecondaryExposure {
     }
 }
 
+#[napi(object)]
+pub struct CMABRankedGroup {
+    pub score: f64,
+    pub variant_name: String,
+    #[napi(js_name = "ruleID")]
+    pub rule_id: String,
+    pub value: Option<HashMap<String, Value>>,
+    pub cmab_name: String,
+}
+
+impl From<CMABRankedGroupActual> for CMABRankedGroup {
+    fn from(group: CMABRankedGroupActual) -> Self {
+        CMABRankedGroup {
+            score: group.score,
+            variant_name: group.variant_name,
+            rule_id: group.rule_id.unperformant_to_string(),
+            value: group.value,
+            cmab_name: group.cmab_name,
+        }
+    }
+}
+
 #[napi(object)]
 pub struct FeatureGate {
     pub name: String,
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, sync::Arc};
+use std::collections::HashMap;
 
 use napi::bindgen_prelude::{Either3, Either5};
 use napi_derive::napi;
@@ -95,9 +95,7 @@ impl StatsigUser {
         user_data.private_attributes = Self::convert_to_dynamic_value_map(args.private_attributes);
 
         Self {
-            inner: StatsigUserActual {
-                data: Arc::new(user_data),
-            },
+            inner: StatsigUserActual::new(user_data),
         }
     }
 
@@ -116,12 +114,10 @@ impl StatsigUser {
         >,
     ) -> Self {
         Self {
-            inner: StatsigUserActual {
-                data: Arc::new(UserData {
-                    custom_ids: Some(Self::convert_custom_ids(custom_ids)),
-                    ..UserData::default()
-                }),
-            },
+            inner: StatsigUserActual::new(UserData {
+                custom_ids: Some(Self::convert_custom_ids(custom_ids)),
+                ..UserData::default()
+            }),
         }
     }
 
@@ -186,7 +182,7 @@ macro_rules! add_hashmap_getter_setter {
             pub fn $field_accessor(&self) -> Option<HashMap<String, String>> {
                 let mut result: HashMap<String, String> = HashMap::new();
 
-                let value_map = match &self.inner.data.$field_accessor {
+                let value_map = match &self.inner.get_user_data().$field_accessor {
                     Some(value) => value,
                     _ => return None,
                 };
@@ -205,7 +201,7 @@ macro_rules! add_hashmap_getter_setter {
                 let value = match value {
                     Some(value) => value,
                     _ => {
-                        let mut_data = Arc::make_mut(&mut self.inner.data);
+                        let mut_data = self.inner.get_user_data_mut();
                         mut_data.$field_accessor = None;
                         return;
                     }
@@ -225,7 +221,7 @@ macro_rules! add_hashmap_getter_setter {
                     converted.insert(key, DynamicValue::from(value));
                 }
 
-                let mut_data = Arc::make_mut(&mut self.inner.data);
+                let mut_data = self.inner.get_user_data_mut();
                 mut_data.$field_accessor = Some(converted);
             }
         }
@@ -238,7 +234,7 @@ macro_rules! add_string_getter_setter {
         impl StatsigUser {
             #[napi(getter, js_name = $field_name)]
             pub fn $field_accessor(&self) -> Option<String> {
-                match &self.inner.data.$field_accessor {
+                match &self.inner.get_user_data().$field_accessor {
                     Some(value) => value.string_value.clone().map(|s| s.value),
                     _ => None,
                 }
@@ -248,11 +244,11 @@ macro_rules! add_string_getter_setter {
             pub fn $setter_name(&mut self, value: Value) {
                 match value {
                     Value::Null => {
-                        let mut_data = Arc::make_mut(&mut self.inner.data);
+                        let mut_data = self.inner.get_user_data_mut();
                         mut_data.$field_accessor = None;
                     }
                     _ => {
-                        let mut_data = Arc::make_mut(&mut self.inner.data);
+                        let mut_data = self.inner.get_user_data_mut();
                         mut_data.$field_accessor = Some(dyn_value!(value));
                     }
                 }
@@ -4,6 +4,13 @@
 import builtins
 import typing
 
+class CMABRankedGroup:
+    score: builtins.float
+    variant_name: builtins.str
+    rule_id: builtins.str
+    value: typing.Optional[typing.Any]
+    cmab_name: builtins.str
+
 class DataStoreBase:
     def __new__(cls,): ...
     ...
@@ -76,6 +83,10 @@ class ExperimentEvaluationOptions:
 class FailureDetails:
     reason: builtins.str
     error: typing.Optional[builtins.str]
+    error_code: typing.Optional[builtins.str]
+    error_code_value: typing.Optional[builtins.int]
+    error_category: typing.Optional[builtins.str]
+    error_context: typing.Optional[builtins.dict[builtins.str, builtins.str]]
 
 class FeatureGate:
     name: builtins.str
@@ -217,9 +228,18 @@ class StatsigBasePy:
     def get_experiment(self, user:StatsigUser, name:builtins.str, options:typing.Optional[ExperimentEvaluationOptions]=None) -> Experiment:
         ...
 
+    def get_experiment_by_group_name(self, experiment_name:builtins.str, group_name:builtins.str) -> Experiment:
+        ...
+
     def manually_log_experiment_exposure(self, user:StatsigUser, name:builtins.str) -> None:
         ...
 
+    def get_cmab_ranked_groups(self, user:StatsigUser, cmab_name:builtins.str) -> builtins.list[CMABRankedGroup]:
+        ...
+
+    def log_cmab_exposure_for_group(self, user:StatsigUser, cmab_name:builtins.str, group_id:builtins.str) -> None:
+        ...
+
     def get_layer(self, user:StatsigUser, name:builtins.str, options:typing.Optional[LayerEvaluationOptions]=None) -> Layer:
         ...
 
@@ -280,17 +300,38 @@ class StatsigBasePy:
     def identify(self, user:StatsigUser) -> None:
         ...
 
+    def subscribe(self, event_name:builtins.str, callback:typing.Any) -> builtins.str:
+        r"""
+        Calls `callback` with a dict describing each matching event, on the thread that ran the
+        evaluation. Returns a subscription id.
+        """
+        ...
+
+    def unsubscribe(self, event_name:builtins.str) -> None:
+        ...
+
+    def unsubscribe_by_id(self, subscription_id:builtins.str) -> None:
+        ...
+
+    def unsubscribe_all(self) -> None:
+        ...
+
 
 class StatsigOptions:
     specs_url: typing.Optional[builtins.str]
     specs_sync_interval_ms: typing.Optional[builtins.int]
     init_timeout_ms: typing.Optional[builtins.int]
+    max_specs_age_ms: typing.Optional[builtins.int]
+    stale_specs_policy: typing.Optional[builtins.str]
+    gcir_cache_max_entries: typing.Optional[builtins.int]
     log_event_url: typing.Optional[builtins.str]
     disable_all_logging: typing.Optional[builtins.bool]
     disable_network: typing.Optional[builtins.bool]
     event_logging_flush_interval_ms: typing.Optional[builtins.int]
     event_logging_max_queue_size: typing.Optional[builtins.int]
     event_logging_max_pending_batch_queue_size: typing.Optional[builtins.int]
+    exposure_dedupe_shared_memory_path: typing.Optional[builtins.str]
+    shared_specs_path: typing.Optional[builtins.str]
     enable_id_lists: typing.Optional[builtins.bool]
     wait_for_user_agent_init: typing.Optional[builtins.bool]
     wait_for_country_lookup_init: typing.Optional[builtins.bool]
@@ -309,7 +350,7 @@ class StatsigOptions:
     config_compression_mode: typing.Optional[builtins.str]
     proxy_config: typing.Optional[ProxyConfig]
     spec_adapter_configs: typing.Optional[list]
-    def __new__(cls,specs_url:typing.Optional[builtins.str]=None, specs_sync_interval_ms:typing.Optional[builtins.int]=None, init_timeout_ms:typing.Optional[builtins.int]=None, log_event_url:typing.Optional[builtins.str]=None, disable_all_logging:typing.Optional[builtins.bool]=None, disable_network:typing.Optional[builtins.bool]=None, event_logging_flush_interval_ms:typing.Optional[builtins.int]=None, event_logging_max_queue_size:typing.Optional[builtins.int]=None, event_logging_max_pending_batch_queue_size:typing.Optional[builtins.int]=None, enable_id_lists:typing.Optional[builtins.bool]=None, wait_for_user_agent_init:typing.Optional[builtins.bool]=None, wait_for_country_lookup_init:typing.Optional[builtins.bool]=None, disable_user_agent_parsing:typing.Optional[builtins.bool]=None, disable_country_lookup:typing.Optional[builtins.bool]=None, id_lists_url:typing.Optional[builtins.str]=None, id_lists_sync_interval_ms:typing.Optional[builtins.int]=None, fallback_to_statsig_api:typing.Optional[builtins.bool]=None, environment:typing.Optional[builtins.str]=None, output_log_level:typing.Optional[builtins.str]=None, global_custom_fields:typing.Optional[typing.Mapping[builtins.str, typing.Union[builtins.str, builtins.int, builtins.float, builtins.bool, typing.List[typing.Union[builtins.str, builtins.int, builtins.float, builtins.bool]]]]]=None, observability_client:typing.Optional[ObservabilityClientBase]=None, data_store:typing.Optional[DataStoreBase]=None, persistent_storage:typing.Optional[PersistentStorageBaseClass]=None, config_compression_mode:typing.Optional[builtins.str]=None, proxy_config:typing.Optional[ProxyConfig]=None, output_logger_provider:typing.Optional[OutputLoggerProviderBase]=None, spec_adapter_configs:typing.Optional[list]=None): ...
+    def __new__(cls,specs_url:typing.Optional[builtins.str]=None, specs_sync_interval_ms:typing.Optional[builtins.int]=None, init_timeout_ms:typing.Optional[builtins.int]=None, log_event_url:typing.Optional[builtins.str]=None, disable_all_logging:typing.Optional[builtins.bool]=None, disable_network:typing.Optional[builtins.bool]=None, event_logging_flush_interval_ms:typing.Optional[builtins.int]=None, event_logging_max_queue_size:typing.Optional[builtins.int]=None, event_logging_max_pending_batch_queue_size:typing.Optional[builtins.int]=None, enable_id_lists:typing.Optional[builtins.bool]=None, wait_for_user_agent_init:typing.Optional[builtins.bool]=None, wait_for_country_lookup_init:typing.Optional[builtins.bool]=None, disable_user_agent_parsing:typing.Optional[builtins.bool]=None, disable_country_lookup:typing.Optional[builtins.bool]=None, id_lists_url:typing.Optional[builtins.str]=None, id_lists_sync_interval_ms:typing.Optional[builtins.int]=None, fallback_to_statsig_api:typing.Optional[builtins.bool]=None, environment:typing.Optional[builtins.str]=None, output_log_level:typing.Optional[builtins.str]=None, global_custom_fields:typing.Optional[typing.Mapping[builtins.str, typing.Union[builtins.str, builtins.int, builtins.float, builtins.bool, typing.List[typing.Union[builtins.str, builtins.int, builtins.float, builtins.bool]]]]]=None, observability_client:typing.Optional[ObservabilityClientBase]=None, data_store:typing.Optional[DataStoreBase]=None, persistent_storage:typing.Optional[PersistentStorageBaseClass]=None, config_compression_mode:typing.Optional[builtins.str]=None, proxy_config:typing.Optional[ProxyConfig]=None, output_logger_provider:typing.Optional[OutputLoggerProviderBase]=None, spec_adapter_configs:typing.Optional[list]=None, exposure_dedupe_shared_memory_path:typing.Optional[builtins.str]=None, max_specs_age_ms:typing.Optional[builtins.int]=None, stale_specs_policy:typing.Optional[builtins.str]=None, gcir_cache_max_entries:typing.Optional[builtins.int]=None, shared_specs_path:typing.Optional[builtins.str]=None): ...
 
 class StatsigUser:
     user_id: builtins.str
@@ -28,6 +28,7 @@ fn statsig_python_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<statsig_types_py::FeatureGatePy>()?;
     m.add_class::<statsig_types_py::DynamicConfigPy>()?;
     m.add_class::<statsig_types_py::ExperimentPy>()?;
+    m.add_class::<statsig_types_py::CMABRankedGroupPy>()?;
     m.add_class::<statsig_types_py::LayerPy>()?;
     m.add_class::<statsig_types_py::FeatureGateEvaluationOptionsPy>()?;
     m.add_class::<statsig_types_py::ExperimentEvaluationOptionsPy>()?;
@@ -3,8 +3,8 @@ use crate::safe_gil::SafeGil;
 use crate::statsig_options_py::{safe_convert_to_statsig_options, StatsigOptionsPy};
 use crate::statsig_persistent_storage_override_adapter_py::convert_dict_to_user_persisted_values;
 use crate::statsig_types_py::{
-    DynamicConfigPy, InitializeDetailsPy, LayerPy, ParameterStoreEvaluationOptionsPy,
-    ParameterStorePy,
+    CMABRankedGroupPy, DynamicConfigPy, InitializeDetailsPy, LayerPy,
+    ParameterStoreEvaluationOptionsPy, ParameterStorePy,
 };
 use crate::{
     statsig_types_py::{
@@ -17,6 +17,7 @@ use parking_lot::Mutex;
 use pyo3::types::PyTuple;
 use pyo3::{prelude::*, types::PyDict};
 use pyo3_stub_gen::derive::*;
+use statsig_rust::sdk_event_emitter::SubscriptionID;
 use statsig_rust::{
     log_e, unwrap_or_return, ClientInitResponseOptions, DynamicConfigEvaluationOptions,
     ExperimentEvaluationOptions, FeatureGateEvaluationOptions, HashAlgorithm,
@@ -96,10 +97,8 @@ impl StatsigBasePy {
                         call_completion_future(&future_clone, py, (py_details,));
                     }
                     Err(e) => {
-                        let error_details = InitializeDetailsPy::from_error(
-                            "initialize_failed",
-                            Some(e.to_string()),
-                        );
+                        let error_details =
+                            InitializeDetailsPy::from_error("initialize_failed", Some(&e));
                         call_completion_future(&future_clone, py, (error_details,));
                     }
                 };
@@ -111,8 +110,7 @@ impl StatsigBasePy {
                 TAG,
                 "Failed to spawn statsig initialize with details task: {e}"
             );
-            let error_details =
-                InitializeDetailsPy::from_error("initialize_failed", Some(e.to_string()));
+            let error_details = InitializeDetailsPy::from_error("initialize_failed", Some(&e));
             call_completion_future(&future, py, (error_details,));
         }
 
@@ -331,6 +329,28 @@ impl StatsigBasePy {
         }
     }
 
+    #[pyo3(signature = (experiment_name, group_name))]
+    pub fn get_experiment_by_group_name(
+        &self,
+        experiment_name: &str,
+        group_name: &str,
+        py: Python,
+    ) -> ExperimentPy {
+        let experiment = self
+            .inner
+            .get_experiment_by_group_name(experiment_name, group_name);
+
+        ExperimentPy {
+            name: experiment.name.clone(),
+            rule_id: experiment.rule_id.clone(),
+            id_type: experiment.id_type.clone(),
+            group_name: experiment.group_name.clone(),
+            value: map_to_py_dict(py, &experiment.value),
+            details: experiment.details.clone().into(),
+            inner: experiment,
+        }
+    }
+
     #[pyo3(signature = (user, name))]
     pub fn manually_log_experiment_exposure(
         &self,
@@ -342,6 +362,32 @@ impl StatsigBasePy {
         Ok(())
     }
 
+    #[pyo3(signature = (user, cmab_name))]
+    pub fn get_cmab_ranked_groups(
+        &self,
+        user: &StatsigUserPy,
+        cmab_name: &str,
+        py: Python,
+    ) -> Vec<CMABRankedGroupPy> {
+        self.inner
+            .get_cmab_ranked_groups(&user.inner, cmab_name)
+            .into_iter()
+            .map(|group| CMABRankedGroupPy::new(py, group))
+            .collect()
+    }
+
+    #[pyo3(signature = (user, cmab_name, group_id))]
+    pub fn log_cmab_exposure_for_group(
+        &self,
+        user: &StatsigUserPy,
+        cmab_name: &str,
+        group_id: String,
+    ) -> PyResult<()> {
+        self.inner
+            .log_cmab_exposure_for_group(&user.inner, cmab_name, group_id);
+        Ok(())
+    }
+
     #[pyo3(signature = (user, name, options=None))]
     pub fn get_layer(
         &self,
@@ -575,6 +621,50 @@ impl StatsigBasePy {
         self.inner.identify(&user.inner);
         Ok(())
     }
+
+    /// Calls `callback` with a dict describing each matching event, on the thread that ran the
+    /// evaluation. Returns a subscription id.
+    #[pyo3(signature = (event_name, callback))]
+    pub fn subscribe(&self, event_name: &str, callback: PyObject) -> String {
+        let sub_id = self
+            .inner
+            .event_emitter
+            .subscribe(event_name, move |event| {
+                let event_map: HashMap<String, serde_json::Value> =
+                    event.to_json_map().into_iter().collect();
+
+                SafeGil::run(|py| {
+                    let py = match py {
+                        Some(py) => py,
+                        None => return,
+                    };
+
+                    let event_dict = map_to_py_dict(py, &event_map);
+                    if let Err(e) = callback.call1(py, (event_dict,)) {
+                        log_e!(TAG, "Failed to call event subscription callback: {:?}", e);
+                    }
+                });
+            });
+
+        sub_id.encode()
+    }
+
+    #[pyo3(signature = (event_name))]
+    pub fn unsubscribe(&self, event_name: &str) {
+        self.inner.event_emitter.unsubscribe(event_name);
+    }
+
+    #[pyo3(signature = (subscription_id))]
+    pub fn unsubscribe_by_id(&self, subscription_id: &str) {
+        match SubscriptionID::decode(subscription_id) {
+            Some(sub_id) => self.inner.event_emitter.unsubscribe_by_id(&sub_id),
+            None => log_e!(TAG, "Invalid subscription ID: {}", subscription_id),
+        }
+    }
+
+    pub fn unsubscribe_all(&self) {
+        self.inner.event_emitter.unsubscribe_all();
+    }
 }
 
 fn get_completion_event(py: Python) -> PyResult<(PyObject, PyObject)> {
@@ -13,7 +13,10 @@ use statsig_rust::data_store_interface::DataStoreTrait;
 use statsig_rust::networking::proxy_config::ProxyConfig;
 use statsig_rust::output_logger::OutputLogProvider;
 use statsig_rust::statsig_options::DEFAULT_INIT_TIMEOUT_MS;
-use statsig_rust::{log_w, ConfigCompressionMode, PersistentStorage, SpecAdapterConfig};
+use statsig_rust::{
+    log_w, ConfigCompressionMode, ExposureDedupeStore, PersistentStorage,
+    SharedMemoryExposureDedupeStore, SpecAdapterConfig, StaleSpecsPolicy,
+};
 use statsig_rust::{output_logger::LogLevel, ObservabilityClient, StatsigOptions};
 use std::collections::HashMap;
 use std::sync::{Arc, Weak};
@@ -62,6 +65,7 @@ impl From<SpecAdapterConfigPy> for SpecAdapterConfig {
             client_cert_path: None,
             domain_name: None,
             client_key_path: None,
+            grpc_stream_config: None,
         }
     }
 }
@@ -111,6 +115,12 @@ pub struct StatsigOptionsPy {
     #[pyo3(get, set)]
     pub init_timeout_ms: Option<u64>,
     #[pyo3(get, set)]
+    pub max_specs_age_ms: Option<u64>,
+    #[pyo3(get, set)]
+    pub stale_specs_policy: Option<String>,
+    #[pyo3(get, set)]
+    pub gcir_cache_max_entries: Option<u32>,
+    #[pyo3(get, set)]
     pub log_event_url: Option<String>,
     #[pyo3(get, set)]
     pub disable_all_logging: Option<bool>,
@@ -123,6 +133,10 @@ pub struct StatsigOptionsPy {
     #[pyo3(get, set)]
     pub event_logging_max_pending_batch_queue_size: Option<u32>,
     #[pyo3(get, set)]
+    pub exposure_dedupe_shared_memory_path: Option<String>,
+    #[pyo3(get, set)]
+    pub shared_specs_path: Option<String>,
+    #[pyo3(get, set)]
     pub enable_id_lists: Option<bool>,
     #[pyo3(get, set)]
     pub wait_for_user_agent_init: Option<bool>,
@@ -192,6 +206,11 @@ impl StatsigOptionsPy {
         proxy_config=None,
         output_logger_provider=None,
         spec_adapter_configs=None,
+        exposure_dedupe_shared_memory_path=None,
+        max_specs_age_ms=None,
+        stale_specs_policy=None,
+        gcir_cache_max_entries=None,
+        shared_specs_path=None,
     ))]
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -222,6 +241,11 @@ impl StatsigOptionsPy {
         proxy_config: Option<Py<ProxyConfigPy>>,
         output_logger_provider: Option<Py<OutputLoggerProviderBasePy>>,
         spec_adapter_configs: Option<Py<PyList>>,
+        exposure_dedupe_shared_memory_path: Option<String>,
+        max_specs_age_ms: Option<u64>,
+        stale_specs_policy: Option<String>,
+        gcir_cache_max_entries: Option<u32>,
+        shared_specs_path: Option<String>,
     ) -> Self {
         Self {
             specs_url,
@@ -251,6 +275,11 @@ impl StatsigOptionsPy {
             proxy_config,
             output_logger_provider,
             spec_adapter_configs,
+            exposure_dedupe_shared_memory_path,
+            max_specs_age_ms,
+            stale_specs_policy,
+            gcir_cache_max_entries,
+            shared_specs_path,
         }
     }
 }
@@ -293,6 +322,11 @@ fn create_inner_statsig_options(
         specs_adapter: None,
         specs_sync_interval_ms: opts.specs_sync_interval_ms,
         init_timeout_ms: opts.init_timeout_ms,
+        max_specs_age_ms: opts.max_specs_age_ms,
+        stale_specs_policy: opts
+            .stale_specs_policy
+            .as_ref()
+            .map(|policy| StaleSpecsPolicy::from(policy.as_str())),
         data_store: opts
             .data_store
             .and_then(|store| match store.extract::<DataStoreBasePy>(py) {
@@ -311,10 +345,22 @@ fn create_inner_statsig_options(
         event_logging_flush_interval_ms: None,
         event_logging_max_queue_size: opts.event_logging_max_queue_size,
         event_logging_max_pending_batch_queue_size: opts.event_logging_max_pending_batch_queue_size,
+        exposure_dedupe_store: opts
+            .exposure_dedupe_shared_memory_path
+            .as_deref()
+            .and_then(create_shared_exposure_dedupe_store),
         enable_id_lists: opts.enable_id_lists,
         id_lists_url: opts.id_lists_url.clone(),
         id_lists_sync_interval_ms: opts.id_lists_sync_interval_ms,
         fallback_to_statsig_api: opts.fallback_to_statsig_api,
+        gcir_cache_max_entries: opts.gcir_cache_max_entries,
+        computed_user_attributes: None,
+        geo_ip_provider: None,
+        enable_zero_copy_specs: None,
+        enable_delta_specs_updates: None,
+        user_agent_cache_max_entries: None,
+        user_agent_parser_provider: None,
+        user_agent_parser_type: None,
         environment: opts.environment.clone(),
         id_lists_adapter: None,
         override_adapter: None,
@@ -324,6 +370,7 @@ fn create_inner_statsig_options(
             .map(|level| LogLevel::from(level.as_str())),
         observability_client: ob_client_weak,
         service_name: None,
+        shared_specs_path: opts.shared_specs_path,
         wait_for_user_agent_init: opts.wait_for_user_agent_init,
         wait_for_country_lookup_init: opts.wait_for_user_agent_init,
         global_custom_fields,
@@ -397,3 +444,13 @@ fn extract_observability_client(
 
     (ob_client_weak, ob_client_strong)
 }
+
+fn create_shared_exposure_dedupe_store(path: &str) -> Option<Arc<dyn ExposureDedupeStore>> {
+    match SharedMemoryExposureDedupeStore::with_default_capacity(path) {
+        Ok(store) => Some(Arc::new(store)),
+        Err(e) => {
+            log_w!(TAG, "Failed to create shared exposure dedupe store: {}", e);
+            None
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use std::sync::Weak;
+use std::{collections::HashMap, sync::Weak};
 
 use pyo3::{
     prelude::*,
@@ -9,9 +9,9 @@ use serde_json::{json, Map, Value};
 use statsig_rust::{
     log_e,
     statsig_types::{DynamicConfig, Experiment, Layer},
-    DynamicConfigEvaluationOptions, EvaluationDetails, ExperimentEvaluationOptions, FailureDetails,
-    FeatureGateEvaluationOptions, InitializeDetails, LayerEvaluationOptions,
-    ParameterStoreEvaluationOptions, Statsig, StatsigUser,
+    CMABRankedGroup, DynamicConfigEvaluationOptions, EvaluationDetails,
+    ExperimentEvaluationOptions, FailureDetails, FeatureGateEvaluationOptions, InitializeDetails,
+    LayerEvaluationOptions, ParameterStoreEvaluationOptions, Statsig, StatsigErr, StatsigUser,
 };
 
 use crate::pyo_utils::{
@@ -55,17 +55,14 @@ impl From<InitializeDetails> for InitializeDetailsPy {
 }
 
 impl InitializeDetailsPy {
-    pub fn from_error(reason: &str, error: Option<String>) -> Self {
+    pub fn from_error(reason: &str, error: Option<&StatsigErr>) -> Self {
         InitializeDetailsPy {
             duration: 0.0,
             init_success: false,
             is_config_spec_ready: false,
             is_id_list_ready: None,
             source: "error".to_string(),
-            failure_details: Some(FailureDetailsPy {
-                reason: reason.to_string(),
-                error,
-            }),
+            failure_details: Some(FailureDetailsPy::new(reason, error)),
         }
     }
 }
@@ -79,16 +76,39 @@ pub struct FailureDetailsPy {
 
     #[pyo3(get)]
     pub error: Option<String>,
+
+    #[pyo3(get)]
+    pub error_code: Option<String>,
+
+    #[pyo3(get)]
+    pub error_code_value: Option<u16>,
+
+    #[pyo3(get)]
+    pub error_category: Option<String>,
+
+    #[pyo3(get)]
+    pub error_context: Option<HashMap<String, String>>,
 }
 
-impl From<FailureDetails> for FailureDetailsPy {
-    fn from(value: FailureDetails) -> Self {
+impl FailureDetailsPy {
+    fn new(reason: &str, error: Option<&StatsigErr>) -> Self {
         FailureDetailsPy {
-            reason: value.reason,
-            error: value.error.map(|e| e.to_string()),
+            reason: reason.to_string(),
+            error: error.map(|e| e.to_string()),
+            error_code: error.map(|e| e.code().as_str().to_string()),
+            error_code_value: error.map(|e| e.code().as_u16()),
+            error_category: error.map(|e| e.category().as_str().to_string()),
+            error_context: error.map(|e| e.context().to_map()),
         }
     }
 }
+
+impl From<FailureDetails> for FailureDetailsPy {
+    fn from(value: FailureDetails) -> Self {
+        FailureDetailsPy::new(&value.reason, value.error.as_ref())
+    }
+}
+
 #[gen_stub_pyclass]
 #[pyclass(name = "EvaluationDetails", module = "statsig_python_core")]
 #[derive(Clone)]
@@ -177,6 +197,37 @@ pub struct ExperimentPy {
     pub inner: Experiment,
 }
 
+#[gen_stub_pyclass]
+#[pyclass(name = "CMABRankedGroup", module = "statsig_python_core")]
+pub struct CMABRankedGroupPy {
+    #[pyo3(get)]
+    pub score: f64,
+
+    #[pyo3(get)]
+    pub variant_name: String,
+
+    #[pyo3(get)]
+    pub rule_id: String,
+
+    #[pyo3(get)]
+    pub value: Option<PyObject>,
+
+    #[pyo3(get)]
+    pub cmab_name: String,
+}
+
+impl CMABRankedGroupPy {
+    pub fn new(py: Python, group: CMABRankedGroup) -> Self {
+        CMABRankedGroupPy {
+            score: group.score,
+            variant_name: group.variant_name,
+            rule_id: group.rule_id.unperformant_to_string(),
+            value: group.value.map(|v| map_to_py_dict(py, &v)),
+            cmab_name: group.cmab_name,
+        }
+    }
+}
+
 #[gen_stub_pyclass]
 #[pyclass(name = "Layer", module = "statsig_python_core")]
 pub struct LayerPy {
@@ -6,7 +6,7 @@ use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use pyo3_stub_gen::derive::*;
 use statsig_rust::{log_e, log_w, DynamicValue, StatsigUser, StatsigUserData};
-use std::{collections::HashMap, str, sync::Arc};
+use std::{collections::HashMap, str};
 
 const TAG: &str = stringify!(StatsigUserPy);
 
@@ -146,7 +146,7 @@ impl StatsigUserPy {
 
     #[getter]
     fn get_custom_ids(&self) -> Option<HashMap<&str, &str>> {
-        let value = self.inner.data.custom_ids.as_ref()?;
+        let value = self.inner.get_user_data().custom_ids.as_ref()?;
 
         let mapped = value
             .iter()
@@ -172,7 +172,7 @@ impl StatsigUserPy {
 
     #[getter]
     fn get_custom(&self) -> Option<HashMap<&str, Option<ValidPrimitivesPyRef>>> {
-        get_map_field_ref(&self.inner.data.custom)
+        get_map_field_ref(&self.inner.get_user_data().custom)
     }
 
     #[setter]
@@ -202,7 +202,7 @@ impl StatsigUserPy {
 
     #[getter]
     fn get_private_attributes(&self) -> Option<HashMap<&str, Option<ValidPrimitivesPyRef>>> {
-        get_map_field_ref(&self.inner.data.private_attributes)
+        get_map_field_ref(&self.inner.get_user_data().private_attributes)
     }
 
     #[setter]
@@ -234,7 +234,7 @@ impl StatsigUserPy {
     // ---------------------------------------- [Pickling]
 
     pub fn __getstate__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
-        let bytes = match self.inner.data.to_bytes() {
+        let bytes = match self.inner.get_user_data().to_bytes() {
             Some(bytes) => bytes,
             None => {
                 log_e!(TAG, "Failed to serialize StatsigUser.");
@@ -255,9 +255,7 @@ impl StatsigUserPy {
             }
         };
 
-        let inner = StatsigUser {
-            data: Arc::new(user_data),
-        };
+        let inner = StatsigUser::new(user_data);
 
         *self = StatsigUserPy { inner };
         Ok(())
@@ -0,0 +1,93 @@
+import pytest
+from statsig_python_core import Statsig, StatsigOptions, StatsigUser
+from mock_scrapi import MockScrapi
+from utils import get_test_data_resource
+from pytest_httpserver import HTTPServer
+
+
+@pytest.fixture
+def statsig_setup(httpserver: HTTPServer):
+    mock_scrapi = MockScrapi(httpserver)
+    dcs_content = get_test_data_resource("dcs_with_cmab.json")
+    mock_scrapi.stub(
+        "/v2/download_config_specs/secret-key.json", response=dcs_content, method="GET"
+    )
+    mock_scrapi.stub("/v1/log_event", response='{"success": true}', method="POST")
+
+    options = StatsigOptions()
+    options.specs_url = mock_scrapi.url_for_endpoint("/v2/download_config_specs")
+    options.log_event_url = mock_scrapi.url_for_endpoint("/v1/log_event")
+    options.output_log_level = "none"
+
+    statsig = Statsig("secret-key", options)
+    statsig.initialize().wait()
+
+    yield statsig, mock_scrapi
+
+    statsig.shutdown().wait()
+
+
+def test_get_cmab_ranked_groups(statsig_setup):
+    statsig, _ = statsig_setup
+
+    groups = statsig.get_cmab_ranked_groups(StatsigUser("a-user"), "test_cmab")
+
+    assert [g.variant_name for g in groups] == ["Green", "Blue"]
+    assert groups[0].score == 2.0
+    assert groups[0].rule_id == "green_group_id"
+    assert groups[0].cmab_name == "test_cmab"
+    assert groups[0].value == {"button_color": "green"}
+
+
+def test_get_cmab_ranked_groups_unknown_cmab(statsig_setup):
+    statsig, _ = statsig_setup
+
+    assert statsig.get_cmab_ranked_groups(StatsigUser("a-user"), "not_a_cmab") == []
+
+
+def test_log_cmab_exposure_for_group(statsig_setup):
+    statsig, mock_scrapi = statsig_setup
+
+    statsig.log_cmab_exposure_for_group(
+        StatsigUser("a-user"), "test_cmab", "blue_group_id"
+    )
+    statsig.flush_events().wait()
+
+    events = [
+        e
+        for e in mock_scrapi.get_logged_events()
+        if e["eventName"] == "statsig::config_exposure"
+    ]
+
+    assert len(events) == 1
+    assert events[0]["metadata"]["config"] == "test_cmab"
+    assert events[0]["metadata"]["ruleID"] == "blue_group_id"
+
+
+def test_get_experiment_by_group_name(httpserver: HTTPServer):
+    mock_scrapi = MockScrapi(httpserver)
+    mock_scrapi.stub(
+        "/v2/download_config_specs/secret-key.json",
+        response=get_test_data_resource("eval_proj_dcs.json"),
+        method="GET",
+    )
+    mock_scrapi.stub("/v1/log_event", response='{"success": true}', method="POST")
+
+    options = StatsigOptions()
+    options.specs_url = mock_scrapi.url_for_endpoint("/v2/download_config_specs")
+    options.log_event_url = mock_scrapi.url_for_endpoint("/v1/log_event")
+    options.output_log_level = "none"
+
+    statsig = Statsig("secret-key", options)
+    statsig.initialize().wait()
+
+    experiment = statsig.get_experiment_by_group_name(
+        "test_experiment_no_targeting", "Control"
+    )
+
+    assert experiment.name == "test_experiment_no_targeting"
+    assert experiment.group_name == "Control"
+    assert experiment.rule_id == "54QJztEPRLXK7ZCvXeY9q4"
+    assert experiment.value["value"] == "control"
+
+    statsig.shutdown().wait()
@@ -0,0 +1,156 @@
+from statsig_python_core import Statsig, StatsigOptions, StatsigUser
+from pytest_httpserver import HTTPServer
+import json
+import threading
+from utils import get_test_data_resource
+import pytest
+
+
+@pytest.fixture
+def statsig_setup(httpserver: HTTPServer):
+    dcs_content = get_test_data_resource("eval_proj_dcs.json")
+    json_data = json.loads(dcs_content)
+
+    httpserver.expect_request(
+        "/v2/download_config_specs/secret-key.json"
+    ).respond_with_json(json_data)
+
+    httpserver.expect_request("/v1/log_event").respond_with_json({"success": True})
+
+    options = StatsigOptions(
+        specs_url=httpserver.url_for("/v2/download_config_specs"),
+        log_event_url=httpserver.url_for("/v1/log_event"),
+        output_log_level="debug",
+    )
+    statsig = Statsig("secret-key", options)
+
+    statsig.initialize().wait()
+
+    yield statsig
+
+    statsig.shutdown().wait()
+
+
+def test_subscribe_to_gate_evaluations(statsig_setup):
+    statsig = statsig_setup
+    events = []
+
+    statsig.subscribe("gate_evaluated", events.append)
+    statsig.check_gate(StatsigUser("a-user"), "test_public")
+
+    assert len(events) == 1
+    assert events[0]["event_name"] == "gate_evaluated"
+    assert events[0]["gate_name"] == "test_public"
+    assert events[0]["value"] is True
+
+
+def test_subscribe_to_all_events(statsig_setup):
+    statsig = statsig_setup
+    events = []
+
+    statsig.subscribe("*", events.append)
+    statsig.check_gate(StatsigUser("a-user"), "test_public")
+    statsig.get_experiment(StatsigUser("a-user"), "experiment_with_many_params")
+
+    assert [e["event_name"] for e in events] == [
+        "gate_evaluated",
+        "experiment_evaluated",
+    ]
+    assert events[1]["experiment"]["name"] == "experiment_with_many_params"
+
+
+def test_unsubscribe_by_id(statsig_setup):
+    statsig = statsig_setup
+    kept = []
+    removed = []
+
+    statsig.subscribe("gate_evaluated", kept.append)
+    sub_id = statsig.subscribe("gate_evaluated", removed.append)
+    statsig.unsubscribe_by_id(sub_id)
+
+    statsig.check_gate(StatsigUser("a-user"), "test_public")
+
+    assert len(kept) == 1
+    assert len(removed) == 0
+
+
+def test_unsubscribe_all(statsig_setup):
+    statsig = statsig_setup
+    events = []
+
+    statsig.subscribe("gate_evaluated", events.append)
+    statsig.subscribe("*", events.append)
+    statsig.unsubscribe_all()
+
+    statsig.check_gate(StatsigUser("a-user"), "test_public")
+
+    assert len(events) == 0
+
+
+def test_callback_errors_do_not_break_evaluation(statsig_setup):
+    statsig = statsig_setup
+
+    def raise_error(_event):
+        raise Exception("Bad callback")
+
+    statsig.subscribe("gate_evaluated", raise_error)
+
+    assert statsig.check_gate(StatsigUser("a-user"), "test_public")
+
+
+def test_callbacks_from_other_threads(statsig_setup):
+    statsig = statsig_setup
+    events = []
+
+    statsig.subscribe("gate_evaluated", events.append)
+
+    threads = [
+        threading.Thread(
+            target=lambda: statsig.check_gate(StatsigUser("a-user"), "test_public")
+        )
+        for _ in range(5)
+    ]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+
+    assert len(events) == 5
+
+
+def test_subscribe_while_callback_is_running(statsig_setup):
+    statsig = statsig_setup
+    callback_started = threading.Event()
+    subscribed = threading.Event()
+    events = []
+
+    def slow_callback(event):
+        callback_started.set()
+        # gives up the GIL while the other thread subscribes
+        subscribed.wait(timeout=5)
+        events.append(event)
+
+    statsig.subscribe("gate_evaluated", slow_callback)
+
+    # daemon threads, so a deadlock fails the test instead of hanging the run
+    evaluating = threading.Thread(
+        target=lambda: statsig.check_gate(StatsigUser("a-user"), "test_public"),
+        daemon=True,
+    )
+    evaluating.start()
+    assert callback_started.wait(timeout=5)
+
+    def subscribe():
+        statsig.subscribe("gate_evaluated", events.append)
+        subscribed.set()
+
+    subscribing = threading.Thread(target=subscribe, daemon=True)
+    subscribing.start()
+
+    subscribing.join(timeout=5)
+    evaluating.join(timeout=5)
+
+    assert not subscribing.is_alive()
+    assert not evaluating.is_alive()
+    assert subscribed.is_set()
+    assert len(events) == 1
@@ -3,6 +3,7 @@ use crate::{
     dyn_value, log_d, log_e, unwrap_or_return_with, user::StatsigUserInternal, DynamicValue,
 };
 use parking_lot::RwLock;
+use std::net::Ipv6Addr;
 use std::sync::Arc;
 
 pub struct CountryLookup;
@@ -10,6 +11,8 @@ pub struct CountryLookup;
 pub struct CountryLookupData {
     country_codes: Vec<String>,
     ip_ranges: Vec<i64>,
+    ipv6_country_codes: Vec<String>,
+    ipv6_ranges: Vec<u128>,
 }
 
 lazy_static::lazy_static! {
@@ -20,6 +23,12 @@ lazy_static::lazy_static! {
 const TAG: &str = "CountryLookup";
 const UNINITIALIZED_REASON: &str = "CountryLookupNotLoaded";
 
+// ip_supalite.table counts IPv4 ranges in /24 blocks, ip6_supalite.table counts IPv6 ranges in /48
+// blocks. Both tables use the same layout: a list of two character country codes terminated by
+// "**", followed by (block count, country code index) pairs with variable width counts.
+const IPV4_BLOCK_SIZE: u128 = 1 << 8;
+const IPV6_BLOCK_SIZE: u128 = 1 << 80;
+
 pub trait UsizeExt {
     fn post_inc(&mut self) -> Self;
 }
@@ -33,6 +42,12 @@ impl UsizeExt for usize {
 }
 
 impl CountryLookup {
+    pub fn is_loaded() -> bool {
+        COUNTRY_LOOKUP_DATA
+            .try_read_for(std::time::Duration::from_secs(5))
+            .is_some_and(|lock| lock.is_some())
+    }
+
     pub fn load_country_lookup() {
         match COUNTRY_LOOKUP_DATA.try_read_for(std::time::Duration::from_secs(5)) {
             Some(lock) => {
@@ -50,54 +65,20 @@ impl CountryLookup {
             }
         }
 
-        let bytes = include_bytes!("../../resources/ip_supalite.table");
-
-        let mut raw_code_lookup: Vec<String> = vec![];
-        let mut country_codes: Vec<String> = vec![];
-        let mut ip_ranges: Vec<i64> = vec![];
-
-        let mut i = 0;
-
-        while i < bytes.len() {
-            let c1 = bytes[i.post_inc()] as char;
-            let c2 = bytes[i.post_inc()] as char;
-
-            raw_code_lookup.push(format!("{c1}{c2}"));
-
-            if c1 == '*' {
-                break;
-            }
-        }
-
-        let longs = |index: usize| bytes[index] as i64;
-
-        let mut last_end_range = 0_i64;
-        while (i + 1) < bytes.len() {
-            let mut count: i64 = 0;
-            let n1 = longs(i.post_inc());
-            if n1 < 240 {
-                count = n1;
-            } else if n1 == 242 {
-                let n2 = longs(i.post_inc());
-                let n3 = longs(i.post_inc());
-                count = n2 | (n3 << 8);
-            } else if n1 == 243 {
-                let n2 = longs(i.post_inc());
-                let n3 = longs(i.post_inc());
-                let n4 = longs(i.post_inc());
-                count = n2 | (n3 << 8) | (n4 << 16);
-            }
-
-            last_end_range += count * 256;
-
-            let cc = bytes[i.post_inc()] as usize;
-            ip_ranges.push(last_end_range);
-            country_codes.push(raw_code_lookup[cc].clone())
-        }
+        let (country_codes, ip_ranges) = Self::parse_table(
+            include_bytes!("../../resources/ip_supalite.table"),
+            IPV4_BLOCK_SIZE,
+        );
+        let (ipv6_country_codes, ipv6_ranges) = Self::parse_table(
+            include_bytes!("../../resources/ip6_supalite.table"),
+            IPV6_BLOCK_SIZE,
+        );
 
         let country_lookup = CountryLookupData {
             country_codes,
-            ip_ranges,
+            ip_ranges: ip_ranges.into_iter().map(|r| r as i64).collect(),
+            ipv6_country_codes,
+            ipv6_ranges,
         };
 
         match COUNTRY_LOOKUP_DATA.try_write_for(std::time::Duration::from_secs(5)) {
@@ -124,10 +105,6 @@ impl CountryLookup {
             _ => return None,
         };
 
-        if unwrapped_field != "country" {
-            return None;
-        }
-
         let ip = match user.get_user_value(&Some(DynamicString::from(IP.to_string()))) {
             Some(v) => match &v.string_value {
                 Some(s) => &s.value,
@@ -136,12 +113,31 @@ impl CountryLookup {
             None => return None,
         };
 
-        Self::lookup(ip, evaluator_context)
+        let prepared = user.get_prepared_values();
+        if let Some(memoized) =
+            prepared.and_then(|p| p.ip_values.lock().get(unwrapped_field).cloned())
+        {
+            return Some(memoized);
+        }
+
+        let value = match evaluator_context.geo_ip_provider {
+            Some(provider) => provider.get_value(ip, unwrapped_field),
+            None if unwrapped_field == "country" => Self::lookup(ip, evaluator_context),
+            None => None,
+        };
+
+        if let (Some(prepared), Some(value)) = (prepared, &value) {
+            prepared
+                .ip_values
+                .lock()
+                .insert(unwrapped_field.to_string(), value.clone());
+        }
+
+        value
     }
 
     fn lookup(ip_address: &str, evaluator_context: &mut EvaluatorContext) -> Option<DynamicValue> {
-        let parts: Vec<&str> = ip_address.split('.').collect();
-        if parts.len() != 4 {
+        if !ip_address.contains(':') && ip_address.split('.').count() != 4 {
             return None;
         }
 
@@ -160,10 +156,37 @@ impl CountryLookup {
             None
         });
 
+        match Self::parse_ip(ip_address)? {
+            ParsedIp::V4(ip_number) => Self::lookup_numeric(ip_number, country_lookup_data),
+            ParsedIp::V6(ip_number) => Self::lookup_numeric_v6(ip_number, country_lookup_data),
+        }
+    }
+
+    fn parse_ip(ip_address: &str) -> Option<ParsedIp> {
+        if !ip_address.contains(':') {
+            return Self::parse_ipv4(ip_address).map(ParsedIp::V4);
+        }
+
+        // strip brackets and zone ids, e.g. "[fe80::1%eth0]"
+        let ip_address = ip_address.trim_start_matches('[').trim_end_matches(']');
+        let ip_address = ip_address.split('%').next().unwrap_or(ip_address);
+
+        let ip = ip_address.parse::<Ipv6Addr>().ok()?;
+        match ip.to_ipv4_mapped() {
+            Some(v4) => Some(ParsedIp::V4(u32::from(v4) as i64)),
+            None => Some(ParsedIp::V6(u128::from(ip))),
+        }
+    }
+
+    fn parse_ipv4(ip_address: &str) -> Option<i64> {
+        let parts: Vec<&str> = ip_address.split('.').collect();
+        if parts.len() != 4 {
+            return None;
+        }
+
         let nums: Vec<Option<i64>> = parts.iter().map(|&x| x.parse().ok()).collect();
         if let (Some(n0), Some(n1), Some(n2), Some(n3)) = (nums[0], nums[1], nums[2], nums[3]) {
-            let ip_number = (n0 * 256_i64.pow(3)) + (n1 << 16) + (n2 << 8) + n3;
-            return Self::lookup_numeric(ip_number, country_lookup_data);
+            return Some((n0 * 256_i64.pow(3)) + (n1 << 16) + (n2 << 8) + n3);
         }
 
         None
@@ -196,4 +219,152 @@ impl CountryLookup {
 
         min
     }
+
+    fn lookup_numeric_v6(
+        ip_address: u128,
+        country_lookup_data: &CountryLookupData,
+    ) -> Option<DynamicValue> {
+        let ranges = &country_lookup_data.ipv6_ranges;
+        let index = ranges.partition_point(|&end| end <= ip_address);
+        let cc = country_lookup_data.ipv6_country_codes.get(index)?;
+        if cc == "--" {
+            return None;
+        }
+        Some(dyn_value!(cc.clone()))
+    }
+
+    fn parse_table(bytes: &[u8], block_size: u128) -> (Vec<String>, Vec<u128>) {
+        let mut raw_code_lookup: Vec<String> = vec![];
+        let mut country_codes: Vec<String> = vec![];
+        let mut ip_ranges: Vec<u128> = vec![];
+
+        let mut i = 0;
+
+        while i + 1 < bytes.len() {
+            let c1 = bytes[i.post_inc()] as char;
+            let c2 = bytes[i.post_inc()] as char;
+
+            raw_code_lookup.push(format!("{c1}{c2}"));
+
+            if c1 == '*' {
+                break;
+            }
+        }
+
+        // counts below 240 are stored inline, larger ones follow as 2, 3, 4 or 8 little endian bytes
+        let read_le = |i: &mut usize, width: usize| -> u128 {
+            let mut count = 0_u128;
+            for shift in 0..width {
+                let byte = bytes.get(i.post_inc()).copied().unwrap_or_default();
+                count |= (byte as u128) << (shift * 8);
+            }
+            count
+        };
+
+        let mut last_end_range = 0_u128;
+        while (i + 1) < bytes.len() {
+            let n1 = bytes[i.post_inc()];
+            let count = match n1 {
+                0..=239 => n1 as u128,
+                242 => read_le(&mut i, 2),
+                243 => read_le(&mut i, 3),
+                244 => read_le(&mut i, 4),
+                245 => read_le(&mut i, 8),
+                _ => 0,
+            };
+
+            last_end_range = last_end_range.saturating_add(count.saturating_mul(block_size));
+
+            let cc = bytes[i.post_inc()] as usize;
+            ip_ranges.push(last_end_range);
+            country_codes.push(
+                raw_code_lookup
+                    .get(cc)
+                    .cloned()
+                    .unwrap_or_else(|| "--".to_string()),
+            );
+        }
+
+        (country_codes, ip_ranges)
+    }
+}
+
+enum ParsedIp {
+    V4(i64),
+    V6(u128),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // generated by tools/python/build_ip6_table.py from:
+    //   apnic|JP|ipv6|2001:200::|23|19990813|allocated
+    //   apnic|AU|ipv6|2001:8000::|19|20010101|allocated
+    //   ripencc|DE|ipv6|2a00:1000::|64|20100101|assigned
+    const SAMPLE_IPV6_TABLE: &[u8] = b"/~--JPAUDE**\
+        \xf5\x00\x00\x00\x02\x01\x20\x00\x00\x01\xf4\x00\x00\x00\x02\x02\xf4\x00\x00\x00\
+        \x7c\x01\xf4\x00\x00\x00\x20\x03\xf5\x00\x00\x00\x70\xfe\x09\x00\x00\x01\x01\x04\
+        \xf5\xff\xff\xff\xef\xff\xd5\x00\x00\x01";
+
+    fn sample_data() -> CountryLookupData {
+        let (ipv6_country_codes, ipv6_ranges) =
+            CountryLookup::parse_table(SAMPLE_IPV6_TABLE, IPV6_BLOCK_SIZE);
+        CountryLookupData {
+            country_codes: vec![],
+            ip_ranges: vec![],
+            ipv6_country_codes,
+            ipv6_ranges,
+        }
+    }
+
+    fn lookup_v6(ip: &str, data: &CountryLookupData) -> Option<String> {
+        match CountryLookup::parse_ip(ip)? {
+            ParsedIp::V6(ip) => CountryLookup::lookup_numeric_v6(ip, data)?
+                .string_value
+                .map(|s| s.value.to_string()),
+            ParsedIp::V4(_) => panic!("expected an ipv6 address"),
+        }
+    }
+
+    #[test]
+    fn test_ipv6_lookup() {
+        let data = sample_data();
+
+        assert_eq!(lookup_v6("2001:200::1", &data).as_deref(), Some("JP"));
+        assert_eq!(lookup_v6("2001:3ff:ffff::", &data).as_deref(), Some("JP"));
+        assert_eq!(lookup_v6("2001:9fff:abcd::1", &data).as_deref(), Some("AU"));
+        assert_eq!(lookup_v6("2a00:1000::1", &data).as_deref(), Some("DE"));
+        assert_eq!(
+            lookup_v6("[2a00:1000::1%eth0]", &data).as_deref(),
+            Some("DE")
+        );
+    }
+
+    #[test]
+    fn test_ipv6_lookup_outside_allocations() {
+        let data = sample_data();
+
+        assert_eq!(lookup_v6("2001:1ff:ffff:ffff::", &data), None);
+        assert_eq!(lookup_v6("2001:400::1", &data), None);
+        assert_eq!(
+            lookup_v6("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff", &data),
+            None
+        );
+        assert_eq!(lookup_v6("::", &data), None);
+    }
+
+    #[test]
+    fn test_ipv4_mapped_addresses_are_normalized() {
+        assert!(matches!(
+            CountryLookup::parse_ip("::ffff:1.2.3.4"),
+            Some(ParsedIp::V4(0x01020304))
+        ));
+        assert!(matches!(
+            CountryLookup::parse_ip("1.2.3.4"),
+            Some(ParsedIp::V4(0x01020304))
+        ));
+        assert!(CountryLookup::parse_ip("1.2.3").is_none());
+        assert!(CountryLookup::parse_ip("2001:db8::g").is_none());
+    }
 }
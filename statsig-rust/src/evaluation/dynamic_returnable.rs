@@ -1,3 +1,4 @@
+use crate::specs_response::specs_payload::{is_retaining_payload, RetainedRawValue};
 use crate::{hashing::djb2, log_e};
 use parking_lot::Mutex;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -7,7 +8,7 @@ use serde_json::{
 };
 use std::{
     collections::HashMap,
-    sync::{Arc, Weak},
+    sync::{Arc, OnceLock, Weak},
     time::Duration,
 };
 
@@ -22,7 +23,7 @@ lazy_static::lazy_static! {
         value: Arc::new(MemoizedValue {
             raw_value: None,
             bool_value: None,
-            json_value: None,
+            json_value: OnceLock::from(None),
         }),
     };
 }
@@ -49,9 +50,9 @@ impl DynamicReturnable {
 
         let hash = djb2(raw_value.get());
         let value = Arc::new(MemoizedValue {
-            raw_value: Some(raw_value),
+            raw_value: Some(RawJson::Owned(raw_value)),
             bool_value: None,
-            json_value: Some(value.clone()),
+            json_value: OnceLock::from(Some(value.clone())),
         });
 
         Self::new(hash.to_string(), value)
@@ -62,11 +63,11 @@ impl DynamicReturnable {
     }
 
     pub fn get_json(&self) -> Option<HashMap<String, JsonValue>> {
-        self.value.json_value.clone()
+        self.value.get_json_ref().cloned()
     }
 
     pub fn get_json_ref(&self) -> Option<&HashMap<String, JsonValue>> {
-        self.value.json_value.as_ref()
+        self.value.get_json_ref()
     }
 
     fn new(hash: String, value: Arc<MemoizedValue>) -> Self {
@@ -87,12 +88,15 @@ impl<'de> Deserialize<'de> for DynamicReturnable {
         let raw_value_str = raw_value_ref.get();
         let hash = djb2(raw_value_str);
 
-        if let Some(value) = get_memoized_value(&hash) {
+        // values retained in an older payload are not reused, so that payload can be released
+        if let Some(value) = get_memoized_value(&hash).filter(|v| !v.is_retained_elsewhere()) {
             return Ok(DynamicReturnable { hash, value });
         }
 
-        let raw_value = raw_value_ref.to_owned();
-        let value = MemoizedValue::new(raw_value);
+        let value = match RetainedRawValue::from_retained(raw_value_ref) {
+            Some(retained) => MemoizedValue::new_retained(retained),
+            None => MemoizedValue::new(raw_value_ref.to_owned()),
+        };
 
         let new_returnable = DynamicReturnable::new(hash.clone(), value);
 
@@ -109,11 +113,15 @@ impl Serialize for DynamicReturnable {
             return bool_value.serialize(serializer);
         }
 
-        if let Some(raw_value) = &self.value.raw_value {
-            return raw_value.serialize(serializer);
+        match &self.value.raw_value {
+            Some(RawJson::Owned(raw_value)) => return raw_value.serialize(serializer),
+            Some(RawJson::Retained(retained)) => {
+                return retained.as_raw_value().serialize(serializer)
+            }
+            None => {}
         }
 
-        if let Some(json_value) = &self.value.json_value {
+        if let Some(json_value) = self.value.get_json_ref() {
             return json_value.serialize(serializer);
         }
 
@@ -139,24 +147,41 @@ impl Drop for DynamicReturnable {
             None => return,
         };
 
-        if found.strong_count() == 1 {
+        // the entry may already point at a newer value with the same hash
+        if found.as_ptr() == Arc::as_ptr(&self.value) && found.strong_count() == 1 {
             memo.remove(&self.hash);
         }
     }
 }
 
+#[derive(Debug, Clone)]
+pub(crate) enum RawJson {
+    Owned(Box<RawValue>),
+    Retained(RetainedRawValue),
+}
+
+impl RawJson {
+    fn get(&self) -> &str {
+        match self {
+            RawJson::Owned(raw_value) => raw_value.get(),
+            RawJson::Retained(retained) => retained.as_str(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct MemoizedValue {
-    pub(crate) raw_value: Option<Box<RawValue>>,
+    pub(crate) raw_value: Option<RawJson>,
     pub(crate) bool_value: Option<bool>,
-    pub(crate) json_value: Option<HashMap<String, JsonValue>>,
+    // parsed on first use for values retained in a specs payload
+    pub(crate) json_value: OnceLock<Option<HashMap<String, JsonValue>>>,
 }
 
 impl PartialEq for MemoizedValue {
     fn eq(&self, other: &Self) -> bool {
-        self.raw_value.as_ref().map(|v| v.get()) == other.raw_value.as_ref().map(|v| v.get())
+        self.raw_value.as_ref().map(RawJson::get) == other.raw_value.as_ref().map(RawJson::get)
             && self.bool_value == other.bool_value
-            && self.json_value == other.json_value
+            && self.get_json_ref() == other.get_json_ref()
     }
 }
 
@@ -171,27 +196,62 @@ impl MemoizedValue {
         Arc::new(value)
     }
 
+    fn new_retained(retained: RetainedRawValue) -> Arc<Self> {
+        let value = match retained.as_str() {
+            "true" => Self::from_bool(true),
+            "false" => Self::from_bool(false),
+            _ => Self {
+                raw_value: Some(RawJson::Retained(retained)),
+                bool_value: None,
+                json_value: OnceLock::new(),
+            },
+        };
+
+        Arc::new(value)
+    }
+
     fn from_bool(bool_value: bool) -> Self {
         Self {
             raw_value: None,
             bool_value: Some(bool_value),
-            json_value: None,
+            json_value: OnceLock::from(None),
         }
     }
 
     fn from_raw_value(raw_value: Box<RawValue>) -> Self {
-        let json_value = match serde_json::from_str(raw_value.get()) {
-            Ok(json_value) => json_value,
-            Err(e) => {
-                log_e!(TAG, "Failed to parse json: {}", e);
-                None
-            }
-        };
+        let json_value = parse_json(raw_value.get());
 
         Self {
-            raw_value: Some(raw_value),
+            raw_value: Some(RawJson::Owned(raw_value)),
             bool_value: None,
-            json_value,
+            json_value: OnceLock::from(json_value),
+        }
+    }
+
+    fn get_json_ref(&self) -> Option<&HashMap<String, JsonValue>> {
+        self.json_value
+            .get_or_init(|| {
+                self.raw_value
+                    .as_ref()
+                    .and_then(|raw| parse_json(raw.get()))
+            })
+            .as_ref()
+    }
+
+    fn is_retained_elsewhere(&self) -> bool {
+        match &self.raw_value {
+            Some(RawJson::Retained(retained)) => !is_retaining_payload(retained.payload()),
+            _ => false,
+        }
+    }
+}
+
+fn parse_json(raw_value: &str) -> Option<HashMap<String, JsonValue>> {
+    match serde_json::from_str(raw_value) {
+        Ok(json_value) => json_value,
+        Err(e) => {
+            log_e!(TAG, "Failed to parse json: {}", e);
+            None
         }
     }
 }
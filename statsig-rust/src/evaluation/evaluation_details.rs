@@ -34,6 +34,14 @@ impl EvaluationDetails {
         }
     }
 
+    pub fn stale(spec_store_data: &SpecStoreData) -> Self {
+        Self {
+            reason: format!("{}:Stale", spec_store_data.source),
+            lcut: Some(spec_store_data.values.time),
+            received_at: spec_store_data.time_received_at,
+        }
+    }
+
     #[must_use]
     pub fn unrecognized_no_data() -> Self {
         Self {
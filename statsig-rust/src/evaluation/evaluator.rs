@@ -12,7 +12,6 @@ use crate::evaluation::evaluation_types::SecondaryExposure;
 use crate::evaluation::evaluator_context::EvaluatorContext;
 use crate::evaluation::evaluator_value::{EvaluatorValue, EvaluatorValueType};
 use crate::evaluation::get_unit_id::get_unit_id;
-use crate::evaluation::user_agent_parsing::UserAgentParser;
 use crate::event_logging::exposable_string;
 use crate::specs_response::spec_types::{Condition, Rule, Spec};
 use crate::{dyn_value, log_e, unwrap_or_return, StatsigErr};
@@ -193,8 +192,8 @@ fn try_apply_config_mapping(
     };
 
     let spec_salt = match opt_spec {
-        Some(spec) => &spec.salt,
-        None => &EMPTY_STR,
+        Some(spec) => spec.salt.as_str(),
+        None => EMPTY_STR.as_str(),
     };
 
     for mapping in mapping_list {
@@ -318,7 +317,7 @@ fn evaluate_condition<'a>(
         "ua_based" => match ctx.user.get_user_value(&condition.field) {
             Some(value) => Some(value),
             None => {
-                temp_value = UserAgentParser::get_value_from_user_agent(
+                temp_value = ctx.user_agent_parser.get_value_from_user_agent(
                     ctx.user,
                     &condition.field,
                     &mut ctx.result.override_reason,
@@ -333,7 +332,13 @@ fn evaluate_condition<'a>(
                 temp_value.as_ref()
             }
         },
-        "user_field" => ctx.user.get_user_value(&condition.field),
+        "user_field" => match ctx.user.get_user_value(&condition.field) {
+            Some(value) => Some(value),
+            None => {
+                temp_value = ctx.get_computed_user_value(&condition.field);
+                temp_value.as_ref()
+            }
+        },
         "environment_field" => {
             temp_value = ctx.user.get_value_from_environment(&condition.field);
             temp_value.as_ref()
@@ -522,7 +527,7 @@ fn evaluate_config_delegate<'a>(
     Ok(true)
 }
 
-fn evaluate_pass_percentage(ctx: &mut EvaluatorContext, rule: &Rule, spec_salt: &String) -> bool {
+fn evaluate_pass_percentage(ctx: &mut EvaluatorContext, rule: &Rule, spec_salt: &str) -> bool {
     if rule.pass_percentage == 100f64 {
         return true;
     }
@@ -1,12 +1,14 @@
+use crate::evaluation::dynamic_string::DynamicString;
 use crate::evaluation::dynamic_value::DynamicValue;
 use crate::evaluation::evaluator_result::EvaluatorResult;
+use crate::evaluation::user_agent_parsing::CachedUserAgentParser;
 use crate::event_logging::exposable_string::ExposableString;
 use crate::hashing::HashUtil;
 use crate::spec_store::SpecStoreData;
 use crate::specs_response::spec_types::{Rule, Spec};
 use crate::user::StatsigUserInternal;
 use crate::StatsigErr::StackOverflowError;
-use crate::{OverrideAdapter, StatsigErr};
+use crate::{GeoIpProvider, OverrideAdapter, StatsigErr};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -20,7 +22,10 @@ pub struct EvaluatorContext<'a> {
     pub nested_count: u16,
     pub app_id: Option<&'a DynamicValue>,
     pub override_adapter: Option<&'a Arc<dyn OverrideAdapter>>,
+    pub geo_ip_provider: Option<&'a Arc<dyn GeoIpProvider>>,
+    pub user_agent_parser: &'a CachedUserAgentParser,
     pub nested_gate_memo: HashMap<&'a str, (bool, Option<&'a ExposableString>)>,
+    pub computed_user_values: HashMap<String, Option<DynamicValue>>,
 }
 
 impl<'a> EvaluatorContext<'a> {
@@ -30,6 +35,8 @@ impl<'a> EvaluatorContext<'a> {
         hashing: &'a HashUtil,
         app_id: Option<&'a DynamicValue>,
         override_adapter: Option<&'a Arc<dyn OverrideAdapter>>,
+        geo_ip_provider: Option<&'a Arc<dyn GeoIpProvider>>,
+        user_agent_parser: &'a CachedUserAgentParser,
     ) -> Self {
         let result = EvaluatorResult::default();
 
@@ -40,11 +47,31 @@ impl<'a> EvaluatorContext<'a> {
             app_id,
             result,
             override_adapter,
+            geo_ip_provider,
+            user_agent_parser,
             nested_count: 0,
             nested_gate_memo: HashMap::new(),
+            computed_user_values: HashMap::new(),
         }
     }
 
+    /// Computes the `StatsigOptions::computed_user_attributes` entry for `field` on first use and
+    /// memoizes it for the rest of this context.
+    pub fn get_computed_user_value(
+        &mut self,
+        field: &Option<DynamicString>,
+    ) -> Option<DynamicValue> {
+        let key = &field.as_ref()?.value;
+        if let Some(memoized) = self.computed_user_values.get(key) {
+            return memoized.clone();
+        }
+
+        let provider = self.user.get_computed_user_attribute(field)?;
+        let value = provider.compute(self.user.user_ref);
+        self.computed_user_values.insert(key.clone(), value.clone());
+        value
+    }
+
     pub fn reset_result(&mut self) {
         self.nested_count = 0;
         self.result = EvaluatorResult::default();
@@ -1,34 +1,18 @@
-use crate::{dyn_value, log_w, DynamicValue};
-
 use super::statsig_uaparser::UaParser;
-
-const TAG: &str = "ExperimentalUserAgentParser";
+use super::user_agent_parser_provider_trait::{ParsedUserAgent, UserAgentParserProvider};
 
 pub struct ExperimentalUserAgentParser;
 
-impl ExperimentalUserAgentParser {
-    pub fn get_value_from_user_agent(field: &str, user_agent: &str) -> Option<DynamicValue> {
-        match field {
-            "os_name" | "osname" => {
-                let os = UaParser::parse_os(user_agent);
-                Some(dyn_value!(os.name))
-            }
-            "os_version" | "osversion" => {
-                let os = UaParser::parse_os(user_agent);
-                Some(dyn_value!(os.version.get_version_string()))
-            }
-            "browser_name" | "browsername" => {
-                let browser = UaParser::parse_browser(user_agent);
-                Some(dyn_value!(browser.name))
-            }
-            "browser_version" | "browserversion" => {
-                let browser = UaParser::parse_browser(user_agent);
-                Some(dyn_value!(browser.version.get_version_string()))
-            }
-            _ => {
-                log_w!(TAG, "Unsupported field: {}", field);
-                None
-            }
-        }
+impl UserAgentParserProvider for ExperimentalUserAgentParser {
+    fn parse(&self, user_agent: &str) -> Option<ParsedUserAgent> {
+        let os = UaParser::parse_os(user_agent);
+        let browser = UaParser::parse_browser(user_agent);
+
+        Some(ParsedUserAgent {
+            os_name: Some(os.name.to_string()),
+            os_version: os.version.get_version_string(),
+            browser_name: Some(browser.name.to_string()),
+            browser_version: browser.version.get_version_string(),
+        })
     }
 }
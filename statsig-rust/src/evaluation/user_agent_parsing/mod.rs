@@ -1,6 +1,11 @@
 pub mod experimental_ua_parser;
 pub mod statsig_uaparser;
 pub mod third_party_ua_parser;
+mod ua_parse_cache;
 pub mod ua_parser;
+pub mod user_agent_parser_provider_trait;
 
+#[allow(deprecated)]
 pub use ua_parser::UserAgentParser;
+pub use ua_parser::{CachedUserAgentParser, UserAgentParserType};
+pub use user_agent_parser_provider_trait::{ParsedUserAgent, UserAgentParserProvider};
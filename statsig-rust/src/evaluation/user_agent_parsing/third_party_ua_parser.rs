@@ -1,9 +1,11 @@
-use crate::{dyn_value, log_d, log_e, DynamicValue};
+use crate::{log_d, log_e};
 use parking_lot::RwLock;
 use std::borrow::Cow;
 use std::sync::Arc;
 use uaparser::{Parser, UserAgentParser as ExtUserAgentParser};
 
+use super::user_agent_parser_provider_trait::{ParsedUserAgent, UserAgentParserProvider};
+
 lazy_static::lazy_static! {
     static ref PARSER: Arc<RwLock<Option<ExtUserAgentParser>>> = Arc::from(RwLock::from(None));
 }
@@ -13,50 +15,31 @@ const TAG: &str = "ThirdPartyUserAgentParser";
 pub struct ThirdPartyUserAgentParser;
 
 impl ThirdPartyUserAgentParser {
-    pub fn get_value_from_user_agent(
-        field: &str,
-        user_agent: &str,
-    ) -> Result<Option<DynamicValue>, &'static str> {
+    fn parse_all(user_agent: &str) -> Result<ParsedUserAgent, &'static str> {
         let lock = PARSER
             .try_read_for(std::time::Duration::from_secs(5))
             .ok_or("lock_failure")?;
         let parser = lock.as_ref().ok_or("parser_not_loaded")?;
 
-        fn get_json_version(
-            major: Option<Cow<str>>,
-            minor: Option<Cow<str>>,
-            patch: Option<Cow<str>>,
-        ) -> String {
-            let mut result = String::new();
-            result += &major.unwrap_or(Cow::Borrowed("0"));
-            result += ".";
-            result += &minor.unwrap_or(Cow::Borrowed("0"));
-            result += ".";
-            result += &patch.unwrap_or(Cow::Borrowed("0"));
-            result
-        }
+        let os = parser.parse_os(user_agent);
+        let browser = parser.parse_user_agent(user_agent);
 
-        let result = match field {
-            "os_name" | "osname" => {
-                let os = parser.parse_os(user_agent);
-                os.family.to_string()
-            }
-            "os_version" | "osversion" => {
-                let os = parser.parse_os(user_agent);
-                get_json_version(os.major, os.minor, os.patch)
-            }
-            "browser_name" | "browsername" => {
-                let user_agent = parser.parse_user_agent(user_agent);
-                user_agent.family.to_string()
-            }
-            "browser_version" | "browserversion" => {
-                let user_agent = parser.parse_user_agent(user_agent);
-                get_json_version(user_agent.major, user_agent.minor, user_agent.patch)
-            }
-            _ => return Ok(None),
-        };
+        Ok(ParsedUserAgent {
+            os_name: Some(os.family.to_string()),
+            os_version: Some(get_json_version(os.major, os.minor, os.patch)),
+            browser_name: Some(browser.family.to_string()),
+            browser_version: Some(get_json_version(
+                browser.major,
+                browser.minor,
+                browser.patch,
+            )),
+        })
+    }
 
-        Ok(Some(dyn_value!(result)))
+    pub fn is_loaded() -> bool {
+        PARSER
+            .try_read_for(std::time::Duration::from_secs(5))
+            .is_some_and(|lock| lock.is_some())
     }
 
     pub fn load_parser() {
@@ -101,3 +84,23 @@ impl ThirdPartyUserAgentParser {
         }
     }
 }
+
+impl UserAgentParserProvider for ThirdPartyUserAgentParser {
+    fn parse(&self, user_agent: &str) -> Option<ParsedUserAgent> {
+        Self::parse_all(user_agent).ok()
+    }
+}
+
+fn get_json_version(
+    major: Option<Cow<str>>,
+    minor: Option<Cow<str>>,
+    patch: Option<Cow<str>>,
+) -> String {
+    let mut result = String::new();
+    result += &major.unwrap_or(Cow::Borrowed("0"));
+    result += ".";
+    result += &minor.unwrap_or(Cow::Borrowed("0"));
+    result += ".";
+    result += &patch.unwrap_or(Cow::Borrowed("0"));
+    result
+}
@@ -0,0 +1,153 @@
+use crate::hashing::ahash_str;
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use super::user_agent_parser_provider_trait::ParsedUserAgent;
+
+pub const DEFAULT_UA_CACHE_MAX_ENTRIES: usize = 1000;
+
+/// Least recently used cache of parsed user agents, keyed by the hash of the user agent string.
+/// Entries keep the string they were parsed from, so a hash collision is treated as a miss.
+pub(crate) struct UserAgentParseCache {
+    max_entries: usize,
+    state: Mutex<CacheState>,
+}
+
+struct CacheEntry {
+    user_agent: Box<str>,
+    parsed: Arc<ParsedUserAgent>,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<u64, CacheEntry>,
+    // last use -> key, oldest first
+    recency: BTreeMap<u64, u64>,
+    clock: u64,
+}
+
+impl UserAgentParseCache {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Returns the cached result for `user_agent`, or runs `parse` and caches what it returns.
+    /// The lock is not held while parsing, so concurrent misses on the same user agent may both parse.
+    pub fn get_or_parse(
+        &self,
+        user_agent: &str,
+        parse: impl FnOnce() -> Option<ParsedUserAgent>,
+    ) -> Option<Arc<ParsedUserAgent>> {
+        let key = ahash_str(user_agent);
+
+        if let Some(parsed) = self.state.lock().get(key, user_agent) {
+            return Some(parsed);
+        }
+
+        let parsed = Arc::new(parse()?);
+        self.state
+            .lock()
+            .insert(key, user_agent, parsed.clone(), self.max_entries);
+        Some(parsed)
+    }
+}
+
+impl CacheState {
+    fn get(&mut self, key: u64, user_agent: &str) -> Option<Arc<ParsedUserAgent>> {
+        let entry = self.entries.get_mut(&key)?;
+        if *entry.user_agent != *user_agent {
+            return None;
+        }
+
+        self.clock += 1;
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(self.clock, key);
+        entry.last_used = self.clock;
+        Some(entry.parsed.clone())
+    }
+
+    fn insert(
+        &mut self,
+        key: u64,
+        user_agent: &str,
+        parsed: Arc<ParsedUserAgent>,
+        max_entries: usize,
+    ) {
+        self.clock += 1;
+        let entry = CacheEntry {
+            user_agent: user_agent.into(),
+            parsed,
+            last_used: self.clock,
+        };
+        if let Some(replaced) = self.entries.insert(key, entry) {
+            self.recency.remove(&replaced.last_used);
+        }
+        self.recency.insert(self.clock, key);
+
+        while self.entries.len() > max_entries {
+            match self.recency.pop_first() {
+                Some((_, oldest)) => self.entries.remove(&oldest),
+                None => break,
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(os_name: &str) -> Option<ParsedUserAgent> {
+        Some(ParsedUserAgent {
+            os_name: Some(os_name.to_string()),
+            ..ParsedUserAgent::default()
+        })
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used() {
+        let cache = UserAgentParseCache::new(2);
+        cache.get_or_parse("a", || parsed("A"));
+        cache.get_or_parse("b", || parsed("B"));
+
+        // touch "a" so "b" is the oldest
+        cache.get_or_parse("a", || panic!("should be cached"));
+        cache.get_or_parse("c", || parsed("C"));
+
+        assert_eq!(cache.state.lock().entries.len(), 2);
+        cache.get_or_parse("a", || panic!("should be cached"));
+        cache.get_or_parse("c", || panic!("should be cached"));
+
+        let reparsed = cache.get_or_parse("b", || parsed("B2")).unwrap();
+        assert_eq!(reparsed.os_name.as_deref(), Some("B2"));
+    }
+
+    #[test]
+    fn test_colliding_hashes_are_not_shared() {
+        let cache = UserAgentParseCache::new(2);
+        let key = ahash_str("a");
+        cache
+            .state
+            .lock()
+            .insert(key, "b", Arc::new(parsed("B").unwrap()), 2);
+
+        let result = cache.get_or_parse("a", || parsed("A")).unwrap();
+        assert_eq!(result.os_name.as_deref(), Some("A"));
+        assert_eq!(cache.state.lock().entries.len(), 1);
+    }
+
+    #[test]
+    fn test_does_not_cache_unavailable_results() {
+        let cache = UserAgentParseCache::new(2);
+        assert!(cache.get_or_parse("a", || None).is_none());
+        assert_eq!(cache.state.lock().entries.len(), 0);
+
+        let result = cache.get_or_parse("a", || parsed("A")).unwrap();
+        assert_eq!(result.os_name.as_deref(), Some("A"));
+    }
+}
@@ -1,11 +1,14 @@
 use std::env;
+use std::sync::Arc;
 
 use crate::evaluation::dynamic_string::DynamicString;
 use crate::user::StatsigUserInternal;
-use crate::{log_w, DynamicValue};
+use crate::{log_w, DynamicValue, StatsigOptions};
 
 use super::experimental_ua_parser::ExperimentalUserAgentParser;
 use super::third_party_ua_parser::ThirdPartyUserAgentParser;
+use super::ua_parse_cache::{UserAgentParseCache, DEFAULT_UA_CACHE_MAX_ENTRIES};
+use super::user_agent_parser_provider_trait::{ParsedUserAgent, UserAgentParserProvider};
 
 lazy_static::lazy_static! {
     static ref USE_EXPERIMENTAL_PARSER: bool = env::var("STATSIG_USE_EXPERIMENTAL_UAPARSER").is_ok_and(|v| v == "true" || v == "1");
@@ -15,10 +18,67 @@ lazy_static::lazy_static! {
 const TAG: &str = "UserAgentParser";
 const UNINITIALIZED_REASON: &str = "UAParserNotLoaded";
 
-pub struct UserAgentParser;
+/// Which bundled parser answers `ua_based` conditions when no `UserAgentParserProvider` is set.
+/// Defaults to the `STATSIG_USE_EXPERIMENTAL_UAPARSER` environment variable when unset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserAgentParserType {
+    /// The `uaparser` crate with the bundled regexes. Loaded during initialize.
+    ThirdParty,
+    /// Statsig's tokenizer based parser. Needs no loading.
+    Experimental,
+}
+
+impl From<&str> for UserAgentParserType {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "experimental" => UserAgentParserType::Experimental,
+            _ => UserAgentParserType::ThirdParty,
+        }
+    }
+}
+
+/// Resolves `ua_based` fields for one Statsig instance, with the parser picked in `StatsigOptions`
+/// and a cache of parse results shared across evaluations.
+pub struct CachedUserAgentParser {
+    provider: Arc<dyn UserAgentParserProvider>,
+    uses_third_party: bool,
+    cache: Option<UserAgentParseCache>,
+}
+
+impl CachedUserAgentParser {
+    pub fn new(options: &StatsigOptions) -> Self {
+        let parser_type = options
+            .user_agent_parser_type
+            .unwrap_or(if *USE_EXPERIMENTAL_PARSER {
+                UserAgentParserType::Experimental
+            } else {
+                UserAgentParserType::ThirdParty
+            });
+
+        let (provider, uses_third_party): (Arc<dyn UserAgentParserProvider>, bool) =
+            match (&options.user_agent_parser_provider, parser_type) {
+                (Some(provider), _) => (provider.clone(), false),
+                (None, UserAgentParserType::Experimental) => {
+                    (Arc::new(ExperimentalUserAgentParser), false)
+                }
+                (None, UserAgentParserType::ThirdParty) => {
+                    (Arc::new(ThirdPartyUserAgentParser), true)
+                }
+            };
+
+        let max_entries = options
+            .user_agent_cache_max_entries
+            .map_or(DEFAULT_UA_CACHE_MAX_ENTRIES, |max| max as usize);
+
+        Self {
+            provider,
+            uses_third_party,
+            cache: (max_entries > 0).then(|| UserAgentParseCache::new(max_entries)),
+        }
+    }
 
-impl UserAgentParser {
     pub fn get_value_from_user_agent(
+        &self,
         user: &StatsigUserInternal,
         field: &Option<DynamicString>,
         override_reason: &mut Option<&str>,
@@ -28,40 +88,79 @@ impl UserAgentParser {
             _ => return None,
         };
 
-        let user_agent = match user.get_user_value(&USER_AGENT_STRING) {
-            Some(v) => match &v.string_value {
-                Some(s) => &s.value,
-                _ => return None,
-            },
-            None => return None,
+        let prepared = user.get_prepared_values();
+        let parsed = match prepared.and_then(|p| p.parsed_user_agent.get()) {
+            Some(parsed) => Some(parsed.clone()),
+            None => {
+                let parsed = self.parse(get_user_agent(user)?);
+                if let (Some(prepared), Some(parsed)) = (prepared, &parsed) {
+                    let _ = prepared.parsed_user_agent.set(parsed.clone());
+                }
+                parsed
+            }
         };
 
-        if user_agent.len() > 1000 {
-            return None;
+        match parsed {
+            Some(parsed) => parsed.get_value(field_lowered),
+            None => {
+                *override_reason = Some(UNINITIALIZED_REASON);
+                log_w!(TAG, "Failed to load UA Parser. Check StatsigOptions.disable_user_agent_parsing and or wait_for_user_agent_init");
+                None
+            }
         }
+    }
 
-        if *USE_EXPERIMENTAL_PARSER {
-            ExperimentalUserAgentParser::get_value_from_user_agent(field_lowered, user_agent)
-        } else {
-            let result =
-                ThirdPartyUserAgentParser::get_value_from_user_agent(field_lowered, user_agent);
-
-            match result {
-                Ok(v) => v,
-                Err(_) => {
-                    *override_reason = Some(UNINITIALIZED_REASON);
-                    log_w!(TAG, "Failed to load UA Parser. Check StatsigOptions.disable_user_agent_parsing and or wait_for_user_agent_init");
-                    None
-                }
-            }
+    fn parse(&self, user_agent: &str) -> Option<Arc<ParsedUserAgent>> {
+        let parse = || self.provider.parse(user_agent);
+        match &self.cache {
+            Some(cache) => cache.get_or_parse(user_agent, parse),
+            None => parse().map(Arc::new),
         }
     }
 
+    pub fn is_loaded(&self) -> bool {
+        !self.uses_third_party || ThirdPartyUserAgentParser::is_loaded()
+    }
+
+    pub fn load_parser(&self) {
+        if self.uses_third_party {
+            ThirdPartyUserAgentParser::load_parser();
+        }
+    }
+}
+
+#[deprecated(note = "Use the Statsig instance's parser, configured through StatsigOptions.")]
+pub struct UserAgentParser;
+
+#[allow(deprecated)]
+impl UserAgentParser {
+    /// Forwards to the parser of the user's Statsig instance. None without an instance.
+    pub fn get_value_from_user_agent(
+        user: &StatsigUserInternal,
+        field: &Option<DynamicString>,
+        override_reason: &mut Option<&str>,
+    ) -> Option<DynamicValue> {
+        user.statsig_instance?
+            .user_agent_parser
+            .get_value_from_user_agent(user, field, override_reason)
+    }
+
     pub fn load_parser() {
-        if *USE_EXPERIMENTAL_PARSER {
-            // noop
-        } else {
+        if !*USE_EXPERIMENTAL_PARSER {
             ThirdPartyUserAgentParser::load_parser();
         }
     }
 }
+
+fn get_user_agent<'a>(user: &'a StatsigUserInternal) -> Option<&'a str> {
+    let user_agent = user
+        .get_user_value(&USER_AGENT_STRING)?
+        .string_value
+        .as_ref()?;
+
+    if user_agent.value.len() > 1000 {
+        return None;
+    }
+
+    Some(&user_agent.value)
+}
@@ -0,0 +1,37 @@
+use crate::{dyn_value, DynamicValue};
+
+/// The fields `ua_based` conditions can read from a user agent.
+/// Fields left as `None` evaluate as null.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParsedUserAgent {
+    pub os_name: Option<String>,
+    pub os_version: Option<String>,
+    pub browser_name: Option<String>,
+    pub browser_version: Option<String>,
+}
+
+impl ParsedUserAgent {
+    pub fn get_value(&self, field: &str) -> Option<DynamicValue> {
+        let value = match field {
+            "os_name" | "osname" => &self.os_name,
+            "os_version" | "osversion" => &self.os_version,
+            "browser_name" | "browsername" => &self.browser_name,
+            "browser_version" | "browserversion" => &self.browser_version,
+            _ => return None,
+        };
+
+        Some(dyn_value!(value.as_deref()))
+    }
+}
+
+/// Parses user agents for `ua_based` conditions.
+///
+/// By default the SDK uses one of its bundled parsers (see `StatsigOptions::user_agent_parser_type`).
+/// Set `StatsigOptions::user_agent_parser_provider` to parse with your own logic instead, for
+/// example to report in-house app user agents as proper os or browser names. Results are cached per
+/// user agent (see `StatsigOptions::user_agent_cache_max_entries`).
+pub trait UserAgentParserProvider: Send + Sync {
+    /// Returns the parsed user agent, or `None` if the parser is not available yet.
+    /// Called on the evaluating thread on a cache miss, so it must not block.
+    fn parse(&self, user_agent: &str) -> Option<ParsedUserAgent>;
+}
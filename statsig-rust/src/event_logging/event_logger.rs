@@ -16,6 +16,7 @@ use crate::{
     log_d, log_e, log_w,
     networking::NetworkError,
     observability::ops_stats::{OpsStatsForInstance, OPS_STATS},
+    statsig_health::EventLoggerHealth,
     statsig_metadata::StatsigMetadata,
     write_lock_or_noop, EventLoggingAdapter, StatsigErr, StatsigOptions, StatsigRuntime,
 };
@@ -53,6 +54,7 @@ pub struct EventLogger {
     shutdown_notify: Notify,
     ops_stats: Arc<OpsStatsForInstance>,
     enqueue_dropped_events_count: AtomicU64,
+    total_dropped_events_count: AtomicU64,
 }
 
 impl EventLogger {
@@ -71,7 +73,7 @@ impl EventLogger {
                     .event_logging_max_pending_batch_queue_size
                     .unwrap_or(DEFAULT_PENDING_BATCH_MAX),
             ),
-            event_sampler: ExposureSampling::new(sdk_key),
+            event_sampler: ExposureSampling::new(sdk_key, options),
             flush_interval: FlushInterval::new(),
             options: options.clone(),
             logging_adapter: event_logging_adapter.clone(),
@@ -81,6 +83,7 @@ impl EventLogger {
             limit_flush_semaphore: Arc::new(Semaphore::new(MAX_LIMIT_FLUSH_TASKS)),
             ops_stats: OPS_STATS.get_for_instance(sdk_key),
             enqueue_dropped_events_count: AtomicU64::new(0),
+            total_dropped_events_count: AtomicU64::new(0),
         });
 
         me.spawn_background_task(statsig_rt);
@@ -134,6 +137,15 @@ impl EventLogger {
         self.shutdown_notify.notify_one();
     }
 
+    pub fn get_health(&self) -> EventLoggerHealth {
+        EventLoggerHealth {
+            queue_depth: self.queue.approximate_pending_events_count(),
+            dropped_events_count: self.total_dropped_events_count.load(Ordering::Relaxed),
+            consecutive_flush_failures: self.flush_interval.get_consecutive_failures(),
+            flush_interval_ms: self.flush_interval.get_current_flush_interval_ms(),
+        }
+    }
+
     fn spawn_background_task(self: &Arc<Self>, rt: &Arc<StatsigRuntime>) {
         let me = self.clone();
         let rt_clone = rt.clone();
@@ -380,6 +392,8 @@ impl EventLogger {
         flush_type: FlushType,
     ) {
         let dropped_events_count = batch.events.len() as u64;
+        self.total_dropped_events_count
+            .fetch_add(dropped_events_count, Ordering::Relaxed);
 
         let kind = match flush_type {
             FlushType::ScheduledMaxTime => "Scheduled (Max Time)",
@@ -439,6 +453,9 @@ impl EventLogger {
     }
 
     fn log_dropped_event_warning(&self, dropped_events_count: u64) {
+        self.total_dropped_events_count
+            .fetch_add(dropped_events_count, Ordering::Relaxed);
+
         let approximate_pending_events_count = self.queue.approximate_pending_events_count();
         log_w!(
             TAG,
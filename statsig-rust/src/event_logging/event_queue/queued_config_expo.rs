@@ -12,6 +12,8 @@ use crate::{
     EvaluationDetails, SecondaryExposure,
 };
 
+use ahash::RandomState;
+
 use super::queued_event::{EnqueueOperation, QueuedEvent, QueuedExposure};
 
 pub struct EnqueueConfigExpoOp<'a> {
@@ -52,11 +54,17 @@ impl EnqueueOperation for EnqueueConfigExpoOp<'_> {
 
 impl<'a> QueuedExposure<'a> for EnqueueConfigExpoOp<'a> {
     fn create_exposure_sampling_key(&self) -> ExposureSamplingKey {
-        let user_data = &self.user.user_ref.data;
         let evaluation = self.config.__evaluation.as_ref().map(|e| &e.base);
         let passed = self.config.__evaluation.as_ref().is_some_and(|e| e.passed);
 
-        ExposureSamplingKey::new(evaluation, user_data.as_ref(), passed as u64)
+        ExposureSamplingKey::new(evaluation, self.user.get_user_values_hash(), passed as u64)
+    }
+
+    fn create_exposure_sampling_key_with(&self, hasher: &RandomState) -> ExposureSamplingKey {
+        let evaluation = self.config.__evaluation.as_ref().map(|e| &e.base);
+        let passed = self.config.__evaluation.as_ref().is_some_and(|e| e.passed);
+
+        ExposureSamplingKey::with_hasher(evaluation, &self.user.user_ref.data, passed, hasher)
     }
 
     fn get_rule_id_ref(&self) -> &'a str {
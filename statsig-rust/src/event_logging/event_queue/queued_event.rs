@@ -11,6 +11,7 @@ use crate::{
         statsig_event_internal::StatsigEventInternal,
     },
 };
+use ahash::RandomState;
 
 pub trait EnqueueOperation {
     fn as_exposure(&self) -> Option<&impl QueuedExposure>;
@@ -19,6 +20,7 @@ pub trait EnqueueOperation {
 
 pub trait QueuedExposure<'a> {
     fn create_exposure_sampling_key(&self) -> ExposureSamplingKey;
+    fn create_exposure_sampling_key_with(&self, hasher: &RandomState) -> ExposureSamplingKey;
     fn get_rule_id_ref(&'a self) -> &'a str;
     fn get_extra_exposure_info_ref(&'a self) -> Option<&'a ExtraExposureInfo>;
 }
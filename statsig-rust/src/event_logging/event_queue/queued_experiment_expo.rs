@@ -12,6 +12,8 @@ use crate::{
     EvaluationDetails, SecondaryExposure,
 };
 
+use ahash::RandomState;
+
 use super::queued_event::{EnqueueOperation, QueuedEvent, QueuedExposure};
 
 pub struct EnqueueExperimentExpoOp<'a> {
@@ -51,7 +53,6 @@ impl EnqueueOperation for EnqueueExperimentExpoOp<'_> {
 
 impl<'a> QueuedExposure<'a> for EnqueueExperimentExpoOp<'a> {
     fn create_exposure_sampling_key(&self) -> ExposureSamplingKey {
-        let user_data = &self.user.user_ref.data;
         let evaluation = self.experiment.__evaluation.as_ref().map(|e| &e.base);
 
         let in_experiment = self
@@ -60,7 +61,28 @@ impl<'a> QueuedExposure<'a> for EnqueueExperimentExpoOp<'a> {
             .as_ref()
             .is_some_and(|e| e.is_user_in_experiment == Some(true));
 
-        ExposureSamplingKey::new(evaluation, user_data.as_ref(), in_experiment as u64)
+        ExposureSamplingKey::new(
+            evaluation,
+            self.user.get_user_values_hash(),
+            in_experiment as u64,
+        )
+    }
+
+    fn create_exposure_sampling_key_with(&self, hasher: &RandomState) -> ExposureSamplingKey {
+        let evaluation = self.experiment.__evaluation.as_ref().map(|e| &e.base);
+
+        let in_experiment = self
+            .experiment
+            .__evaluation
+            .as_ref()
+            .is_some_and(|e| e.is_user_in_experiment == Some(true));
+
+        ExposureSamplingKey::with_hasher(
+            evaluation,
+            &self.user.user_ref.data,
+            in_experiment,
+            hasher,
+        )
     }
 
     fn get_rule_id_ref(&self) -> &'a str {
@@ -14,6 +14,8 @@ use crate::{
     EvaluationDetails, SecondaryExposure,
 };
 
+use ahash::RandomState;
+
 use super::queued_event::{EnqueueOperation, QueuedEvent, QueuedExposure};
 
 pub struct EnqueueGateExpoOp<'a> {
@@ -58,11 +60,17 @@ impl EnqueueOperation for EnqueueGateExpoOp<'_> {
 
 impl<'a> QueuedExposure<'a> for EnqueueGateExpoOp<'a> {
     fn create_exposure_sampling_key(&self) -> ExposureSamplingKey {
-        let user_data = &self.user.user_ref.data;
         let evaluation = self.evaluation.as_ref().map(|e| &e.base);
         let value = self.evaluation.as_ref().is_some_and(|e| e.value);
 
-        ExposureSamplingKey::new(evaluation, user_data.as_ref(), value as u64)
+        ExposureSamplingKey::new(evaluation, self.user.get_user_values_hash(), value as u64)
+    }
+
+    fn create_exposure_sampling_key_with(&self, hasher: &RandomState) -> ExposureSamplingKey {
+        let evaluation = self.evaluation.as_ref().map(|e| &e.base);
+        let value = self.evaluation.as_ref().is_some_and(|e| e.value);
+
+        ExposureSamplingKey::with_hasher(evaluation, &self.user.user_ref.data, value, hasher)
     }
 
     fn get_rule_id_ref(&'a self) -> &'a str {
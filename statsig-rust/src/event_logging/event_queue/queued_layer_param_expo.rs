@@ -13,6 +13,8 @@ use crate::{
     EvaluationDetails, SecondaryExposure,
 };
 
+use ahash::RandomState;
+
 use super::queued_event::{EnqueueOperation, QueuedEvent, QueuedExposure};
 
 pub enum EnqueueLayerParamExpoOp<'a> {
@@ -71,14 +73,28 @@ impl<'a> QueuedExposure<'a> for EnqueueLayerParamExpoOp<'a> {
     fn create_exposure_sampling_key(&self) -> ExposureSamplingKey {
         let layer = self.get_layer_ref();
 
-        let user_data = &layer.__user.data;
+        let user_values_hash = layer
+            .__user_values_hash
+            .unwrap_or_else(|| layer.__user.data.create_user_values_hash());
         let evaluation = layer.__evaluation.as_ref().map(|e| &e.base);
 
         // todo: use Cow and pre-hash the parameter name
         let pname = self.get_parameter_name_ref();
         let pname_hash = ahash_str(pname);
 
-        ExposureSamplingKey::new(evaluation, user_data.as_ref(), pname_hash)
+        ExposureSamplingKey::new(evaluation, user_values_hash, pname_hash)
+    }
+
+    fn create_exposure_sampling_key_with(&self, hasher: &RandomState) -> ExposureSamplingKey {
+        let layer = self.get_layer_ref();
+        let evaluation = layer.__evaluation.as_ref().map(|e| &e.base);
+
+        ExposureSamplingKey::with_hasher(
+            evaluation,
+            &layer.__user.data,
+            self.get_parameter_name_ref(),
+            hasher,
+        )
     }
 
     fn get_rule_id_ref(&'a self) -> &'a str {
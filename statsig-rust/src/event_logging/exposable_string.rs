@@ -1,6 +1,8 @@
 use crate::{hashing::ahash_str, log_e};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::value::{to_raw_value, RawValue};
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::{borrow::Cow, sync::Arc};
 
 lazy_static::lazy_static! {
@@ -9,6 +11,12 @@ lazy_static::lazy_static! {
     pub static ref DISABLED_RULE: ExposableString = ExposableString::from_str_ref("disabled");
 }
 
+thread_local! {
+    // Only set while specs are parsed, see with_interned_strings
+    static PARSE_INTERNER: RefCell<Option<HashMap<Box<str>, ExposableString>>> =
+        const { RefCell::new(None) };
+}
+
 const TAG: &str = "ExposableString";
 
 #[derive(Debug, Clone)]
@@ -83,8 +91,50 @@ impl<'de> Deserialize<'de> for ExposableString {
         D: Deserializer<'de>,
     {
         let value = Cow::<'de, str>::deserialize(deserializer)?;
-        Ok(Self::from_str_ref(&value))
+
+        let interned = PARSE_INTERNER.with(|interner| {
+            let mut interner = interner.borrow_mut();
+            let strings = interner.as_mut()?;
+            if let Some(found) = strings.get(value.as_ref()) {
+                return Some(found.clone());
+            }
+
+            let created = Self::from_str_ref(&value);
+            strings.insert(Box::from(value.as_ref()), created.clone());
+            Some(created)
+        });
+
+        Ok(interned.unwrap_or_else(|| Self::from_str_ref(&value)))
+    }
+}
+
+/// Runs `parse` with an interner for the `ExposableString`s it deserializes, so values repeated
+/// across specs, such as rule IDs, share one allocation. The interner is local to this thread and
+/// dropped once `parse` returns. Outside of it, every string is deserialized on its own.
+pub(crate) fn with_interned_strings<T>(parse: impl FnOnce() -> T) -> T {
+    struct ResetOnDrop(bool);
+
+    impl Drop for ResetOnDrop {
+        fn drop(&mut self) {
+            if self.0 {
+                PARSE_INTERNER.with(|interner| *interner.borrow_mut() = None);
+            }
+        }
     }
+
+    // nested calls share the outer interner
+    let started = PARSE_INTERNER.with(|interner| {
+        let mut interner = interner.borrow_mut();
+        if interner.is_some() {
+            return false;
+        }
+
+        *interner = Some(HashMap::new());
+        true
+    });
+    let _reset = ResetOnDrop(started);
+
+    parse()
 }
 
 impl Serialize for ExposableString {
@@ -112,3 +162,21 @@ fn str_from_raw_value(raw_value: &RawValue) -> &str {
         ""
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strings_are_only_shared_within_a_parse() {
+        let parse = || serde_json::from_str::<Vec<ExposableString>>(r#"["rule", "rule"]"#).unwrap();
+
+        let shared = with_interned_strings(parse);
+        assert!(Arc::ptr_eq(&shared[0].raw_value, &shared[1].raw_value));
+
+        let separate = parse();
+        assert_eq!(separate[0], separate[1]);
+        assert!(!Arc::ptr_eq(&separate[0].raw_value, &separate[1].raw_value));
+        assert!(!Arc::ptr_eq(&shared[0].raw_value, &separate[0].raw_value));
+    }
+}
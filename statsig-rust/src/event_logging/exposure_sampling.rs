@@ -4,11 +4,13 @@ use crate::{
     global_configs::GlobalConfigs,
     log_d, log_e,
     user::user_data::UserData,
-    write_lock_or_noop, write_lock_or_return, DynamicValue,
+    write_lock_or_noop, DynamicValue, ExposureDedupeStore, InMemoryExposureDedupeStore,
+    StatsigOptions,
 };
-use ahash::AHashSet;
+use ahash::{AHashSet, RandomState};
 use chrono::Utc;
 use parking_lot::RwLock;
+use std::hash::Hash;
 use std::sync::{
     atomic::{AtomicU64, Ordering},
     Arc,
@@ -46,22 +48,24 @@ pub struct ExposureSampling {
     spec_sampling_set: RwLock<AHashSet<SpecAndRuleHashTuple>>,
     last_spec_sampling_reset: AtomicU64,
 
-    exposure_dedupe_set: RwLock<AHashSet<ExposureSamplingKey>>,
-    last_exposure_dedupe_reset: AtomicU64,
+    exposure_dedupe_store: Arc<dyn ExposureDedupeStore>,
 
     global_configs: Arc<GlobalConfigs>,
 }
 
 impl ExposureSampling {
-    pub fn new(sdk_key: &str) -> Self {
+    pub fn new(sdk_key: &str, options: &StatsigOptions) -> Self {
         let now = Utc::now().timestamp_millis() as u64;
+        let exposure_dedupe_store = match &options.exposure_dedupe_store {
+            Some(store) => store.clone(),
+            None => Arc::new(InMemoryExposureDedupeStore::new()),
+        };
 
         Self {
             spec_sampling_set: RwLock::from(AHashSet::default()),
             last_spec_sampling_reset: AtomicU64::from(now),
 
-            exposure_dedupe_set: RwLock::from(AHashSet::default()),
-            last_exposure_dedupe_reset: AtomicU64::from(now),
+            exposure_dedupe_store,
 
             global_configs: GlobalConfigs::get_instance(sdk_key),
         }
@@ -73,7 +77,12 @@ impl ExposureSampling {
             None => return EvtSamplingDecision::ForceSampled,
         };
 
-        let expo_sampling_key = exposure.create_exposure_sampling_key();
+        // stores with their own hasher get a key built from the raw values, which is then also used
+        // for sampling. It cannot reuse the user values hash cached on a prepared user.
+        let expo_sampling_key = match self.exposure_dedupe_store.key_hasher() {
+            Some(hasher) => exposure.create_exposure_sampling_key_with(hasher),
+            None => exposure.create_exposure_sampling_key(),
+        };
         if self.should_dedupe_exposure(&expo_sampling_key) {
             return EvtSamplingDecision::Deduped;
         }
@@ -110,18 +119,12 @@ impl ExposureSampling {
     }
 
     pub fn try_reset_all_sampling(&self) {
-        self.try_reset_exposure_dedupe_set();
+        self.exposure_dedupe_store.try_reset();
         self.try_reset_spec_sampling_set();
     }
 
     fn should_dedupe_exposure(&self, sampling_key: &ExposureSamplingKey) -> bool {
-        let mut dedupe_set = write_lock_or_return!(TAG, self.exposure_dedupe_set, false);
-        if dedupe_set.contains(sampling_key) {
-            return true;
-        }
-
-        dedupe_set.insert(sampling_key.clone());
-        false
+        self.exposure_dedupe_store.check_and_insert(sampling_key)
     }
 
     fn should_sample_based_on_evaluation(&self, extra_info: Option<&ExtraExposureInfo>) -> bool {
@@ -182,36 +185,6 @@ impl ExposureSampling {
         }
     }
 
-    fn try_reset_exposure_dedupe_set(&self) {
-        let now = Utc::now().timestamp_millis() as u64;
-        let last_dedupe_reset = self.last_exposure_dedupe_reset.load(Ordering::Relaxed);
-        let mut dedupe_map = match self
-            .exposure_dedupe_set
-            .try_write_for(std::time::Duration::from_secs(5))
-        {
-            Some(map) => map,
-            None => {
-                log_e!(TAG, "Failed to acquire write lock for exposure dedupe set");
-                return;
-            }
-        };
-
-        let has_expired = now - last_dedupe_reset > SAMPLING_TTL_MS;
-        let is_full = dedupe_map.len() > SAMPLING_MAX_KEYS;
-
-        if has_expired || is_full {
-            log_d!(
-                TAG,
-                "Resetting exposure dedupe set. has_expired: {:?}, is_full: {:?}",
-                has_expired,
-                is_full
-            );
-            dedupe_map.clear();
-            self.last_exposure_dedupe_reset
-                .store(now, Ordering::Relaxed);
-        }
-    }
-
     fn sample_key_exists(&self, key: &SpecAndRuleHashTuple) -> bool {
         match self
             .spec_sampling_set
@@ -273,12 +246,14 @@ pub struct ExposureSamplingKey {
 }
 
 impl ExposureSamplingKey {
-    pub fn new(evaluation: Option<&BaseEvaluation>, user: &UserData, additional_hash: u64) -> Self {
+    pub fn new(
+        evaluation: Option<&BaseEvaluation>,
+        user_values_hash: u64,
+        additional_hash: u64,
+    ) -> Self {
         let spec_name_hash = evaluation.as_ref().map_or(0, |e| e.name.hash_value);
         let rule_id_hash = evaluation.as_ref().map_or(0, |e| e.rule_id.hash_value);
 
-        let user_values_hash = user.create_user_values_hash();
-
         Self {
             spec_name_hash,
             rule_id_hash,
@@ -287,6 +262,24 @@ impl ExposureSamplingKey {
         }
     }
 
+    /// Hashes the raw values with `hasher`, see `ExposureDedupeStore::key_hasher`.
+    pub fn with_hasher(
+        evaluation: Option<&BaseEvaluation>,
+        user_data: &UserData,
+        additional: impl Hash,
+        hasher: &RandomState,
+    ) -> Self {
+        let spec_name_hash = evaluation.map_or(0, |e| hasher.hash_one(e.name.as_str()));
+        let rule_id_hash = evaluation.map_or(0, |e| hasher.hash_one(e.rule_id.as_str()));
+
+        Self {
+            spec_name_hash,
+            rule_id_hash,
+            user_values_hash: user_data.create_user_values_hash_with(hasher),
+            additional_hash: hasher.hash_one(additional),
+        }
+    }
+
     pub fn is_sampled(&self, sampling_rate: Option<u64>) -> bool {
         let sampling_rate = match sampling_rate {
             Some(rate) => rate,
@@ -10,6 +10,7 @@ const TAG: &str = stringify!(FlushInterval);
 pub struct FlushInterval {
     current_flush_interval_ms: AtomicU64,
     last_scheduled_flush_attempt_time: AtomicU64,
+    consecutive_failures: AtomicU64,
 }
 
 impl FlushInterval {
@@ -17,6 +18,7 @@ impl FlushInterval {
         Self {
             current_flush_interval_ms: AtomicU64::new(EventLoggerConstants::min_flush_interval_ms()),
             last_scheduled_flush_attempt_time: AtomicU64::new(get_now_timestamp()),
+            consecutive_failures: AtomicU64::new(0),
         }
     }
 
@@ -24,12 +26,18 @@ impl FlushInterval {
         self.current_flush_interval_ms.load(Relaxed)
     }
 
+    pub fn get_consecutive_failures(&self) -> u64 {
+        self.consecutive_failures.load(Relaxed)
+    }
+
     pub fn mark_scheduled_flush_attempt(&self) {
         let now = get_now_timestamp();
         self.last_scheduled_flush_attempt_time.store(now, Relaxed);
     }
 
     pub fn adjust_for_success(&self) {
+        self.consecutive_failures.store(0, Relaxed);
+
         let current = self.load_current_interval();
         let min_interval = EventLoggerConstants::min_flush_interval_ms();
         let adjusted = (current / 2).max(min_interval);
@@ -48,6 +56,8 @@ impl FlushInterval {
     }
 
     pub fn adjust_for_failure(&self) {
+        self.consecutive_failures.fetch_add(1, Relaxed);
+
         let current = self.load_current_interval();
         let adjusted = (current * 2).min(EventLoggerConstants::max_flush_interval_ms());
         self.current_flush_interval_ms.store(adjusted, Relaxed);
@@ -132,6 +142,18 @@ mod tests {
         assert_eq!(flush_interval.get_current_flush_interval_ms(), 15000);
     }
 
+    #[test]
+    fn test_consecutive_failures_reset_on_success() {
+        let flush_interval = FlushInterval::new();
+
+        flush_interval.adjust_for_failure();
+        flush_interval.adjust_for_failure();
+        assert_eq!(flush_interval.get_consecutive_failures(), 2);
+
+        flush_interval.adjust_for_success();
+        assert_eq!(flush_interval.get_consecutive_failures(), 0);
+    }
+
     #[test]
     fn test_success_backoff_min() {
         let flush_interval = FlushInterval::new();
@@ -0,0 +1,26 @@
+use crate::event_logging::exposure_sampling::ExposureSamplingKey;
+use ahash::RandomState;
+
+pub const EXPOSURE_DEDUPE_TTL_MS: u64 = 60_000;
+pub const EXPOSURE_DEDUPE_MAX_KEYS: usize = 100_000;
+
+/// Tracks which exposures have already been logged within the current dedupe window.
+///
+/// The default store is per-process. Provide a shared implementation (see
+/// [`crate::SharedMemoryExposureDedupeStore`]) through `StatsigOptions::exposure_dedupe_store`
+/// to dedupe across every process on a host.
+pub trait ExposureDedupeStore: Send + Sync {
+    /// Returns `true` if the key was already recorded within the window, otherwise records it
+    /// and returns `false`.
+    fn check_and_insert(&self, key: &ExposureSamplingKey) -> bool;
+
+    /// Stores shared between processes return a hasher seeded the same way in every process.
+    /// Keys passed to `check_and_insert` are then built from the raw values with it, instead of
+    /// with the SDK's per-process randomized hasher.
+    fn key_hasher(&self) -> Option<&RandomState> {
+        None
+    }
+
+    /// Called periodically by the EventLogger so stores can expire stale keys.
+    fn try_reset(&self) {}
+}
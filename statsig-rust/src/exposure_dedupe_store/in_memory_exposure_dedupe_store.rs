@@ -0,0 +1,66 @@
+use super::exposure_dedupe_store_trait::{
+    ExposureDedupeStore, EXPOSURE_DEDUPE_MAX_KEYS, EXPOSURE_DEDUPE_TTL_MS,
+};
+use crate::{
+    event_logging::exposure_sampling::ExposureSamplingKey, log_d, write_lock_or_noop,
+    write_lock_or_return,
+};
+use ahash::AHashSet;
+use chrono::Utc;
+use parking_lot::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const TAG: &str = "InMemoryExposureDedupeStore";
+
+pub struct InMemoryExposureDedupeStore {
+    dedupe_set: RwLock<AHashSet<ExposureSamplingKey>>,
+    last_reset: AtomicU64,
+}
+
+impl InMemoryExposureDedupeStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            dedupe_set: RwLock::from(AHashSet::default()),
+            last_reset: AtomicU64::from(Utc::now().timestamp_millis() as u64),
+        }
+    }
+}
+
+impl Default for InMemoryExposureDedupeStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExposureDedupeStore for InMemoryExposureDedupeStore {
+    fn check_and_insert(&self, key: &ExposureSamplingKey) -> bool {
+        let mut dedupe_set = write_lock_or_return!(TAG, self.dedupe_set, false);
+        if dedupe_set.contains(key) {
+            return true;
+        }
+
+        dedupe_set.insert(key.clone());
+        false
+    }
+
+    fn try_reset(&self) {
+        let now = Utc::now().timestamp_millis() as u64;
+        let last_reset = self.last_reset.load(Ordering::Relaxed);
+        let mut dedupe_set = write_lock_or_noop!(TAG, self.dedupe_set);
+
+        let has_expired = now.saturating_sub(last_reset) > EXPOSURE_DEDUPE_TTL_MS;
+        let is_full = dedupe_set.len() > EXPOSURE_DEDUPE_MAX_KEYS;
+
+        if has_expired || is_full {
+            log_d!(
+                TAG,
+                "Resetting exposure dedupe set. has_expired: {:?}, is_full: {:?}",
+                has_expired,
+                is_full
+            );
+            dedupe_set.clear();
+            self.last_reset.store(now, Ordering::Relaxed);
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod exposure_dedupe_store_trait;
+pub mod in_memory_exposure_dedupe_store;
+pub mod shared_memory_exposure_dedupe_store;
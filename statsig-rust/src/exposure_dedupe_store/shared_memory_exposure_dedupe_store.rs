@@ -0,0 +1,243 @@
+use super::exposure_dedupe_store_trait::{ExposureDedupeStore, EXPOSURE_DEDUPE_TTL_MS};
+use crate::{event_logging::exposure_sampling::ExposureSamplingKey, log_d, log_w, StatsigErr};
+use ahash::RandomState;
+use chrono::Utc;
+use file_guard::Lock;
+use memmap2::MmapMut;
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+const TAG: &str = "SharedMemoryExposureDedupeStore";
+
+const MAGIC: u64 = 0x5354_4744_4455_5031; // "STGDDUP1"
+const HEADER_SIZE: usize = 64;
+const SLOT_SIZE: usize = std::mem::size_of::<u64>();
+const MAX_PROBES: usize = 16;
+
+const EPOCH_BITS: u32 = 24;
+const EPOCH_MASK: u64 = (1 << EPOCH_BITS) - 1;
+
+const DEFAULT_SHARED_DEDUPE_CAPACITY: usize = 1 << 20;
+
+// Fixed so every process attached to a table builds the same keys
+const KEY_HASHER_SEEDS: [u64; 4] = [
+    0x243f_6a88_85a3_08d3,
+    0x1319_8a2e_0370_7344,
+    0xa409_3822_299f_31d0,
+    0x082e_fa98_ec4e_6c89,
+];
+
+/// An [`ExposureDedupeStore`] backed by a memory-mapped file, so every process on a host that
+/// opens the same path shares one dedupe set. On Linux, a path under `/dev/shm` keeps the table
+/// in shared memory.
+///
+/// Each slot is a single `u64` holding a key fingerprint and the dedupe window it was recorded
+/// in, so inserts are a lock-free compare-and-swap. Entries from previous windows are treated as
+/// empty, matching the TTL of the in-memory store. If every probed slot is taken the exposure is
+/// not deduped.
+///
+/// Keys are hashed with fixed seeds (see [`ExposureDedupeStore::key_hasher`]), so all processes
+/// sharing a table must run the same SDK version.
+pub struct SharedMemoryExposureDedupeStore {
+    // Keeps the mapping alive for `slots`
+    _mmap: MmapMut,
+    slots: *const AtomicU64,
+    capacity: usize,
+    key_hasher: RandomState,
+}
+
+// Safety: `slots` points into `_mmap`, which lives as long as the store and is only accessed
+// through atomics.
+unsafe impl Send for SharedMemoryExposureDedupeStore {}
+unsafe impl Sync for SharedMemoryExposureDedupeStore {}
+
+impl SharedMemoryExposureDedupeStore {
+    pub fn with_default_capacity(path: &str) -> Result<Self, StatsigErr> {
+        Self::new(path, DEFAULT_SHARED_DEDUPE_CAPACITY)
+    }
+
+    pub fn new(path: &str, capacity: usize) -> Result<Self, StatsigErr> {
+        if capacity == 0 {
+            return Err(StatsigErr::CustomError(
+                "Shared exposure dedupe capacity must be greater than zero".to_string(),
+            ));
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|e| StatsigErr::FileError(format!("Failed to open {path}: {e}")))?;
+
+        let capacity = Self::init_file_if_needed(&mut file, capacity)?;
+
+        // Safety: the file is only ever mutated through this layout, by this type
+        let mut mmap = unsafe { MmapMut::map_mut(&file) }
+            .map_err(|e| StatsigErr::FileError(format!("Failed to map {path}: {e}")))?;
+
+        if get_file_len(capacity).is_none_or(|len| mmap.len() < len) {
+            return Err(StatsigErr::FileError(format!(
+                "Shared exposure dedupe file {path} is truncated"
+            )));
+        }
+
+        let slots = unsafe { mmap.as_mut_ptr().add(HEADER_SIZE) } as *const AtomicU64;
+
+        log_d!(TAG, "Attached to {} with {} slots", path, capacity);
+
+        Ok(Self {
+            _mmap: mmap,
+            slots,
+            capacity,
+            key_hasher: RandomState::with_seeds(
+                KEY_HASHER_SEEDS[0],
+                KEY_HASHER_SEEDS[1],
+                KEY_HASHER_SEEDS[2],
+                KEY_HASHER_SEEDS[3],
+            ),
+        })
+    }
+
+    fn init_file_if_needed(file: &mut File, capacity: usize) -> Result<usize, StatsigErr> {
+        let mut lock = file_guard::lock(file, Lock::Exclusive, 0, 1)
+            .map_err(|e| StatsigErr::FileError(format!("Failed to lock dedupe file: {e}")))?;
+
+        let file_len = lock
+            .metadata()
+            .map_err(|e| StatsigErr::FileError(e.to_string()))?
+            .len() as usize;
+
+        if file_len >= HEADER_SIZE {
+            let mut header = [0u8; 16];
+            lock.seek(SeekFrom::Start(0))
+                .and_then(|_| lock.read_exact(&mut header))
+                .map_err(|e| StatsigErr::FileError(e.to_string()))?;
+
+            let magic = u64::from_le_bytes(header[0..8].try_into().unwrap_or_default());
+            let existing_capacity =
+                u64::from_le_bytes(header[8..16].try_into().unwrap_or_default());
+
+            if magic != MAGIC {
+                return Err(StatsigErr::FileError(
+                    "Shared exposure dedupe file has an unknown format".to_string(),
+                ));
+            }
+
+            // a corrupt capacity would otherwise index past the mapping or divide by zero
+            let existing_capacity = usize::try_from(existing_capacity)
+                .ok()
+                .filter(|c| *c > 0 && get_file_len(*c).is_some_and(|len| len <= file_len))
+                .ok_or_else(|| {
+                    StatsigErr::FileError(format!(
+                        "Shared exposure dedupe file has an invalid capacity of {existing_capacity} for {file_len} bytes"
+                    ))
+                })?;
+
+            if existing_capacity != capacity {
+                log_w!(
+                    TAG,
+                    "Requested capacity {} but existing table has {}. Using existing.",
+                    capacity,
+                    existing_capacity
+                );
+            }
+
+            return Ok(existing_capacity);
+        }
+
+        let table_len = get_file_len(capacity).ok_or_else(|| {
+            StatsigErr::FileError(format!(
+                "Shared exposure dedupe capacity {capacity} is too large"
+            ))
+        })?;
+
+        let mut header = [0u8; HEADER_SIZE];
+        header[0..8].copy_from_slice(&MAGIC.to_le_bytes());
+        header[8..16].copy_from_slice(&(capacity as u64).to_le_bytes());
+
+        lock.set_len(table_len as u64)
+            .and_then(|_| lock.seek(SeekFrom::Start(0)))
+            .and_then(|_| lock.write_all(&header))
+            .and_then(|_| lock.flush())
+            .map_err(|e| StatsigErr::FileError(format!("Failed to size dedupe file: {e}")))?;
+
+        Ok(capacity)
+    }
+
+    fn slot(&self, index: usize) -> &AtomicU64 {
+        // Safety: index is always reduced modulo capacity and the mapping is at least that long
+        unsafe { &*self.slots.add(index % self.capacity) }
+    }
+}
+
+impl ExposureDedupeStore for SharedMemoryExposureDedupeStore {
+    fn check_and_insert(&self, key: &ExposureSamplingKey) -> bool {
+        let fingerprint = get_key_fingerprint(key);
+        let epoch = (Utc::now().timestamp_millis() as u64 / EXPOSURE_DEDUPE_TTL_MS) & EPOCH_MASK;
+        let wanted = (fingerprint << EPOCH_BITS) | epoch;
+        let start = (fingerprint as usize) % self.capacity;
+
+        for probe in 0..MAX_PROBES {
+            let slot = self.slot(start + probe);
+            let mut current = slot.load(Ordering::Acquire);
+
+            loop {
+                if current == wanted {
+                    return true;
+                }
+
+                let is_vacant = current == 0 || (current & EPOCH_MASK) != epoch;
+                if !is_vacant {
+                    break; // occupied by another key in this window, try the next slot
+                }
+
+                match slot.compare_exchange(current, wanted, Ordering::AcqRel, Ordering::Acquire) {
+                    Ok(_) => return false,
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+
+        log_d!(TAG, "No free slot found, skipping dedupe");
+        false
+    }
+
+    fn key_hasher(&self) -> Option<&RandomState> {
+        Some(&self.key_hasher)
+    }
+}
+
+/// The size of a table with `capacity` slots, None if it overflows
+fn get_file_len(capacity: usize) -> Option<usize> {
+    capacity.checked_mul(SLOT_SIZE)?.checked_add(HEADER_SIZE)
+}
+
+/// Folds the sampling key into a non-zero 40 bit fingerprint
+fn get_key_fingerprint(key: &ExposureSamplingKey) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for part in [
+        key.spec_name_hash,
+        key.rule_id_hash,
+        key.user_values_hash,
+        key.additional_hash,
+    ] {
+        hash = mix64(hash ^ part);
+    }
+
+    let fingerprint = hash >> EPOCH_BITS;
+    if fingerprint == 0 {
+        1
+    } else {
+        fingerprint
+    }
+}
+
+// splitmix64 finalizer
+fn mix64(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
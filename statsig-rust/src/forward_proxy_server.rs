@@ -0,0 +1,89 @@
+use crate::observability::ops_stats::OpsStatsForInstance;
+use crate::observability::sdk_errors_observer::ErrorBoundaryEvent;
+use crate::spec_store::SpecStore;
+use crate::{log_d, log_e, log_error_to_statsig_and_console, StatsigErr, StatsigRuntime};
+use sigstat_grpc::statsig_grpc_server::StatsigGrpcServer;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+
+const TAG: &str = stringify!(ForwardProxyServer);
+const BG_TASK_TAG: &str = "grpc_forward_proxy_server";
+
+/// Serves the specs of a `SpecStore` to other SDK instances, until the runtime shuts down.
+pub(crate) fn spawn_forward_proxy_server(
+    sdk_key: &str,
+    address: SocketAddr,
+    spec_store: &Arc<SpecStore>,
+    statsig_runtime: &Arc<StatsigRuntime>,
+    ops_stats: &Arc<OpsStatsForInstance>,
+) -> Result<(), StatsigErr> {
+    let server = StatsigGrpcServer::new(sdk_key);
+    let mut updates_rx = spec_store.subscribe_to_updates();
+    publish_current_specs(&server, spec_store);
+
+    let weak_spec_store = Arc::downgrade(spec_store);
+    let ops_stats = ops_stats.clone();
+
+    statsig_runtime.spawn(BG_TASK_TAG, move |shutdown_notify| async move {
+        let (stop_tx, stop_rx) = oneshot::channel::<()>();
+        let serve = server.clone().serve_with_shutdown(address, async move {
+            tokio::select! {
+                () = shutdown_notify.notified() => {}
+                _ = stop_rx => {}
+            }
+        });
+        tokio::pin!(serve);
+
+        let forward_updates = async {
+            while updates_rx.changed().await.is_ok() {
+                match weak_spec_store.upgrade() {
+                    Some(spec_store) => publish_current_specs(&server, &spec_store),
+                    None => break,
+                }
+            }
+        };
+
+        log_d!(TAG, "Serving specs on {}", address);
+        let result = tokio::select! {
+            result = &mut serve => result,
+            () = forward_updates => {
+                log_d!(TAG, "SpecStore dropped, stopping forward proxy server");
+                let _ = stop_tx.send(());
+                serve.await
+            }
+        };
+
+        if let Err(e) = result {
+            log_error_to_statsig_and_console!(
+                &ops_stats,
+                TAG,
+                StatsigErr::GrpcError(e.to_string())
+            );
+        }
+    })?;
+
+    Ok(())
+}
+
+/// Publishes the current values as a full, uncompressed JSON response.
+fn publish_current_specs(server: &StatsigGrpcServer, spec_store: &SpecStore) {
+    let values = match spec_store.data.try_read_for(Duration::from_secs(5)) {
+        Some(data) => data.values.clone(),
+        None => {
+            log_e!(TAG, "Failed to acquire read lock: Failed to lock data");
+            return;
+        }
+    };
+
+    let lcut = values.time;
+    if lcut == 0 || server.get_last_updated() >= Some(lcut) {
+        return;
+    }
+
+    match serde_json::to_string(values.as_ref()) {
+        Ok(specs) => server.publish_specs(specs, lcut),
+        Err(e) => log_e!(TAG, "Failed to serialize specs: {}", e),
+    }
+}
@@ -0,0 +1,640 @@
+use crate::StatsigErr;
+use serde::ser::{self, Serialize};
+use serde_json::{Map, Number, Value};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+const MAGIC: &[u8; 4] = b"SGIR";
+const VERSION: u8 = 1;
+const MAX_DEPTH: usize = 128;
+
+// serde_json serializes RawValue as a struct with this name and a single string field
+const RAW_JSON_STRUCT_NAME: &str = "$serde_json::private::RawValue";
+
+const TAG_NULL: u8 = 0;
+const TAG_FALSE: u8 = 1;
+const TAG_TRUE: u8 = 2;
+const TAG_INT: u8 = 3;
+const TAG_UINT: u8 = 4;
+const TAG_FLOAT: u8 = 5;
+const TAG_STR: u8 = 6;
+const TAG_STR_REF: u8 = 7;
+const TAG_SEQ: u8 = 8;
+const TAG_MAP: u8 = 9;
+const TAG_END: u8 = 10;
+const TAG_RAW_JSON: u8 = 11;
+
+/// Compact binary encoding used by `GCIRResponseFormat::InitializeCompactBinary`.
+///
+/// Layout: the 4 byte magic `SGIR`, a version byte, then a single tagged value. Values mirror the
+/// JSON data model (null, bool, int, uint, float, string, seq, map). Integers are LEB128 varints
+/// (signed ones zigzag encoded) and seqs and maps are terminated by an end tag.
+///
+/// Strings are interned as they are written: the first occurrence is written in full and gets
+/// the next table index, repeats are written as a varint reference to that index. This keeps
+/// repeated gate names, rule IDs and field names (mostly from secondary exposures) to a byte or two.
+///
+/// Pre-serialized JSON (e.g. config values) is written as a raw JSON tag followed by its text.
+pub struct GCIRBinaryCodec;
+
+impl GCIRBinaryCodec {
+    pub fn encode<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>, StatsigErr> {
+        let mut encoder = BinaryEncoder {
+            buf: Vec::with_capacity(4096),
+            strings: HashMap::new(),
+        };
+
+        encoder.buf.extend_from_slice(MAGIC);
+        encoder.buf.push(VERSION);
+
+        value
+            .serialize(&mut encoder)
+            .map_err(|e| StatsigErr::GCIRError(e.0))?;
+
+        Ok(encoder.buf)
+    }
+
+    /// Decodes into the same JSON value the v2 (`InitializeWithSecondaryExposureMapping`) format produces.
+    pub fn decode(bytes: &[u8]) -> Result<Value, StatsigErr> {
+        if bytes.len() < MAGIC.len() + 1 || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(StatsigErr::GCIRError(
+                "Invalid binary response: missing header".to_string(),
+            ));
+        }
+
+        let version = bytes[MAGIC.len()];
+        if version != VERSION {
+            return Err(StatsigErr::GCIRError(format!(
+                "Invalid binary response: unsupported version {version}"
+            )));
+        }
+
+        let mut decoder = BinaryDecoder {
+            bytes,
+            pos: MAGIC.len() + 1,
+            strings: Vec::new(),
+        };
+
+        let value = decoder.read_value(0)?;
+        if decoder.pos != bytes.len() {
+            return Err(decoder.error("trailing bytes"));
+        }
+
+        Ok(value)
+    }
+}
+
+// ------------------------------------------------------------------------------- [ Encoding ]
+
+#[derive(Debug)]
+struct EncodeError(String);
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+impl ser::Error for EncodeError {
+    fn custom<T: Display>(msg: T) -> Self {
+        EncodeError(msg.to_string())
+    }
+}
+
+struct BinaryEncoder {
+    buf: Vec<u8>,
+    strings: HashMap<String, u64>,
+}
+
+impl BinaryEncoder {
+    fn write_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    fn write_int(&mut self, value: i64) {
+        self.buf.push(TAG_INT);
+        self.write_varint(((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    fn write_uint(&mut self, value: u64) {
+        self.buf.push(TAG_UINT);
+        self.write_varint(value);
+    }
+
+    fn write_str(&mut self, value: &str) {
+        if let Some(index) = self.strings.get(value) {
+            let index = *index;
+            self.buf.push(TAG_STR_REF);
+            self.write_varint(index);
+            return;
+        }
+
+        let index = self.strings.len() as u64;
+        self.strings.insert(value.to_string(), index);
+
+        self.buf.push(TAG_STR);
+        self.write_varint(value.len() as u64);
+        self.buf.extend_from_slice(value.as_bytes());
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut BinaryEncoder {
+    type Ok = ();
+    type Error = EncodeError;
+
+    type SerializeSeq = CompoundEncoder<'a>;
+    type SerializeTuple = CompoundEncoder<'a>;
+    type SerializeTupleStruct = CompoundEncoder<'a>;
+    type SerializeTupleVariant = CompoundEncoder<'a>;
+    type SerializeMap = CompoundEncoder<'a>;
+    type SerializeStruct = CompoundEncoder<'a>;
+    type SerializeStructVariant = CompoundEncoder<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<(), EncodeError> {
+        self.buf.push(if v { TAG_TRUE } else { TAG_FALSE });
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), EncodeError> {
+        self.write_int(i64::from(v));
+        Ok(())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), EncodeError> {
+        self.write_int(i64::from(v));
+        Ok(())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), EncodeError> {
+        self.write_int(i64::from(v));
+        Ok(())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), EncodeError> {
+        self.write_int(v);
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), EncodeError> {
+        self.write_uint(u64::from(v));
+        Ok(())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), EncodeError> {
+        self.write_uint(u64::from(v));
+        Ok(())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), EncodeError> {
+        self.write_uint(u64::from(v));
+        Ok(())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), EncodeError> {
+        self.write_uint(v);
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), EncodeError> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), EncodeError> {
+        // matches serde_json, which writes non-finite floats as null
+        if !v.is_finite() {
+            self.buf.push(TAG_NULL);
+            return Ok(());
+        }
+
+        self.buf.push(TAG_FLOAT);
+        self.buf.extend_from_slice(&v.to_le_bytes());
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), EncodeError> {
+        self.write_str(v.encode_utf8(&mut [0; 4]));
+        Ok(())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), EncodeError> {
+        self.write_str(v);
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), EncodeError> {
+        self.buf.push(TAG_SEQ);
+        for byte in v {
+            self.write_uint(u64::from(*byte));
+        }
+        self.buf.push(TAG_END);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), EncodeError> {
+        self.buf.push(TAG_NULL);
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), EncodeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), EncodeError> {
+        self.buf.push(TAG_NULL);
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), EncodeError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), EncodeError> {
+        self.write_str(variant);
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        self.buf.push(TAG_MAP);
+        self.write_str(variant);
+        value.serialize(&mut *self)?;
+        self.buf.push(TAG_END);
+        Ok(())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<CompoundEncoder<'a>, EncodeError> {
+        self.buf.push(TAG_SEQ);
+        Ok(CompoundEncoder::new(self, 1))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<CompoundEncoder<'a>, EncodeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<CompoundEncoder<'a>, EncodeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<CompoundEncoder<'a>, EncodeError> {
+        self.buf.push(TAG_MAP);
+        self.write_str(variant);
+        self.buf.push(TAG_SEQ);
+        Ok(CompoundEncoder::new(self, 2))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<CompoundEncoder<'a>, EncodeError> {
+        self.buf.push(TAG_MAP);
+        Ok(CompoundEncoder::new(self, 1))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _len: usize,
+    ) -> Result<CompoundEncoder<'a>, EncodeError> {
+        if name == RAW_JSON_STRUCT_NAME {
+            return Ok(CompoundEncoder::raw_json(self));
+        }
+
+        self.buf.push(TAG_MAP);
+        Ok(CompoundEncoder::new(self, 1))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<CompoundEncoder<'a>, EncodeError> {
+        self.buf.push(TAG_MAP);
+        self.write_str(variant);
+        self.buf.push(TAG_MAP);
+        Ok(CompoundEncoder::new(self, 2))
+    }
+}
+
+struct CompoundEncoder<'a> {
+    encoder: &'a mut BinaryEncoder,
+    end_tags: u8,
+    is_raw_json: bool,
+}
+
+impl<'a> CompoundEncoder<'a> {
+    fn new(encoder: &'a mut BinaryEncoder, end_tags: u8) -> Self {
+        Self {
+            encoder,
+            end_tags,
+            is_raw_json: false,
+        }
+    }
+
+    fn raw_json(encoder: &'a mut BinaryEncoder) -> Self {
+        Self {
+            encoder,
+            end_tags: 0,
+            is_raw_json: true,
+        }
+    }
+
+    fn write_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EncodeError> {
+        value.serialize(&mut *self.encoder)
+    }
+
+    fn write_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        if self.is_raw_json {
+            self.encoder.buf.push(TAG_RAW_JSON);
+            return value.serialize(&mut *self.encoder);
+        }
+
+        self.encoder.write_str(key);
+        value.serialize(&mut *self.encoder)
+    }
+
+    fn finish(self) -> Result<(), EncodeError> {
+        for _ in 0..self.end_tags {
+            self.encoder.buf.push(TAG_END);
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for CompoundEncoder<'_> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EncodeError> {
+        self.write_element(value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for CompoundEncoder<'_> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EncodeError> {
+        self.write_element(value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for CompoundEncoder<'_> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EncodeError> {
+        self.write_element(value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for CompoundEncoder<'_> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EncodeError> {
+        self.write_element(value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeMap for CompoundEncoder<'_> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), EncodeError> {
+        self.write_element(key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), EncodeError> {
+        self.write_element(value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for CompoundEncoder<'_> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        self.write_field(key, value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for CompoundEncoder<'_> {
+    type Ok = ();
+    type Error = EncodeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), EncodeError> {
+        self.write_field(key, value)
+    }
+
+    fn end(self) -> Result<(), EncodeError> {
+        self.finish()
+    }
+}
+
+// ------------------------------------------------------------------------------- [ Decoding ]
+
+struct BinaryDecoder<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    strings: Vec<String>,
+}
+
+impl BinaryDecoder<'_> {
+    fn error(&self, reason: &str) -> StatsigErr {
+        StatsigErr::GCIRError(format!(
+            "Invalid binary response: {reason} at byte {}",
+            self.pos
+        ))
+    }
+
+    fn read_byte(&mut self) -> Result<u8, StatsigErr> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| self.error("unexpected end of input"))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn peek_byte(&self) -> Result<u8, StatsigErr> {
+        self.bytes
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| self.error("unexpected end of input"))
+    }
+
+    fn read_varint(&mut self) -> Result<u64, StatsigErr> {
+        let mut result = 0u64;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.read_byte()?;
+            if shift >= 64 {
+                return Err(self.error("varint overflow"));
+            }
+
+            result |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    fn read_str(&mut self, tag: u8) -> Result<String, StatsigErr> {
+        match tag {
+            TAG_STR => {
+                let len = usize::try_from(self.read_varint()?)
+                    .map_err(|_| self.error("string too long"))?;
+                let end = self
+                    .pos
+                    .checked_add(len)
+                    .filter(|end| *end <= self.bytes.len())
+                    .ok_or_else(|| self.error("string out of bounds"))?;
+
+                let value = std::str::from_utf8(&self.bytes[self.pos..end])
+                    .map_err(|_| self.error("invalid utf-8"))?
+                    .to_string();
+                self.pos = end;
+                self.strings.push(value.clone());
+                Ok(value)
+            }
+            TAG_STR_REF => {
+                let index = self.read_varint()?;
+                usize::try_from(index)
+                    .ok()
+                    .and_then(|i| self.strings.get(i))
+                    .cloned()
+                    .ok_or_else(|| self.error("unknown string reference"))
+            }
+            _ => Err(self.error("expected string")),
+        }
+    }
+
+    fn read_value(&mut self, depth: usize) -> Result<Value, StatsigErr> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("max depth exceeded"));
+        }
+
+        let tag = self.read_byte()?;
+        match tag {
+            TAG_NULL => Ok(Value::Null),
+            TAG_FALSE => Ok(Value::Bool(false)),
+            TAG_TRUE => Ok(Value::Bool(true)),
+            TAG_INT => {
+                let zigzag = self.read_varint()?;
+                let value = ((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64);
+                Ok(Value::Number(Number::from(value)))
+            }
+            TAG_UINT => Ok(Value::Number(Number::from(self.read_varint()?))),
+            TAG_FLOAT => {
+                let end = self.pos + 8;
+                let bytes: [u8; 8] = self
+                    .bytes
+                    .get(self.pos..end)
+                    .and_then(|b| b.try_into().ok())
+                    .ok_or_else(|| self.error("float out of bounds"))?;
+                self.pos = end;
+
+                Number::from_f64(f64::from_le_bytes(bytes))
+                    .map(Value::Number)
+                    .ok_or_else(|| self.error("invalid float"))
+            }
+            TAG_STR | TAG_STR_REF => self.read_str(tag).map(Value::String),
+            TAG_RAW_JSON => {
+                let tag = self.read_byte()?;
+                let raw = self.read_str(tag)?;
+                serde_json::from_str(&raw).map_err(|_| self.error("invalid raw json"))
+            }
+            TAG_SEQ => {
+                let mut values = Vec::new();
+                while self.peek_byte()? != TAG_END {
+                    values.push(self.read_value(depth + 1)?);
+                }
+                self.pos += 1;
+                Ok(Value::Array(values))
+            }
+            TAG_MAP => {
+                let mut map = Map::new();
+                while self.peek_byte()? != TAG_END {
+                    let tag = self.read_byte()?;
+                    let key = self.read_str(tag)?;
+                    let value = self.read_value(depth + 1)?;
+                    map.insert(key, value);
+                }
+                self.pos += 1;
+                Ok(Value::Object(map))
+            }
+            _ => Err(self.error("unknown tag")),
+        }
+    }
+}
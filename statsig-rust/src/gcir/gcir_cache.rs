@@ -0,0 +1,395 @@
+use crate::evaluation::dynamic_string::DynamicString;
+use crate::evaluation::evaluation_types::{
+    AnyConfigEvaluation, GateEvaluation, LayerEvaluation, SecondaryExposure,
+};
+use crate::evaluation::evaluation_types_v2::{
+    AnyConfigEvaluationV2, GateEvaluationV2, LayerEvaluationV2,
+};
+use crate::evaluation::evaluator_context::EvaluatorContext;
+use crate::specs_response::param_store_types::Parameter;
+use crate::specs_response::spec_directory::SpecDirectory;
+use crate::specs_response::spec_types::SpecsResponseFull;
+use crate::{log_d, log_e, ClientInitResponseOptions};
+use parking_lot::Mutex;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::Write;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::gcir_process_iter::should_filter_entity;
+
+const TAG: &str = stringify!(GCIRCache);
+
+// separates key parts. JSON encoded values cannot contain it unescaped
+const KEY_SEPARATOR: char = '\u{1f}';
+// separates the names within a filter
+const KEY_LIST_SEPARATOR: char = '\u{1e}';
+
+pub(crate) type SerializeableParamStores = HashMap<String, HashMap<String, Parameter>>;
+
+/// The user independent sections of a client initialize response.
+pub(crate) enum CachedGCIREvaluations {
+    V1 {
+        feature_gates: HashMap<String, GateEvaluation>,
+        dynamic_configs: HashMap<String, AnyConfigEvaluation>,
+        layer_configs: HashMap<String, LayerEvaluation>,
+        param_stores: SerializeableParamStores,
+    },
+    V2 {
+        feature_gates: HashMap<String, GateEvaluationV2>,
+        dynamic_configs: HashMap<String, AnyConfigEvaluationV2>,
+        layer_configs: HashMap<String, LayerEvaluationV2>,
+        param_stores: SerializeableParamStores,
+        exposures: HashMap<String, SecondaryExposure>,
+    },
+}
+
+pub(crate) enum GCIRCacheLookup {
+    Hit(Arc<CachedGCIREvaluations>),
+    Miss(String),
+    Uncacheable,
+}
+
+/// Memoizes client initialize responses for users that only differ in fields no included spec reads.
+///
+/// Keys are built from the user fields listed in `Spec::fields_used` for every included spec and the
+/// specs they depend on (nested gates, layer delegates), any unit IDs used for bucketing, the
+/// response options and the current lcut. Requests including a spec without `fields_used`, or one
+/// with a `current_time` condition, are not cached. When full, the least recently used entry is evicted.
+///
+/// Lives in `SpecStoreData`, so it is cleared whenever the SpecStore swaps in new values.
+#[derive(Default)]
+pub(crate) struct GCIRCache {
+    key_plans: Mutex<HashMap<String, Option<Arc<GCIRCacheKeyPlan>>>>,
+    entries: Mutex<CacheEntries>,
+}
+
+struct CacheEntry {
+    evaluations: Arc<CachedGCIREvaluations>,
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct CacheEntries {
+    entries: HashMap<String, CacheEntry>,
+    // last use -> key, oldest first
+    recency: BTreeMap<u64, String>,
+    clock: u64,
+}
+
+#[derive(Default)]
+struct GCIRCacheKeyPlan {
+    // Option to match StatsigUserInternal::get_user_value
+    user_fields: Vec<Option<DynamicString>>,
+    unit_id_types: Vec<DynamicString>,
+}
+
+impl GCIRCache {
+    pub fn lookup(
+        &self,
+        values: &SpecsResponseFull,
+        context: &mut EvaluatorContext,
+        options: &ClientInitResponseOptions,
+        format: &str,
+    ) -> GCIRCacheLookup {
+        let options_key = match get_options_key(options, format) {
+            Some(key) => key,
+            None => return GCIRCacheLookup::Uncacheable,
+        };
+
+        let plan = match self.get_key_plan(values, options, &options_key) {
+            Some(plan) => plan,
+            None => return GCIRCacheLookup::Uncacheable,
+        };
+
+        let key = plan.build_key(&options_key, values.time, context);
+        let mut entries = match self.entries.try_lock_for(Duration::from_secs(5)) {
+            Some(entries) => entries,
+            None => {
+                log_e!(TAG, "Failed to acquire lock: Failed to lock entries");
+                return GCIRCacheLookup::Uncacheable;
+            }
+        };
+
+        match entries.get(&key) {
+            Some(cached) => GCIRCacheLookup::Hit(cached),
+            None => GCIRCacheLookup::Miss(key),
+        }
+    }
+
+    pub fn insert(&self, key: String, evaluations: Arc<CachedGCIREvaluations>, max_entries: usize) {
+        let mut entries = match self.entries.try_lock_for(Duration::from_secs(5)) {
+            Some(entries) => entries,
+            None => {
+                log_e!(TAG, "Failed to acquire lock: Failed to lock entries");
+                return;
+            }
+        };
+
+        entries.insert(key, evaluations, max_entries);
+    }
+
+    pub fn clear(&self) {
+        if let Some(mut plans) = self.key_plans.try_lock_for(Duration::from_secs(5)) {
+            plans.clear();
+        }
+
+        if let Some(mut entries) = self.entries.try_lock_for(Duration::from_secs(5)) {
+            *entries = CacheEntries::default();
+        }
+    }
+
+    fn get_key_plan(
+        &self,
+        values: &SpecsResponseFull,
+        options: &ClientInitResponseOptions,
+        options_key: &str,
+    ) -> Option<Arc<GCIRCacheKeyPlan>> {
+        let mut plans = match self.key_plans.try_lock_for(Duration::from_secs(5)) {
+            Some(plans) => plans,
+            None => {
+                log_e!(TAG, "Failed to acquire lock: Failed to lock key plans");
+                return None;
+            }
+        };
+
+        plans
+            .entry(options_key.to_string())
+            .or_insert_with(|| GCIRCacheKeyPlan::new(values, options).map(Arc::new))
+            .clone()
+    }
+}
+
+impl CacheEntries {
+    fn get(&mut self, key: &str) -> Option<Arc<CachedGCIREvaluations>> {
+        let entry = self.entries.get_mut(key)?;
+
+        self.clock += 1;
+        let key = self.recency.remove(&entry.last_used)?;
+        self.recency.insert(self.clock, key);
+        entry.last_used = self.clock;
+        Some(entry.evaluations.clone())
+    }
+
+    fn insert(&mut self, key: String, evaluations: Arc<CachedGCIREvaluations>, max_entries: usize) {
+        self.clock += 1;
+        let entry = CacheEntry {
+            evaluations,
+            last_used: self.clock,
+        };
+        if let Some(replaced) = self.entries.insert(key.clone(), entry) {
+            self.recency.remove(&replaced.last_used);
+        }
+        self.recency.insert(self.clock, key);
+
+        while self.entries.len() > max_entries {
+            match self.recency.pop_first() {
+                Some((_, oldest)) => {
+                    log_d!(
+                        TAG,
+                        "Cache full ({} entries), evicting the oldest",
+                        max_entries
+                    );
+                    self.entries.remove(&oldest)
+                }
+                None => break,
+            };
+        }
+    }
+}
+
+impl GCIRCacheKeyPlan {
+    fn new(values: &SpecsResponseFull, options: &ClientInitResponseOptions) -> Option<Self> {
+        let mut builder = KeyPlanBuilder {
+            values,
+            visited: HashSet::new(),
+            user_fields: BTreeSet::new(),
+            unit_id_types: BTreeSet::new(),
+        };
+
+        for directory in [
+            &values.feature_gates,
+            &values.dynamic_configs,
+            &values.layer_configs,
+        ] {
+            for spec_addy in directory.specs.values() {
+                let spec = &spec_addy.spec;
+                let name = spec_addy.name.as_str();
+
+                if spec.entity == "segment" || spec.entity == "holdout" {
+                    continue;
+                }
+
+                if should_filter_entity(spec, name, options) {
+                    continue;
+                }
+
+                builder.visit(directory, name)?;
+            }
+        }
+
+        Some(GCIRCacheKeyPlan {
+            user_fields: builder
+                .user_fields
+                .into_iter()
+                .map(|f| Some(DynamicString::from(f)))
+                .collect(),
+            unit_id_types: builder
+                .unit_id_types
+                .into_iter()
+                .map(DynamicString::from)
+                .collect(),
+        })
+    }
+
+    /// Computed user values are memoized in `context`, so they are reused when evaluating a miss.
+    fn build_key(&self, options_key: &str, lcut: u64, context: &mut EvaluatorContext) -> String {
+        let mut key = String::with_capacity(128);
+        key.push_str(options_key);
+        let _ = write!(key, "{KEY_SEPARATOR}{lcut}");
+
+        for field in &self.user_fields {
+            let name = field.as_ref().map(|f| f.value.as_str()).unwrap_or_default();
+            let computed;
+            let value = match context.user.get_user_value(field) {
+                Some(value) => Some(value),
+                None => {
+                    computed = context.get_computed_user_value(field);
+                    computed.as_ref()
+                }
+            };
+            append_key_value(&mut key, name, value.map(|v| &v.json_value));
+        }
+
+        for id_type in &self.unit_id_types {
+            let value = context.user.get_unit_id(id_type);
+            append_key_value(&mut key, &id_type.value, value.map(|v| &v.json_value));
+        }
+
+        key
+    }
+}
+
+struct KeyPlanBuilder<'a> {
+    values: &'a SpecsResponseFull,
+    visited: HashSet<String>,
+    user_fields: BTreeSet<String>,
+    unit_id_types: BTreeSet<String>,
+}
+
+impl KeyPlanBuilder<'_> {
+    /// Returns None if the spec cannot be cached
+    fn visit(&mut self, directory: &SpecDirectory, name: &str) -> Option<()> {
+        let values = self.values;
+        let spec = match directory.get(name) {
+            Some(spec_addy) => spec_addy.spec.clone(),
+            // unknown specs evaluate to the same default for everyone
+            None => return Some(()),
+        };
+
+        let visited_key = format!("{}:{}", spec.entity, name);
+        if !self.visited.insert(visited_key) {
+            return Some(());
+        }
+
+        self.user_fields
+            .extend(spec.fields_used.as_ref()?.iter().cloned());
+
+        for rule in &spec.rules {
+            if rule.pass_percentage > 0.0 && rule.pass_percentage < 100.0 {
+                self.unit_id_types.insert(rule.id_type.value.clone());
+            }
+
+            if let Some(delegate) = &rule.config_delegate {
+                self.visit(&values.dynamic_configs, delegate)?;
+            }
+
+            for condition_key in &rule.conditions {
+                let condition = match values.condition_map.get(condition_key) {
+                    Some(condition) => condition,
+                    None => continue,
+                };
+
+                match condition.condition_type.as_str() {
+                    "current_time" => return None,
+                    "pass_gate" | "fail_gate" => {
+                        let gate_name = condition
+                            .target_value
+                            .as_ref()
+                            .and_then(|v| v.string_value.as_ref())?;
+                        self.visit(&values.feature_gates, &gate_name.value)?;
+                    }
+                    "unit_id" | "user_bucket" => {
+                        self.unit_id_types.insert(condition.id_type.value.clone());
+                    }
+                    "ua_based" => {
+                        self.user_fields.insert("userAgent".to_string());
+                    }
+                    "ip_based" => {
+                        self.user_fields.insert("ip".to_string());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Some(())
+    }
+}
+
+/// None if the options cannot be cached
+fn get_options_key(options: &ClientInitResponseOptions, format: &str) -> Option<String> {
+    // local overrides can change at any time
+    if options.include_local_overrides == Some(true) {
+        return None;
+    }
+
+    let mut key = String::with_capacity(64);
+    let _ = write!(
+        key,
+        "{format}{KEY_SEPARATOR}{}{KEY_SEPARATOR}{}",
+        options.get_hash_algorithm(),
+        options.client_sdk_key.as_deref().unwrap_or_default()
+    );
+
+    for filter in [
+        &options.feature_gate_filter,
+        &options.experiment_filter,
+        &options.dynamic_config_filter,
+        &options.layer_filter,
+        &options.param_store_filter,
+        &options.entity_type_filter,
+        &options.target_app_id_filter,
+    ] {
+        key.push(KEY_SEPARATOR);
+        if let Some(filter) = filter {
+            append_sorted_names(&mut key, filter.iter());
+        }
+    }
+
+    key.push(KEY_SEPARATOR);
+    if let Some(patterns) = &options.exclude_filter {
+        append_sorted_names(&mut key, patterns.iter());
+    }
+
+    Some(key)
+}
+
+// count first, so an empty filter differs from an unset one and from a filter of one empty name
+fn append_sorted_names<'a>(key: &mut String, names: impl Iterator<Item = &'a String>) {
+    let mut names: Vec<&String> = names.collect();
+    names.sort();
+
+    let _ = write!(key, "{}", names.len());
+    for name in names {
+        key.push(KEY_LIST_SEPARATOR);
+        key.push_str(name);
+    }
+}
+
+fn append_key_value(key: &mut String, name: &str, value: Option<&serde_json::Value>) {
+    let _ = match value {
+        Some(value) => write!(key, "{KEY_SEPARATOR}{name}={value}"),
+        None => write!(key, "{KEY_SEPARATOR}{name}"),
+    };
+}
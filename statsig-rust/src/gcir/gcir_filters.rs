@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+
+use crate::ClientInitResponseOptions;
+
+pub(crate) const PARAM_STORE_ENTITY: &str = "param_store";
+
+/// Applies the entity independent filters of `ClientInitResponseOptions`.
+/// Returns true if the given spec or param store should be left out of the response.
+pub(crate) fn should_filter_by_options(
+    options: &ClientInitResponseOptions,
+    entity: &str,
+    name: &str,
+    target_app_ids: &Option<Vec<String>>,
+) -> bool {
+    if let Some(entity_types) = &options.entity_type_filter {
+        if !entity_types.contains(entity) {
+            return true;
+        }
+    }
+
+    if let Some(patterns) = &options.exclude_filter {
+        if patterns.iter().any(|p| glob_matches(p, name)) {
+            return true;
+        }
+    }
+
+    if let Some(filter_app_ids) = &options.target_app_id_filter {
+        let targeted = target_app_ids
+            .as_ref()
+            .is_some_and(|ids| ids.iter().any(|id| filter_app_ids.contains(id)));
+
+        if !targeted {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Exact names are checked first, entries containing `*` or `?` are treated as globs.
+pub(crate) fn name_filter_matches(filter: &HashSet<String>, name: &str) -> bool {
+    if filter.contains(name) {
+        return true;
+    }
+
+    filter
+        .iter()
+        .any(|pattern| is_glob(pattern) && glob_matches(pattern, name))
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// `*` matches any sequence of characters (including none), `?` matches exactly one.
+pub(crate) fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // let the last `*` consume one more character and retry
+                Some((star_p, star_n)) => {
+                    backtrack = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
@@ -1,5 +1,7 @@
 use crate::gcir::feature_gates_processor::get_gate_evaluations;
 
+use crate::evaluation::user_agent_parsing::CachedUserAgentParser;
+use crate::observability::observability_client_adapter::{MetricType, ObservabilityEvent};
 use crate::observability::ops_stats::OpsStatsForInstance;
 use crate::observability::ErrorBoundaryEvent;
 use crate::specs_response::spec_types::SessionReplayTrigger;
@@ -13,12 +15,13 @@ use crate::{
     spec_store::{SpecStore, SpecStoreData},
     statsig_metadata::StatsigMetadata,
     user::StatsigUserInternal,
-    OverrideAdapter, StatsigErr,
+    GeoIpProvider, OverrideAdapter, StatsigErr, StatsigOptions,
 };
 
 use crate::log_error_to_statsig_and_console;
 use rand::Rng;
 use serde::Deserialize;
+use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -26,7 +29,10 @@ use super::dynamic_configs_processor::{
     get_dynamic_config_evaluations, get_dynamic_config_evaluations_v2,
 };
 use super::feature_gates_processor::get_gate_evaluations_v2;
+use super::gcir_binary_codec::GCIRBinaryCodec;
+use super::gcir_cache::{CachedGCIREvaluations, GCIRCacheLookup};
 use super::gcir_options::ClientInitResponseOptions;
+use super::gcir_signing::GCIRSigner;
 use super::layer_configs_processor::{get_layer_evaluations, get_layer_evaluations_v2};
 use super::param_stores_processor::get_serializeable_param_stores;
 use super::target_app_id_utils::select_app_id;
@@ -35,13 +41,17 @@ pub struct GCIRFormatter {
     spec_store: Arc<SpecStore>,
     default_options: ClientInitResponseOptions,
     override_adapter: Option<Arc<dyn OverrideAdapter>>,
+    geo_ip_provider: Option<Arc<dyn GeoIpProvider>>,
+    user_agent_parser: Arc<CachedUserAgentParser>,
     ops_stats: Arc<OpsStatsForInstance>,
+    cache_max_entries: usize,
 }
 
 #[derive(Deserialize)]
 pub enum GCIRResponseFormat {
     Initialize,                             // v1
     InitializeWithSecondaryExposureMapping, // v2
+    InitializeCompactBinary,                // v2, binary encoded. See GCIRBinaryCodec
 }
 
 impl GCIRResponseFormat {
@@ -50,6 +60,7 @@ impl GCIRResponseFormat {
         match input {
             "v1" => Some(GCIRResponseFormat::Initialize),
             "v2" => Some(GCIRResponseFormat::InitializeWithSecondaryExposureMapping),
+            "binary" => Some(GCIRResponseFormat::InitializeCompactBinary),
             _ => None,
         }
     }
@@ -61,11 +72,16 @@ impl GCIRFormatter {
     pub fn new(
         spec_store: &Arc<SpecStore>,
         override_adapter: &Option<Arc<dyn OverrideAdapter>>,
+        user_agent_parser: &Arc<CachedUserAgentParser>,
         ops_stats: &Arc<OpsStatsForInstance>,
+        options: &StatsigOptions,
     ) -> Self {
         Self {
             spec_store: spec_store.clone(),
+            cache_max_entries: options.gcir_cache_max_entries.unwrap_or(0) as usize,
             override_adapter: override_adapter.as_ref().map(Arc::clone),
+            geo_ip_provider: options.geo_ip_provider.clone(),
+            user_agent_parser: user_agent_parser.clone(),
             ops_stats: ops_stats.clone(),
             default_options: ClientInitResponseOptions {
                 hash_algorithm: Some(HashAlgorithm::Djb2),
@@ -76,7 +92,11 @@ impl GCIRFormatter {
                 dynamic_config_filter: None,
                 layer_filter: None,
                 param_store_filter: None,
+                exclude_filter: None,
+                entity_type_filter: None,
+                target_app_id_filter: None,
                 response_format: None,
+                signing_key: None,
             },
         }
     }
@@ -119,6 +139,42 @@ impl GCIRFormatter {
             })
     }
 
+    pub fn get_as_binary_format(
+        &self,
+        user_internal: StatsigUserInternal,
+        hashing: &HashUtil,
+        options: &ClientInitResponseOptions,
+    ) -> Vec<u8> {
+        let response = self.get_as_v2_format(user_internal, hashing, options);
+
+        let encoded = match options.signing_key {
+            Some(_) => {
+                let mut response = json!(response);
+                self.sign_response(&mut response, options);
+                GCIRBinaryCodec::encode(&response)
+            }
+            None => GCIRBinaryCodec::encode(&response),
+        };
+
+        encoded.unwrap_or_else(|e| {
+            log_error_to_statsig_and_console!(&self.ops_stats, TAG, e);
+            Vec::new()
+        })
+    }
+
+    /// Attaches a signature if `options.signing_key` is set. On failure the response is left unsigned,
+    /// which verifying clients treat as tampered.
+    pub fn sign_response(&self, response: &mut Value, options: &ClientInitResponseOptions) {
+        let key = match &options.signing_key {
+            Some(key) => key,
+            None => return,
+        };
+
+        if let Err(e) = GCIRSigner::sign(response, key) {
+            log_error_to_statsig_and_console!(&self.ops_stats, TAG, e);
+        }
+    }
+
     fn get_v2_impl(
         &self,
         user_internal: &StatsigUserInternal,
@@ -131,33 +187,61 @@ impl GCIRFormatter {
             ));
         });
 
-        let mut sec_expo_hash_memo = HashMap::new();
         let mut context = self.setup_evaluator_context(user_internal, &data, options, hashing);
-        let mut exposures = HashMap::new();
-
-        let param_stores = get_serializeable_param_stores(&mut context, options);
         let evaluated_keys = get_evaluated_keys(user_internal);
         let session_replay_info = get_session_replay_info(&mut context, options, hashing);
 
+        let evaluations = match self.lookup_cache(&data, &mut context, options, "v2") {
+            GCIRCacheLookup::Hit(cached) => cached,
+            lookup => {
+                let mut sec_expo_hash_memo = HashMap::new();
+                let mut exposures = HashMap::new();
+
+                let evaluations = Arc::new(CachedGCIREvaluations::V2 {
+                    param_stores: get_serializeable_param_stores(&mut context, options),
+                    feature_gates: get_gate_evaluations_v2(
+                        &mut context,
+                        options,
+                        &mut sec_expo_hash_memo,
+                        &mut exposures,
+                    )?,
+                    dynamic_configs: get_dynamic_config_evaluations_v2(
+                        &mut context,
+                        options,
+                        &mut sec_expo_hash_memo,
+                        &mut exposures,
+                    )?,
+                    layer_configs: get_layer_evaluations_v2(
+                        &mut context,
+                        options,
+                        &mut sec_expo_hash_memo,
+                        &mut exposures,
+                    )?,
+                    exposures,
+                });
+
+                self.insert_into_cache(&data, lookup, &evaluations);
+                evaluations
+            }
+        };
+
+        let CachedGCIREvaluations::V2 {
+            feature_gates,
+            dynamic_configs,
+            layer_configs,
+            param_stores,
+            exposures,
+        } = evaluations.as_ref()
+        else {
+            return Err(StatsigErr::GCIRError(
+                "Cached evaluations do not match the v2 format".to_string(),
+            ));
+        };
+
         Ok(InitializeEvaluationsResponse {
-            feature_gates: get_gate_evaluations_v2(
-                &mut context,
-                options,
-                &mut sec_expo_hash_memo,
-                &mut exposures,
-            )?,
-            dynamic_configs: get_dynamic_config_evaluations_v2(
-                &mut context,
-                options,
-                &mut sec_expo_hash_memo,
-                &mut exposures,
-            )?,
-            layer_configs: get_layer_evaluations_v2(
-                &mut context,
-                options,
-                &mut sec_expo_hash_memo,
-                &mut exposures,
-            )?,
+            feature_gates: feature_gates.clone(),
+            dynamic_configs: dynamic_configs.clone(),
+            layer_configs: layer_configs.clone(),
             time: data.values.time,
             has_updates: true,
             hash_used: options.get_hash_algorithm().to_string(),
@@ -166,8 +250,8 @@ impl GCIRFormatter {
             sdk_params: HashMap::new(),
             evaluated_keys,
             sdk_info: get_sdk_info(),
-            param_stores,
-            exposures,
+            param_stores: param_stores.clone(),
+            exposures: exposures.clone(),
             can_record_session: session_replay_info.can_record_session,
             session_recording_rate: session_replay_info.session_recording_rate,
             recording_blocked: session_replay_info.recording_blocked,
@@ -191,21 +275,55 @@ impl GCIRFormatter {
             ));
         });
 
-        let mut sec_expo_hash_memo = HashMap::new();
         let mut context = self.setup_evaluator_context(user_internal, &data, options, hashing);
-
-        let param_stores = get_serializeable_param_stores(&mut context, options);
         let evaluated_keys = get_evaluated_keys(user_internal);
         let session_replay_info = get_session_replay_info(&mut context, options, hashing);
-        let gates = get_gate_evaluations(&mut context, options, &mut sec_expo_hash_memo)?;
-        let configs =
-            get_dynamic_config_evaluations(&mut context, options, &mut sec_expo_hash_memo)?;
-        let layers = get_layer_evaluations(&mut context, options, &mut sec_expo_hash_memo)?;
+
+        let evaluations = match self.lookup_cache(&data, &mut context, options, "v1") {
+            GCIRCacheLookup::Hit(cached) => cached,
+            lookup => {
+                let mut sec_expo_hash_memo = HashMap::new();
+
+                let evaluations = Arc::new(CachedGCIREvaluations::V1 {
+                    param_stores: get_serializeable_param_stores(&mut context, options),
+                    feature_gates: get_gate_evaluations(
+                        &mut context,
+                        options,
+                        &mut sec_expo_hash_memo,
+                    )?,
+                    dynamic_configs: get_dynamic_config_evaluations(
+                        &mut context,
+                        options,
+                        &mut sec_expo_hash_memo,
+                    )?,
+                    layer_configs: get_layer_evaluations(
+                        &mut context,
+                        options,
+                        &mut sec_expo_hash_memo,
+                    )?,
+                });
+
+                self.insert_into_cache(&data, lookup, &evaluations);
+                evaluations
+            }
+        };
+
+        let CachedGCIREvaluations::V1 {
+            feature_gates,
+            dynamic_configs,
+            layer_configs,
+            param_stores,
+        } = evaluations.as_ref()
+        else {
+            return Err(StatsigErr::GCIRError(
+                "Cached evaluations do not match the v1 format".to_string(),
+            ));
+        };
 
         Ok(InitializeResponse {
-            feature_gates: gates,
-            dynamic_configs: configs,
-            layer_configs: layers,
+            feature_gates: feature_gates.clone(),
+            dynamic_configs: dynamic_configs.clone(),
+            layer_configs: layer_configs.clone(),
             time: data.values.time,
             has_updates: true,
             hash_used: options.get_hash_algorithm().to_string(),
@@ -213,7 +331,7 @@ impl GCIRFormatter {
             sdk_params: HashMap::new(),
             evaluated_keys,
             sdk_info: get_sdk_info(),
-            param_stores,
+            param_stores: param_stores.clone(),
             can_record_session: session_replay_info.can_record_session,
             session_recording_rate: session_replay_info.session_recording_rate,
             recording_blocked: session_replay_info.recording_blocked,
@@ -226,6 +344,55 @@ impl GCIRFormatter {
         })
     }
 
+    fn lookup_cache(
+        &self,
+        data: &SpecStoreData,
+        context: &mut EvaluatorContext,
+        options: &ClientInitResponseOptions,
+        response_format: &str,
+    ) -> GCIRCacheLookup {
+        if self.cache_max_entries == 0 {
+            return GCIRCacheLookup::Uncacheable;
+        }
+
+        let lookup = data
+            .gcir_cache
+            .lookup(&data.values, context, options, response_format);
+
+        let (metric_name, reason) = match &lookup {
+            GCIRCacheLookup::Hit(_) => ("gcir_cache_hit", None),
+            GCIRCacheLookup::Miss(_) => ("gcir_cache_miss", Some("not_found")),
+            GCIRCacheLookup::Uncacheable => ("gcir_cache_miss", Some("uncacheable")),
+        };
+
+        let mut tags =
+            HashMap::from([("response_format".to_string(), response_format.to_string())]);
+        if let Some(reason) = reason {
+            tags.insert("reason".to_string(), reason.to_string());
+        }
+
+        self.ops_stats.log(ObservabilityEvent::new_event(
+            MetricType::Increment,
+            metric_name.to_string(),
+            1.0,
+            Some(tags),
+        ));
+
+        lookup
+    }
+
+    fn insert_into_cache(
+        &self,
+        data: &SpecStoreData,
+        lookup: GCIRCacheLookup,
+        evaluations: &Arc<CachedGCIREvaluations>,
+    ) {
+        if let GCIRCacheLookup::Miss(key) = lookup {
+            data.gcir_cache
+                .insert(key, evaluations.clone(), self.cache_max_entries);
+        }
+    }
+
     fn setup_evaluator_context<'a>(
         &'a self,
         user_internal: &'a StatsigUserInternal,
@@ -240,7 +407,15 @@ impl GCIRFormatter {
             _ => None,
         };
 
-        EvaluatorContext::new(user_internal, data, hashing, app_id, override_adapter)
+        EvaluatorContext::new(
+            user_internal,
+            data,
+            hashing,
+            app_id,
+            override_adapter,
+            self.geo_ip_provider.as_ref(),
+            &self.user_agent_parser,
+        )
     }
 }
 
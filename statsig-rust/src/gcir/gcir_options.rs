@@ -1,8 +1,10 @@
 use serde::Deserialize;
 use std::collections::HashSet;
 
-use crate::{GCIRResponseFormat, HashAlgorithm};
+use crate::{GCIRResponseFormat, GCIRSigningKey, HashAlgorithm};
 
+/// The per entity filters (`feature_gate_filter`, ...) accept exact names as well as
+/// glob patterns such as `checkout_*`, where `*` matches any sequence and `?` a single character.
 #[derive(Default, Deserialize)]
 pub struct ClientInitResponseOptions {
     pub hash_algorithm: Option<HashAlgorithm>,
@@ -13,7 +15,16 @@ pub struct ClientInitResponseOptions {
     pub dynamic_config_filter: Option<HashSet<String>>,
     pub layer_filter: Option<HashSet<String>>,
     pub param_store_filter: Option<HashSet<String>>,
+    /// Names matching any of these patterns are left out, regardless of the other filters.
+    pub exclude_filter: Option<Vec<String>>,
+    /// Only include these entity types (`feature_gate`, `dynamic_config`, `experiment`,
+    /// `autotune`, `layer`, `param_store`).
+    pub entity_type_filter: Option<HashSet<String>>,
+    /// Only include specs and param stores targeting at least one of these app IDs.
+    pub target_app_id_filter: Option<HashSet<String>>,
     pub response_format: Option<GCIRResponseFormat>,
+    /// Attaches a `signature` to string and binary responses. See `GCIRSigner`.
+    pub signing_key: Option<GCIRSigningKey>,
 }
 
 impl ClientInitResponseOptions {
@@ -1,3 +1,4 @@
+use super::gcir_filters::{name_filter_matches, should_filter_by_options};
 use super::target_app_id_utils::should_filter_spec_for_app;
 use crate::{
     evaluation::{
@@ -59,26 +60,26 @@ pub(crate) fn gcir_process_iter<T>(
     Ok(results)
 }
 
-fn should_filter_entity(spec: &Spec, name: &str, options: &ClientInitResponseOptions) -> bool {
-    match spec.entity.as_str() {
-        "feature_gate" => options
-            .feature_gate_filter
-            .as_ref()
-            .is_some_and(|f| !f.contains(name)),
-        "experiment" => options
-            .experiment_filter
-            .as_ref()
-            .is_some_and(|f| !f.contains(name)),
-        "dynamic_config" => options
-            .dynamic_config_filter
-            .as_ref()
-            .is_some_and(|f| !f.contains(name)),
-        "layer" => options
-            .layer_filter
-            .as_ref()
-            .is_some_and(|f| !f.contains(name)),
-        _ => false,
+pub(crate) fn should_filter_entity(
+    spec: &Spec,
+    name: &str,
+    options: &ClientInitResponseOptions,
+) -> bool {
+    if should_filter_by_options(options, &spec.entity, name, &spec.target_app_ids) {
+        return true;
     }
+
+    let filter = match spec.entity.as_str() {
+        "feature_gate" => &options.feature_gate_filter,
+        "experiment" => &options.experiment_filter,
+        "dynamic_config" => &options.dynamic_config_filter,
+        "layer" => &options.layer_filter,
+        _ => return false,
+    };
+
+    filter
+        .as_ref()
+        .is_some_and(|f| !name_filter_matches(f, name))
 }
 
 pub fn hash_secondary_exposures(
@@ -0,0 +1,219 @@
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use sha2::Sha256;
+
+use crate::StatsigErr;
+
+pub const GCIR_SIGNATURE_FIELD: &str = "signature";
+
+const HMAC_SHA256: &str = "hmac_sha256";
+const ED25519: &str = "ed25519";
+
+/// Key used to sign client initialize responses.
+///
+/// Deserializes from `{"algorithm": "ed25519" | "hmac_sha256", "key": "..."}`. Ed25519 keys are the
+/// base64 encoded 32 byte private key (seed), HMAC keys are used as is.
+#[derive(Clone, Deserialize)]
+#[serde(tag = "algorithm", content = "key", rename_all = "snake_case")]
+pub enum GCIRSigningKey {
+    HmacSha256(String),
+    Ed25519(String),
+}
+
+/// Key used to verify signed client initialize responses.
+///
+/// Ed25519 keys are the base64 encoded 32 byte public key, HMAC keys are the shared secret.
+#[derive(Clone, Deserialize)]
+#[serde(tag = "algorithm", content = "key", rename_all = "snake_case")]
+pub enum GCIRVerificationKey {
+    HmacSha256(String),
+    Ed25519(String),
+}
+
+impl GCIRSigningKey {
+    /// The key clients need to verify responses signed with this key.
+    pub fn verification_key(&self) -> Result<GCIRVerificationKey, StatsigErr> {
+        match self {
+            GCIRSigningKey::HmacSha256(secret) => {
+                Ok(GCIRVerificationKey::HmacSha256(secret.clone()))
+            }
+            GCIRSigningKey::Ed25519(private_key) => {
+                let signing_key = SigningKey::from_bytes(&decode_key(private_key)?);
+                let public_key = BASE64_STANDARD.encode(signing_key.verifying_key().as_bytes());
+                Ok(GCIRVerificationKey::Ed25519(public_key))
+            }
+        }
+    }
+}
+
+/// Detached signatures for client initialize responses.
+///
+/// The signature covers the canonical form of the response (object keys sorted, no whitespace)
+/// without the `signature` field, and is attached as
+/// `"signature": {"algorithm": "...", "value": "<base64>"}`.
+pub struct GCIRSigner;
+
+impl GCIRSigner {
+    pub fn sign(response: &mut Value, key: &GCIRSigningKey) -> Result<(), StatsigErr> {
+        let response_obj = as_object_mut(response)?;
+        response_obj.remove(GCIR_SIGNATURE_FIELD);
+
+        let payload = to_canonical_bytes(&Value::Object(response_obj.clone()));
+        let (algorithm, signature) = match key {
+            GCIRSigningKey::HmacSha256(secret) => (
+                HMAC_SHA256,
+                new_hmac(secret)?
+                    .chain_update(&payload)
+                    .finalize()
+                    .into_bytes()
+                    .to_vec(),
+            ),
+            GCIRSigningKey::Ed25519(private_key) => {
+                let signing_key = SigningKey::from_bytes(&decode_key(private_key)?);
+                (ED25519, signing_key.sign(&payload).to_bytes().to_vec())
+            }
+        };
+
+        let mut signature_obj = Map::new();
+        signature_obj.insert("algorithm".to_string(), Value::from(algorithm));
+        signature_obj.insert(
+            "value".to_string(),
+            Value::from(BASE64_STANDARD.encode(signature)),
+        );
+        response_obj.insert(
+            GCIR_SIGNATURE_FIELD.to_string(),
+            Value::Object(signature_obj),
+        );
+
+        Ok(())
+    }
+
+    /// Verifies a signed JSON response.
+    /// Returns Ok(false) if the response or signature were tampered with, and an error if the
+    /// response is malformed or was signed with another algorithm.
+    pub fn verify(response: &str, key: &GCIRVerificationKey) -> Result<bool, StatsigErr> {
+        let value: Value = serde_json::from_str(response).map_err(|e| {
+            StatsigErr::JsonParseError("ClientInitResponse".to_string(), e.to_string())
+        })?;
+        Self::verify_value(&value, key)
+    }
+
+    /// Same as `verify`, for responses that were already parsed, e.g. with `GCIRBinaryCodec::decode`.
+    pub fn verify_value(response: &Value, key: &GCIRVerificationKey) -> Result<bool, StatsigErr> {
+        let mut response_obj = response
+            .as_object()
+            .ok_or_else(|| gcir_err("Response is not an object"))?
+            .clone();
+
+        let signature_obj = response_obj
+            .remove(GCIR_SIGNATURE_FIELD)
+            .ok_or_else(|| gcir_err("Response is not signed"))?;
+        let algorithm = signature_obj.get("algorithm").and_then(Value::as_str);
+        let signature = signature_obj
+            .get("value")
+            .and_then(Value::as_str)
+            .ok_or_else(|| gcir_err("Signature value is missing"))?;
+        let signature = match BASE64_STANDARD.decode(signature) {
+            Ok(signature) => signature,
+            Err(_) => return Ok(false),
+        };
+
+        let payload = to_canonical_bytes(&Value::Object(response_obj));
+        match key {
+            GCIRVerificationKey::HmacSha256(secret) => {
+                ensure_algorithm(algorithm, HMAC_SHA256)?;
+                Ok(new_hmac(secret)?
+                    .chain_update(&payload)
+                    .verify_slice(&signature)
+                    .is_ok())
+            }
+            GCIRVerificationKey::Ed25519(public_key) => {
+                ensure_algorithm(algorithm, ED25519)?;
+                let verifying_key = VerifyingKey::from_bytes(&decode_key(public_key)?)
+                    .map_err(|e| gcir_err(&format!("Invalid Ed25519 public key: {e}")))?;
+                let signature = match ed25519_dalek::Signature::from_slice(&signature) {
+                    Ok(signature) => signature,
+                    Err(_) => return Ok(false),
+                };
+                Ok(verifying_key.verify(&payload, &signature).is_ok())
+            }
+        }
+    }
+}
+
+/// JSON with object keys sorted and no insignificant whitespace, so the signed bytes do not
+/// depend on map ordering.
+fn to_canonical_bytes(value: &Value) -> Vec<u8> {
+    let mut out = String::with_capacity(1024);
+    write_canonical(value, &mut out);
+    out.into_bytes()
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::from(key.as_str()).to_string());
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+        Value::Array(values) => {
+            out.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(value, out);
+            }
+            out.push(']');
+        }
+        _ => out.push_str(&value.to_string()),
+    }
+}
+
+fn as_object_mut(value: &mut Value) -> Result<&mut Map<String, Value>, StatsigErr> {
+    value
+        .as_object_mut()
+        .ok_or_else(|| gcir_err("Response is not an object"))
+}
+
+fn new_hmac(secret: &str) -> Result<Hmac<Sha256>, StatsigErr> {
+    Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .map_err(|e| gcir_err(&format!("Invalid HMAC key: {e}")))
+}
+
+fn decode_key(key: &str) -> Result<[u8; 32], StatsigErr> {
+    BASE64_STANDARD
+        .decode(key)
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| gcir_err("Ed25519 keys must be 32 bytes, base64 encoded"))
+}
+
+fn ensure_algorithm(actual: Option<&str>, expected: &str) -> Result<(), StatsigErr> {
+    if actual == Some(expected) {
+        return Ok(());
+    }
+
+    Err(gcir_err(&format!(
+        "Expected a {expected} signature, got {}",
+        actual.unwrap_or("none")
+    )))
+}
+
+fn gcir_err(message: &str) -> StatsigErr {
+    StatsigErr::GCIRError(message.to_string())
+}
@@ -2,8 +2,12 @@ pub mod gcir_options;
 
 pub(crate) mod dynamic_configs_processor;
 pub(crate) mod feature_gates_processor;
+pub(crate) mod gcir_binary_codec;
+pub(crate) mod gcir_cache;
+pub(crate) mod gcir_filters;
 pub(crate) mod gcir_formatter;
 pub(crate) mod gcir_process_iter;
+pub(crate) mod gcir_signing;
 pub(crate) mod layer_configs_processor;
 pub(crate) mod param_stores_processor;
 pub(crate) mod stringify_sec_exposures;
@@ -9,6 +9,7 @@ use crate::{
     ClientInitResponseOptions, HashAlgorithm,
 };
 
+use super::gcir_filters::{name_filter_matches, should_filter_by_options, PARAM_STORE_ENTITY};
 use super::target_app_id_utils::should_filter_config_for_app;
 
 type SerializeableParamStore = HashMap<String, HashMap<String, Parameter>>;
@@ -33,8 +34,12 @@ pub(crate) fn get_serializeable_param_stores(
             continue;
         }
 
+        if should_filter_by_options(options, PARAM_STORE_ENTITY, name, &store.target_app_ids) {
+            continue;
+        }
+
         if let Some(param_store_filter) = &options.param_store_filter {
-            if !param_store_filter.contains(name) {
+            if !name_filter_matches(param_store_filter, name) {
                 continue;
             }
         }
@@ -0,0 +1,13 @@
+use crate::DynamicValue;
+
+/// Resolves `ip_based` condition fields from the user's IP address.
+///
+/// By default only `country` is answered, from the table bundled with the SDK. Set
+/// `StatsigOptions::geo_ip_provider` to answer from another source instead (see
+/// [`crate::MmdbGeoIpProvider`] with the `with_mmdb` feature), which may also support fields like
+/// `region`, `city`, `asn` or `continent`.
+pub trait GeoIpProvider: Send + Sync {
+    /// Returns the value of `field` for `ip`, or `None` if the address or field is unknown.
+    /// Called on the evaluating thread for every `ip_based` condition, so it must not block.
+    fn get_value(&self, ip: &str, field: &str) -> Option<DynamicValue>;
+}
@@ -0,0 +1,95 @@
+use super::geo_ip_provider_trait::GeoIpProvider;
+use crate::{dyn_value, log_d, DynamicValue, StatsigErr};
+use maxminddb::{geoip2, MaxMindDBError, Mmap, Reader};
+use serde::Deserialize;
+use std::net::IpAddr;
+use std::path::Path;
+
+const TAG: &str = stringify!(MmdbGeoIpProvider);
+
+/// A [`GeoIpProvider`] backed by MaxMind format (`.mmdb`) databases, e.g. GeoIP2 / GeoLite2
+/// City, Country and ASN.
+///
+/// Supported fields are `country` and `continent` (ISO codes), `region` (ISO code of the most
+/// significant subdivision), `city` (English name), `asn` and `asn_organization`. When several
+/// databases are added, the first one that has a value for the field wins.
+pub struct MmdbGeoIpProvider {
+    readers: Vec<Reader<Mmap>>,
+}
+
+// the subset of the GeoIP2 City and ASN records that fields are read from
+#[derive(Deserialize)]
+struct MmdbRecord<'a> {
+    #[serde(borrow)]
+    city: Option<geoip2::city::City<'a>>,
+    #[serde(borrow)]
+    continent: Option<geoip2::city::Continent<'a>>,
+    #[serde(borrow)]
+    country: Option<geoip2::city::Country<'a>>,
+    #[serde(borrow)]
+    subdivisions: Option<Vec<geoip2::city::Subdivision<'a>>>,
+    autonomous_system_number: Option<u32>,
+    autonomous_system_organization: Option<&'a str>,
+}
+
+impl MmdbGeoIpProvider {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, StatsigErr> {
+        Self { readers: vec![] }.with_database(path)
+    }
+
+    /// Adds another database, consulted after the ones already added.
+    pub fn with_database<P: AsRef<Path>>(mut self, path: P) -> Result<Self, StatsigErr> {
+        let path = path.as_ref();
+        let reader = Reader::open_mmap(path).map_err(|e| {
+            StatsigErr::FileError(format!("Failed to open {}: {}", path.display(), e))
+        })?;
+
+        log_d!(
+            TAG,
+            "Opened {} ({})",
+            path.display(),
+            reader.metadata.database_type
+        );
+
+        self.readers.push(reader);
+        Ok(self)
+    }
+
+    fn lookup_field(reader: &Reader<Mmap>, ip: IpAddr, field: &str) -> Option<DynamicValue> {
+        let record: MmdbRecord = match reader.lookup(ip) {
+            Ok(record) => record,
+            Err(MaxMindDBError::AddressNotFoundError(_)) => return None,
+            Err(e) => {
+                log_d!(TAG, "Failed to look up {}: {}", ip, e);
+                return None;
+            }
+        };
+
+        match field {
+            "country" => record.country?.iso_code.map(|c| dyn_value!(c)),
+            "continent" => record.continent?.code.map(|c| dyn_value!(c)),
+            "region" => record
+                .subdivisions?
+                .first()?
+                .iso_code
+                .map(|c| dyn_value!(c)),
+            "city" => record.city?.names?.get("en").map(|name| dyn_value!(*name)),
+            "asn" => record.autonomous_system_number.map(|n| dyn_value!(n)),
+            "asn_organization" => record.autonomous_system_organization.map(|o| dyn_value!(o)),
+            _ => None,
+        }
+    }
+}
+
+impl GeoIpProvider for MmdbGeoIpProvider {
+    fn get_value(&self, ip: &str, field: &str) -> Option<DynamicValue> {
+        let ip = match ip.parse::<IpAddr>().ok()? {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(IpAddr::V6(v6), IpAddr::V4),
+            v4 => v4,
+        };
+
+        self.readers
+            .iter()
+            .find_map(|reader| Self::lookup_field(reader, ip, field))
+    }
+}
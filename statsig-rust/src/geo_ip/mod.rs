@@ -0,0 +1,3 @@
+pub mod geo_ip_provider_trait;
+#[cfg(feature = "with_mmdb")]
+pub mod mmdb_geo_ip_provider;
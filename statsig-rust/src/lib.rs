@@ -1,13 +1,29 @@
+pub use evaluation::cmab_evaluator::CMABRankedGroup;
 pub use evaluation::dynamic_value::DynamicValue;
 pub use evaluation::evaluation_details::EvaluationDetails;
 pub use evaluation::evaluation_types::SecondaryExposure;
+pub use evaluation::user_agent_parsing::{
+    ParsedUserAgent, UserAgentParserProvider, UserAgentParserType,
+};
 pub use event_logging_adapter::*;
+pub use exposure_dedupe_store::{
+    exposure_dedupe_store_trait::ExposureDedupeStore,
+    in_memory_exposure_dedupe_store::InMemoryExposureDedupeStore,
+    shared_memory_exposure_dedupe_store::SharedMemoryExposureDedupeStore,
+};
+pub use gcir::gcir_binary_codec::GCIRBinaryCodec;
 pub use gcir::gcir_formatter::GCIRResponseFormat;
 pub use gcir::gcir_options::ClientInitResponseOptions;
+pub use gcir::gcir_signing::{GCIRSigner, GCIRSigningKey, GCIRVerificationKey};
+pub use geo_ip::geo_ip_provider_trait::GeoIpProvider;
+#[cfg(feature = "with_mmdb")]
+pub use geo_ip::mmdb_geo_ip_provider::MmdbGeoIpProvider;
 pub use hashing::HashAlgorithm;
 pub use id_lists_adapter::{IdListsAdapter, StatsigHttpIdListsAdapter};
 pub use initialize_response::InitializeResponse;
 pub use instance_registry::InstanceRegistry;
+#[cfg(feature = "with_opentelemetry")]
+pub use observability::OpenTelemetryObservabilityClient;
 pub use observability::{
     observability_client_adapter::ObservabilityClient, ops_stats::OpsStatsEventObserver,
 };
@@ -18,6 +34,8 @@ pub use override_adapter::{
 pub use persistent_storage::persistent_storage_trait::*;
 pub use spec_store::SpecStore;
 pub use specs_adapter::*;
+pub use specs_export::SpecsExportFilter;
+pub use specs_staleness::StaleSpecsPolicy;
 pub use statsig::FailureDetails;
 pub use statsig::InitializeDetails;
 pub use statsig::Statsig;
@@ -26,10 +44,18 @@ pub use statsig_core_api_options::{
     LayerEvaluationOptions, ParameterStoreEvaluationOptions,
 };
 pub use statsig_err::StatsigErr;
+pub use statsig_err_code::{StatsigErrCategory, StatsigErrCode, StatsigErrContext, StatsigErrInfo};
+pub use statsig_health::{
+    EventLoggerHealth, IdListsHealth, LookupLoadState, RuntimeHealth, SpecsHealth,
+    StatsigHealthReport,
+};
 pub use statsig_options::StatsigOptions;
 pub use statsig_runtime::StatsigRuntime;
 pub use user::user_data::UserData as StatsigUserData;
-pub use user::{StatsigUser, StatsigUserBuilder};
+pub use user::{
+    ComputedUserAttributeProvider, PreparedUser, ScopedCustomFields, StatsigUser,
+    StatsigUserBuilder,
+};
 
 pub mod compression;
 pub mod data_store_interface;
@@ -54,7 +80,11 @@ pub mod user;
 
 mod dcs_str;
 mod event_logging_adapter;
+mod exposure_dedupe_store;
+#[cfg(feature = "with_grpc")]
+mod forward_proxy_server;
 mod gcir;
+mod geo_ip;
 mod id_lists_adapter;
 mod initialize_evaluations_response;
 mod initialize_response;
@@ -63,7 +93,11 @@ mod observability;
 mod persistent_storage;
 mod spec_store;
 mod specs_adapter;
+mod specs_export;
+mod specs_staleness;
 mod statsig;
 mod statsig_err;
+mod statsig_err_code;
+mod statsig_health;
 mod statsig_type_factories;
 mod utils;
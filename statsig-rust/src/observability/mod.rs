@@ -1,7 +1,12 @@
 pub use diagnostics_observer::DiagnosticsEvent;
 pub use sdk_errors_observer::ErrorBoundaryEvent;
 
+#[cfg(feature = "with_opentelemetry")]
+pub use opentelemetry_observability_client::OpenTelemetryObservabilityClient;
+
 pub mod diagnostics_observer;
 pub mod observability_client_adapter;
+#[cfg(feature = "with_opentelemetry")]
+pub mod opentelemetry_observability_client;
 pub mod ops_stats;
 pub mod sdk_errors_observer;
@@ -0,0 +1,157 @@
+use super::observability_client_adapter::ObservabilityClient;
+use super::ops_stats::OpsStatsEventObserver;
+use crate::log_d;
+use dashmap::DashMap;
+use opentelemetry::metrics::{Counter, Gauge, Histogram, Meter};
+use opentelemetry::{global, KeyValue};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+const TAG: &str = stringify!(OpenTelemetryObservabilityClient);
+
+const DEFAULT_METER_NAME: &str = "statsig-rust";
+const ERROR_METRIC_NAME: &str = "statsig.sdk.error";
+
+/// An [`ObservabilityClient`] that records SDK metrics as OpenTelemetry instruments.
+///
+/// - `increment` -> `Counter<f64>`
+/// - `gauge` -> `Gauge<f64>`
+/// - `dist` -> `Histogram<f64>`
+/// - `error` -> `Counter<u64>` named `statsig.sdk.error`, with the error tag as an attribute
+///
+/// Metric tags become attributes. Tags the SDK marks as high cardinality (e.g. `lcut`) are
+/// dropped unless enabled with [`OpenTelemetryObservabilityClient::with_high_cardinality_tags`].
+///
+/// The SDK only holds a `Weak` reference to its observability client, so keep the `Arc` alive
+/// for as long as the `Statsig` instance.
+pub struct OpenTelemetryObservabilityClient {
+    meter: Meter,
+    high_cardinality_tags: HashSet<String>,
+    counters: DashMap<String, Counter<f64>>,
+    gauges: DashMap<String, Gauge<f64>>,
+    histograms: DashMap<String, Histogram<f64>>,
+    errors: Counter<u64>,
+}
+
+impl OpenTelemetryObservabilityClient {
+    #[must_use]
+    pub fn new(meter: Meter) -> Self {
+        let errors = meter
+            .u64_counter(ERROR_METRIC_NAME)
+            .with_description("Errors encountered by the Statsig SDK")
+            .build();
+
+        Self {
+            meter,
+            high_cardinality_tags: HashSet::new(),
+            counters: DashMap::new(),
+            gauges: DashMap::new(),
+            histograms: DashMap::new(),
+            errors,
+        }
+    }
+
+    /// Uses a meter from the globally registered `MeterProvider`.
+    #[must_use]
+    pub fn from_global_meter_provider() -> Self {
+        Self::new(global::meter(DEFAULT_METER_NAME))
+    }
+
+    #[must_use]
+    pub fn with_high_cardinality_tags(mut self, tags: &[&str]) -> Self {
+        self.high_cardinality_tags = tags.iter().map(|t| (*t).to_string()).collect();
+        self
+    }
+
+    fn get_counter(&self, metric_name: String) -> Counter<f64> {
+        if let Some(counter) = self.counters.get(&metric_name) {
+            return counter.clone();
+        }
+
+        let counter = self.meter.f64_counter(metric_name.clone()).build();
+        self.counters.entry(metric_name).or_insert(counter).clone()
+    }
+
+    fn get_gauge(&self, metric_name: String) -> Gauge<f64> {
+        if let Some(gauge) = self.gauges.get(&metric_name) {
+            return gauge.clone();
+        }
+
+        let builder = self.meter.f64_gauge(metric_name.clone());
+        let gauge = match get_unit(&metric_name) {
+            Some(unit) => builder.with_unit(unit).build(),
+            None => builder.build(),
+        };
+        self.gauges.entry(metric_name).or_insert(gauge).clone()
+    }
+
+    fn get_histogram(&self, metric_name: String) -> Histogram<f64> {
+        if let Some(histogram) = self.histograms.get(&metric_name) {
+            return histogram.clone();
+        }
+
+        let builder = self.meter.f64_histogram(metric_name.clone());
+        let histogram = match get_unit(&metric_name) {
+            Some(unit) => builder.with_unit(unit).build(),
+            None => builder.build(),
+        };
+        self.histograms
+            .entry(metric_name)
+            .or_insert(histogram)
+            .clone()
+    }
+}
+
+impl ObservabilityClient for OpenTelemetryObservabilityClient {
+    fn init(&self) {
+        log_d!(TAG, "Recording SDK metrics with OpenTelemetry");
+    }
+
+    fn increment(&self, metric_name: String, value: f64, tags: Option<HashMap<String, String>>) {
+        self.get_counter(metric_name)
+            .add(value, &to_attributes(tags));
+    }
+
+    fn gauge(&self, metric_name: String, value: f64, tags: Option<HashMap<String, String>>) {
+        self.get_gauge(metric_name)
+            .record(value, &to_attributes(tags));
+    }
+
+    fn dist(&self, metric_name: String, value: f64, tags: Option<HashMap<String, String>>) {
+        self.get_histogram(metric_name)
+            .record(value, &to_attributes(tags));
+    }
+
+    fn error(&self, tag: String, _error: String) {
+        self.errors.add(1, &[KeyValue::new("tag", tag)]);
+    }
+
+    fn should_enable_high_cardinality_for_this_tag(&self, tag: String) -> Option<bool> {
+        Some(self.high_cardinality_tags.contains(&tag))
+    }
+
+    fn to_ops_stats_event_observer(self: Arc<Self>) -> Arc<dyn OpsStatsEventObserver> {
+        self
+    }
+}
+
+fn to_attributes(tags: Option<HashMap<String, String>>) -> Vec<KeyValue> {
+    tags.map(|tags| {
+        tags.into_iter()
+            .map(|(key, value)| KeyValue::new(key, value))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+fn get_unit(metric_name: &str) -> Option<&'static str> {
+    let short_name = metric_name
+        .strip_prefix("statsig.sdk.")
+        .unwrap_or(metric_name);
+
+    match short_name {
+        "initialization" | "config_propagation_diff" => Some("ms"),
+        _ if short_name.ends_with("_ms") => Some("ms"),
+        _ => None,
+    }
+}
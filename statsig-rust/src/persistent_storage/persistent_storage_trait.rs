@@ -106,7 +106,7 @@ pub fn make_layer_from_sticky_value(
     };
 
     make_layer(
-        user.to_loggable(),
+        user,
         name,
         Some(evaluation),
         details,
@@ -5,13 +5,15 @@ use crate::{
     Statsig,
 };
 use dashmap::DashMap;
-use std::{borrow::Cow, ops::Deref};
+use std::{borrow::Cow, ops::Deref, sync::Arc};
 
 const TAG: &str = "SdkEventEmitter";
 
+type Callback = Arc<dyn Fn(SdkEvent) + Send + Sync>;
+
 struct Listener {
     sub_id_value: String,
-    callback: Box<dyn Fn(SdkEvent) + Send + Sync>,
+    callback: Callback,
 }
 
 #[derive(Clone)]
@@ -55,9 +57,10 @@ impl SubscriptionID {
     }
 }
 
-#[derive(Default)]
+/// Clones share the same listeners.
+#[derive(Default, Clone)]
 pub struct SdkEventEmitter {
-    listeners: DashMap<u8, Vec<Listener>>,
+    listeners: Arc<DashMap<u8, Vec<Listener>>>,
 }
 
 impl SdkEventEmitter {
@@ -75,7 +78,7 @@ impl SdkEventEmitter {
 
         self.listeners.entry(code).or_default().push(Listener {
             sub_id_value: sub_id.value.clone(),
-            callback: Box::new(callback),
+            callback: Arc::new(callback),
         });
 
         sub_id
@@ -102,21 +105,23 @@ impl SdkEventEmitter {
 
     pub(crate) fn emit(&self, event: SdkEvent) {
         let all_code = SdkEventCode::from_name(SdkEvent::ALL).as_raw();
-        self.emit_to_listeners(&event, self.listeners.get(&all_code).as_deref());
+        self.emit_to_listeners(&event, all_code);
 
         let event_code = event.get_code().as_raw();
-        self.emit_to_listeners(&event, self.listeners.get(&event_code).as_deref());
+        self.emit_to_listeners(&event, event_code);
     }
 
-    fn emit_to_listeners(&self, event: &SdkEvent, listeners: Option<&Vec<Listener>>) {
-        let listeners = match listeners {
-            Some(listeners) => listeners,
+    fn emit_to_listeners(&self, event: &SdkEvent, code: u8) {
+        // callbacks run without holding the map's lock, so they can block (e.g. on the GIL) or
+        // subscribe without stalling other threads
+        let callbacks: Vec<Callback> = match self.listeners.get(&code) {
+            Some(listeners) => listeners.iter().map(|l| l.callback.clone()).collect(),
             None => return,
         };
 
-        listeners
+        callbacks
             .iter()
-            .for_each(|listener| (listener.callback)(event.clone()));
+            .for_each(|callback| callback(event.clone()));
     }
 }
 
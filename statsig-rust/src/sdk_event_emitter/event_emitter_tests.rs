@@ -137,3 +137,23 @@ fn test_sub_all() {
     emit(&mut event_emitter, SdkEvent::DYNAMIC_CONFIG_EVALUATED);
     assert_eq!(counter.load(Ordering::SeqCst), 2);
 }
+
+#[test]
+fn test_sub_from_callback() {
+    let mut event_emitter = SdkEventEmitter::default();
+    let (_, counter) = sub(&mut event_emitter, SdkEvent::DYNAMIC_CONFIG_EVALUATED);
+
+    let emitter_clone = event_emitter.clone();
+    let counter_clone = counter.clone();
+    event_emitter.subscribe(SdkEvent::GATE_EVALUATED, move |_| {
+        let counter = counter_clone.clone();
+        emitter_clone.subscribe(SdkEvent::DYNAMIC_CONFIG_EVALUATED, move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+    });
+
+    emit(&mut event_emitter, SdkEvent::GATE_EVALUATED);
+    emit(&mut event_emitter, SdkEvent::DYNAMIC_CONFIG_EVALUATED);
+
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
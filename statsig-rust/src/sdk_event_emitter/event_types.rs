@@ -12,6 +12,7 @@ pub enum SdkEventCode {
     DynamicConfigEvaluated = 3,
     ExperimentEvaluated = 4,
     LayerEvaluated = 5,
+    SpecsStale = 6,
 }
 
 impl SdkEventCode {
@@ -22,6 +23,7 @@ impl SdkEventCode {
             SdkEvent::DYNAMIC_CONFIG_EVALUATED => SdkEventCode::DynamicConfigEvaluated,
             SdkEvent::EXPERIMENT_EVALUATED => SdkEventCode::ExperimentEvaluated,
             SdkEvent::LAYER_EVALUATED => SdkEventCode::LayerEvaluated,
+            SdkEvent::SPECS_STALE => SdkEventCode::SpecsStale,
             _ => SdkEventCode::Unknown,
         }
     }
@@ -48,6 +50,11 @@ pub enum SdkEvent<'a> {
     LayerEvaluated {
         layer: Cow<'a, Layer>,
     },
+    SpecsStale {
+        age_ms: u64,
+        lcut: u64,
+        received_at: Option<u64>,
+    },
 }
 
 impl<'a> SdkEvent<'a> {
@@ -56,6 +63,7 @@ impl<'a> SdkEvent<'a> {
     pub const DYNAMIC_CONFIG_EVALUATED: &'static str = "dynamic_config_evaluated";
     pub const EXPERIMENT_EVALUATED: &'static str = "experiment_evaluated";
     pub const LAYER_EVALUATED: &'static str = "layer_evaluated";
+    pub const SPECS_STALE: &'static str = "specs_stale";
 
     pub fn get_code(&self) -> SdkEventCode {
         match self {
@@ -63,6 +71,7 @@ impl<'a> SdkEvent<'a> {
             SdkEvent::DynamicConfigEvaluated { .. } => SdkEventCode::DynamicConfigEvaluated,
             SdkEvent::ExperimentEvaluated { .. } => SdkEventCode::ExperimentEvaluated,
             SdkEvent::LayerEvaluated { .. } => SdkEventCode::LayerEvaluated,
+            SdkEvent::SpecsStale { .. } => SdkEventCode::SpecsStale,
         }
     }
 
@@ -73,6 +82,7 @@ impl<'a> SdkEvent<'a> {
             SdkEventCode::DynamicConfigEvaluated => SdkEvent::DYNAMIC_CONFIG_EVALUATED,
             SdkEventCode::ExperimentEvaluated => SdkEvent::EXPERIMENT_EVALUATED,
             SdkEventCode::LayerEvaluated => SdkEvent::LAYER_EVALUATED,
+            SdkEventCode::SpecsStale => SdkEvent::SPECS_STALE,
             SdkEventCode::Unknown => "unknown",
         }
     }
@@ -103,6 +113,15 @@ impl<'a> SdkEvent<'a> {
             SdkEvent::LayerEvaluated { layer } => {
                 map.insert("layer".to_string(), json!(layer));
             }
+            SdkEvent::SpecsStale {
+                age_ms,
+                lcut,
+                received_at,
+            } => {
+                map.insert("age_ms".to_string(), json!(age_ms));
+                map.insert("lcut".to_string(), json!(lcut));
+                map.insert("received_at".to_string(), json!(received_at));
+            }
         }
 
         map
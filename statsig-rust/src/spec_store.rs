@@ -1,35 +1,60 @@
 use crate::compression::zstd_decompression_dict::DictionaryDecoder;
 use crate::data_store_interface::{get_data_adapter_dcs_key, DataStoreTrait};
+use crate::event_logging::exposable_string::with_interned_strings;
+use crate::gcir::gcir_cache::GCIRCache;
 use crate::global_configs::GlobalConfigs;
 use crate::id_lists_adapter::{IdList, IdListsUpdateListener};
 use crate::observability::observability_client_adapter::{MetricType, ObservabilityEvent};
 use crate::observability::ops_stats::{OpsStatsForInstance, OPS_STATS};
 use crate::observability::sdk_errors_observer::ErrorBoundaryEvent;
-use crate::specs_response::spec_types::{SpecsResponseFull, SpecsResponseNoUpdates};
+use crate::specs_response::spec_types::{
+    SpecsResponseDelta, SpecsResponseFull, SpecsResponseNoUpdates,
+};
 use crate::specs_response::spec_types_encoded::DecodedSpecsResponse;
+use crate::specs_response::specs_payload::{with_retained_payload, SpecsPayload};
 use crate::utils::maybe_trim_malloc;
 use crate::{
     log_d, log_e, log_error_to_statsig_and_console, SpecsInfo, SpecsSource, SpecsUpdate,
     SpecsUpdateListener, StatsigErr, StatsigRuntime,
 };
 use chrono::Utc;
-use parking_lot::RwLock;
+use parking_lot::{RwLock, RwLockWriteGuard};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::sync::watch;
 
 pub struct SpecStoreData {
     pub source: SpecsSource,
     pub source_api: Option<String>,
     pub time_received_at: Option<u64>,
-    pub values: SpecsResponseFull,
+    pub time_checked_at: Option<u64>,
+    pub(crate) values: Arc<SpecsResponseFull>,
     pub next_values: Option<SpecsResponseFull>,
     pub decompression_dict: Option<DictionaryDecoder>,
     pub id_lists: HashMap<String, IdList>,
+    pub id_lists_time_received_at: Option<u64>,
+    pub(crate) gcir_cache: GCIRCache,
+}
+
+impl SpecStoreData {
+    /// The current specs. They are only replaced through the `SpecStore`.
+    pub fn values(&self) -> &SpecsResponseFull {
+        &self.values
+    }
 }
 
 const TAG: &str = stringify!(SpecStore);
 
+enum ParsedSpecsResponse {
+    Full(Option<DictionaryDecoder>),
+    Delta(Box<SpecsResponseDelta>, Option<DictionaryDecoder>),
+    NoUpdates,
+}
+
+/// (prev_source, prev_lcut, curr_lcut, prev_values to drop outside of the lock)
+type SwappedValues = (SpecsSource, u64, u64, Option<Arc<SpecsResponseFull>>);
+
 pub struct SpecStore {
     pub data: Arc<RwLock<SpecStoreData>>,
 
@@ -38,6 +63,8 @@ pub struct SpecStore {
     statsig_runtime: Arc<StatsigRuntime>,
     ops_stats: Arc<OpsStatsForInstance>,
     global_configs: Arc<GlobalConfigs>,
+    lcut_tx: watch::Sender<u64>,
+    zero_copy_specs: bool,
 }
 
 impl SpecStore {
@@ -51,19 +78,41 @@ impl SpecStore {
         SpecStore {
             hashed_sdk_key,
             data: Arc::new(RwLock::new(SpecStoreData {
-                values: SpecsResponseFull::default(),
+                values: Arc::new(SpecsResponseFull::default()),
                 next_values: Some(SpecsResponseFull::default()),
                 time_received_at: None,
+                time_checked_at: None,
                 source: SpecsSource::Uninitialized,
                 source_api: None,
                 decompression_dict: None,
                 id_lists: HashMap::new(),
+                id_lists_time_received_at: None,
+                gcir_cache: GCIRCache::default(),
             })),
             data_store,
             statsig_runtime,
             ops_stats: OPS_STATS.get_for_instance(sdk_key),
             global_configs: GlobalConfigs::get_instance(sdk_key),
+            lcut_tx: watch::channel(0).0,
+            zero_copy_specs: false,
+        }
+    }
+
+    /// Return values point into the received payload instead of being copied out, and the
+    /// previous values are dropped on update rather than kept to deserialize the next one into.
+    /// See `StatsigOptions::enable_zero_copy_specs`.
+    #[must_use]
+    pub fn with_zero_copy_specs(mut self, enabled: bool) -> Self {
+        self.zero_copy_specs = enabled;
+        if enabled {
+            self.data.write().next_values = None;
         }
+        self
+    }
+
+    /// Receives the lcut of the current values every time they are replaced.
+    pub fn subscribe_to_updates(&self) -> watch::Receiver<u64> {
+        self.lcut_tx.subscribe()
     }
 
     pub fn set_source(&self, source: SpecsSource) {
@@ -79,18 +128,43 @@ impl SpecStore {
     }
 
     pub fn get_current_values(&self) -> Option<SpecsResponseFull> {
-        let data = match self.data.try_read_for(Duration::from_secs(5)) {
-            Some(data) => data,
+        let json = self.serialize_current_values()?;
+        serde_json::from_slice::<SpecsResponseFull>(&json).ok()
+    }
+
+    /// The current values as a complete specs response. The lock is only held to clone the `Arc`.
+    pub fn serialize_current_values(&self) -> Option<Vec<u8>> {
+        let values = match self.data.try_read_for(Duration::from_secs(5)) {
+            Some(data) => data.values.clone(),
             None => {
                 log_e!(TAG, "Failed to acquire read lock: Failed to lock data");
                 return None;
             }
         };
-        let json = serde_json::to_string(&data.values).ok()?;
-        serde_json::from_str::<SpecsResponseFull>(&json).ok()
+
+        match serde_json::to_vec(values.as_ref()) {
+            Ok(json) => Some(json),
+            Err(e) => {
+                log_e!(TAG, "Failed to serialize specs: {}", e);
+                None
+            }
+        }
     }
 
     pub fn set_values(&self, specs_update: SpecsUpdate) -> Result<(), StatsigErr> {
+        self.set_values_from_payload(
+            Arc::new(SpecsPayload::from(specs_update.data)),
+            specs_update.source,
+            specs_update.source_api,
+        )
+    }
+
+    pub fn set_values_from_payload(
+        &self,
+        payload: Arc<SpecsPayload>,
+        source: SpecsSource,
+        source_api: Option<String>,
+    ) -> Result<(), StatsigErr> {
         let (mut next_values, decompression_dict) =
             match self.data.try_write_for(Duration::from_secs(5)) {
                 Some(mut data) => (
@@ -105,17 +179,28 @@ impl SpecStore {
                 }
             };
 
-        let decompression_dict =
-            match self.parse_specs_response(&specs_update, &mut next_values, decompression_dict) {
-                Ok(Some(full)) => full,
-                Ok(None) => {
-                    self.ops_stats_log_no_update(specs_update.source, specs_update.source_api);
-                    return Ok(());
-                }
-                Err(e) => {
-                    return Err(e);
-                }
-            };
+        // mapped payloads are shared with other processes or readers, so are always read in place
+        let retained = self.zero_copy_specs || payload.is_mapped();
+
+        let decompression_dict = match self.parse_specs_response(
+            &payload,
+            &mut next_values,
+            decompression_dict,
+            retained,
+        ) {
+            Ok(ParsedSpecsResponse::Full(dict)) => dict,
+            Ok(ParsedSpecsResponse::Delta(delta, dict)) => {
+                return self.apply_delta(delta, dict, source, source_api);
+            }
+            Ok(ParsedSpecsResponse::NoUpdates) => {
+                self.mark_checked();
+                self.ops_stats_log_no_update(source, source_api);
+                return Ok(());
+            }
+            Err(e) => {
+                return Err(e);
+            }
+        };
 
         if self.are_current_values_newer(&next_values) {
             return Ok(());
@@ -124,21 +209,26 @@ impl SpecStore {
         self.try_update_global_configs(&next_values);
 
         let now = Utc::now().timestamp_millis() as u64;
-        let (prev_source, prev_lcut, curr_values_time) = self.swap_current_with_next(
+        let (prev_source, prev_lcut, curr_values_time, prev_values) = self.swap_current_with_next(
             next_values,
-            &specs_update,
+            &source,
             decompression_dict,
             now,
-            specs_update.source_api.clone(),
+            source_api.clone(),
+            retained,
         )?;
 
-        self.try_update_data_store(&specs_update.source, specs_update.data, now);
+        // dropped outside of the lock, releasing the previous payload if nothing else retains it
+        drop(prev_values);
+
+        self.lcut_tx.send_replace(curr_values_time);
+        self.try_update_data_store(&source, payload, now);
         self.ops_stats_log_config_propagation_diff(
             curr_values_time,
             prev_lcut,
-            &specs_update.source,
+            &source,
             &prev_source,
-            specs_update.source_api,
+            source_api,
         );
 
         // Glibc requested more memory than needed when deserializing a big json blob
@@ -155,33 +245,68 @@ impl SpecStore {
 impl SpecStore {
     fn parse_specs_response(
         &self,
-        values: &SpecsUpdate,
+        payload: &Arc<SpecsPayload>,
         next_values: &mut SpecsResponseFull,
         decompression_dict: Option<DictionaryDecoder>,
-    ) -> Result<Option<Option<DictionaryDecoder>>, StatsigErr> {
-        let full_update_decoder_result = DecodedSpecsResponse::from_slice(
-            &values.data,
-            next_values,
-            decompression_dict.as_ref(),
-        );
+        retained: bool,
+    ) -> Result<ParsedSpecsResponse, StatsigErr> {
+        let mut decode = || {
+            with_interned_strings(|| {
+                DecodedSpecsResponse::from_slice(
+                    payload.as_bytes(),
+                    next_values,
+                    decompression_dict.as_ref(),
+                )
+            })
+        };
+
+        let full_update_decoder_result = match retained {
+            true => with_retained_payload(payload, decode),
+            false => decode(),
+        };
+
+        if let Ok(result) = &full_update_decoder_result {
+            if next_values.is_delta != Some(true) {
+                if next_values.has_updates {
+                    return Ok(ParsedSpecsResponse::Full(result.clone()));
+                }
 
-        if let Ok(result) = full_update_decoder_result {
-            if next_values.has_updates {
-                return Ok(Some(result));
+                return Ok(ParsedSpecsResponse::NoUpdates);
             }
+        }
+
+        // deltas are small, so falling through to a second parse is cheap
+        let mut delta = SpecsResponseDelta::default();
+        let mut decode_delta = || {
+            with_interned_strings(|| {
+                DecodedSpecsResponse::from_slice(
+                    payload.as_bytes(),
+                    &mut delta,
+                    decompression_dict.as_ref(),
+                )
+            })
+        };
 
-            return Ok(None);
+        let delta_decoder_result = match retained {
+            true => with_retained_payload(payload, decode_delta),
+            false => decode_delta(),
+        };
+
+        if let Ok(result) = delta_decoder_result {
+            if delta.is_delta {
+                return Ok(ParsedSpecsResponse::Delta(Box::new(delta), result));
+            }
         }
 
         let mut next_no_updates = SpecsResponseNoUpdates { has_updates: false };
         let no_updates_decoder_result = DecodedSpecsResponse::from_slice(
-            &values.data,
+            payload.as_bytes(),
             &mut next_no_updates,
             decompression_dict.as_ref(),
         );
 
         if no_updates_decoder_result.is_ok() && !next_no_updates.has_updates {
-            return Ok(None);
+            return Ok(ParsedSpecsResponse::NoUpdates);
         }
 
         let error = full_update_decoder_result.err().map_or_else(
@@ -193,27 +318,130 @@ impl SpecStore {
         Err(error)
     }
 
+    fn apply_delta(
+        &self,
+        delta: Box<SpecsResponseDelta>,
+        decompression_dict: Option<DictionaryDecoder>,
+        source: SpecsSource,
+        source_api: Option<String>,
+    ) -> Result<(), StatsigErr> {
+        let diagnostics = delta.diagnostics.clone();
+        let sdk_configs = delta.sdk_configs.clone();
+
+        let now = Utc::now().timestamp_millis() as u64;
+        let mut data = self.write_data()?;
+
+        let curr_checksum = data.values.checksum.as_deref();
+        let base_checksum = delta.base_checksum.as_deref();
+        if data.values.time != delta.base_time
+            || (base_checksum.is_some() && base_checksum != curr_checksum)
+        {
+            return Err(StatsigErr::SpecsDeltaMismatch(format!(
+                "based on [time: {}, checksum: {}], but currently has values for [time: {}, checksum: {}]",
+                delta.base_time,
+                base_checksum.unwrap_or_default(),
+                data.values.time,
+                curr_checksum.unwrap_or_default(),
+            )));
+        }
+
+        if !delta.matches_specs_checksum(&data.values) {
+            return Err(StatsigErr::SpecsDeltaMismatch(format!(
+                "specs checksum of the result does not match for [time: {}]",
+                delta.time
+            )));
+        }
+
+        let prev_lcut = data.values.time;
+        let mut prev_values = None;
+        match Arc::get_mut(&mut data.values) {
+            Some(values) => delta.apply_to(values),
+            None => {
+                // Held elsewhere while being serialized or published. The delta is applied to a
+                // copy outside of the lock, which is swapped in unless the values changed meanwhile
+                let current = data.values.clone();
+                drop(data);
+
+                let mut next_values = copy_values(&current)?;
+                delta.apply_to(&mut next_values);
+
+                data = self.write_data()?;
+                if !Arc::ptr_eq(&data.values, &current) {
+                    return Err(StatsigErr::SpecsDeltaMismatch(
+                        "current values changed while the delta was applied".to_string(),
+                    ));
+                }
+                prev_values = Some(std::mem::replace(&mut data.values, Arc::new(next_values)));
+            }
+        }
+
+        let prev_source = std::mem::replace(&mut data.source, source.clone());
+        data.time_received_at = Some(now);
+        data.time_checked_at = Some(now);
+        data.decompression_dict = decompression_dict;
+        data.source_api = source_api.clone();
+        data.gcir_cache.clear();
+        let curr_values_time = data.values.time;
+        drop(data);
+
+        // dropped outside of the lock, like full updates
+        drop(prev_values);
+
+        if let Some(diagnostics) = diagnostics {
+            self.global_configs
+                .set_diagnostics_sampling_rates(diagnostics);
+        }
+
+        if let Some(sdk_configs) = sdk_configs {
+            self.global_configs.set_sdk_configs(sdk_configs);
+        }
+
+        self.lcut_tx.send_replace(curr_values_time);
+        self.try_update_data_store_with_current_values(&source, now);
+        self.ops_stats_log_config_propagation_diff(
+            curr_values_time,
+            prev_lcut,
+            &source,
+            &prev_source,
+            source_api,
+        );
+
+        Ok(())
+    }
+
     fn swap_current_with_next(
         &self,
         next_values: SpecsResponseFull,
-        specs_update: &SpecsUpdate,
+        source: &SpecsSource,
         decompression_dict: Option<DictionaryDecoder>,
         now: u64,
         source_api: Option<String>,
-    ) -> Result<(SpecsSource, u64, u64), StatsigErr> {
+        retained: bool,
+    ) -> Result<SwappedValues, StatsigErr> {
         match self.data.try_write_for(Duration::from_secs(5)) {
             Some(mut data) => {
-                let prev_source = std::mem::replace(&mut data.source, specs_update.source.clone());
+                let prev_source = std::mem::replace(&mut data.source, source.clone());
                 let prev_lcut = data.values.time;
 
-                let mut temp = next_values;
-                std::mem::swap(&mut data.values, &mut temp);
-                data.next_values = Some(temp);
+                let prev_values = std::mem::replace(&mut data.values, Arc::new(next_values));
+                let prev_values = match retained {
+                    true => Some(prev_values),
+                    // reused for the next update, unless something is still serializing it
+                    false => match Arc::try_unwrap(prev_values) {
+                        Ok(prev_values) => {
+                            data.next_values = Some(prev_values);
+                            None
+                        }
+                        Err(prev_values) => Some(prev_values),
+                    },
+                };
 
                 data.time_received_at = Some(now);
+                data.time_checked_at = Some(now);
                 data.decompression_dict = decompression_dict;
                 data.source_api = source_api;
-                Ok((prev_source, prev_lcut, data.values.time))
+                data.gcir_cache.clear();
+                Ok((prev_source, prev_lcut, data.values.time, prev_values))
             }
             None => {
                 log_e!(TAG, "Failed to acquire write lock: Failed to lock data");
@@ -224,6 +452,28 @@ impl SpecStore {
         }
     }
 
+    fn write_data(&self) -> Result<RwLockWriteGuard<'_, SpecStoreData>, StatsigErr> {
+        self.data
+            .try_write_for(Duration::from_secs(5))
+            .ok_or_else(|| {
+                log_e!(TAG, "Failed to acquire write lock: Failed to lock data");
+                StatsigErr::LockFailure(
+                    "Failed to acquire write lock: Failed to lock data".to_string(),
+                )
+            })
+    }
+
+    fn mark_checked(&self) {
+        match self.data.try_write_for(Duration::from_secs(5)) {
+            Some(mut data) => {
+                data.time_checked_at = Some(Utc::now().timestamp_millis() as u64);
+            }
+            None => {
+                log_e!(TAG, "Failed to acquire write lock: Failed to lock data");
+            }
+        }
+    }
+
     fn ops_stats_log_no_update(&self, source: SpecsSource, source_api: Option<String>) {
         log_d!(TAG, "No Updates");
         self.ops_stats.log(ObservabilityEvent::new_event(
@@ -282,7 +532,7 @@ impl SpecStore {
         }
     }
 
-    fn try_update_data_store(&self, source: &SpecsSource, data: Vec<u8>, now: u64) {
+    fn try_update_data_store(&self, source: &SpecsSource, payload: Arc<SpecsPayload>, now: u64) {
         if source != &SpecsSource::Network {
             return;
         }
@@ -297,7 +547,7 @@ impl SpecStore {
         let spawn_result = self.statsig_runtime.spawn(
             "spec_store_update_data_store",
             move |_shutdown_notif| async move {
-                let data_string = match String::from_utf8(data) {
+                let data_string = match std::str::from_utf8(payload.as_bytes()) {
                     Ok(s) => s,
                     Err(e) => {
                         log_e!(TAG, "Failed to convert data to string: {}", e);
@@ -308,7 +558,7 @@ impl SpecStore {
                 let _ = data_store
                     .set(
                         &get_data_adapter_dcs_key(&hashed_key),
-                        &data_string,
+                        data_string,
                         Some(now),
                     )
                     .await;
@@ -323,6 +573,17 @@ impl SpecStore {
         }
     }
 
+    /// Deltas cannot be stored on their own, so the whole result is written instead.
+    fn try_update_data_store_with_current_values(&self, source: &SpecsSource, now: u64) {
+        if source != &SpecsSource::Network || self.data_store.is_none() {
+            return;
+        }
+
+        if let Some(bytes) = self.serialize_current_values() {
+            self.try_update_data_store(source, Arc::new(SpecsPayload::from(bytes)), now);
+        }
+    }
+
     fn are_current_values_newer(&self, next_values: &SpecsResponseFull) -> bool {
         let data = match self.data.try_read_for(Duration::from_secs(5)) {
             Some(data) => data,
@@ -362,6 +623,23 @@ impl SpecsUpdateListener for SpecStore {
         self.set_values(update)
     }
 
+    fn did_receive_specs_payload(
+        &self,
+        payload: SpecsPayload,
+        source: SpecsSource,
+        source_api: Option<String>,
+    ) -> Result<(), StatsigErr> {
+        self.set_values_from_payload(Arc::new(payload), source, source_api)
+    }
+
+    fn retains_specs_payloads(&self) -> bool {
+        self.zero_copy_specs
+    }
+
+    fn serialize_current_specs(&self) -> Option<Vec<u8>> {
+        self.serialize_current_values()
+    }
+
     fn get_current_specs_info(&self) -> SpecsInfo {
         match self.data.try_read_for(Duration::from_secs(5)) {
             Some(data) => SpecsInfo {
@@ -433,5 +711,63 @@ impl IdListsUpdateListener for SpecStore {
                 data.id_lists.insert(list_name, list);
             }
         }
+
+        data.id_lists_time_received_at = Some(Utc::now().timestamp_millis() as u64);
+        data.gcir_cache.clear();
+    }
+}
+
+// -------------------------------------------------------------------------------------------- [Helpers]
+
+// SpecsResponseFull is deliberately not Clone, so copies go through its JSON form
+fn copy_values(values: &SpecsResponseFull) -> Result<SpecsResponseFull, StatsigErr> {
+    let json =
+        serde_json::to_vec(values).map_err(|e| StatsigErr::SerializationError(e.to_string()))?;
+
+    with_interned_strings(|| serde_json::from_slice::<SpecsResponseFull>(&json))
+        .map_err(|e| StatsigErr::JsonParseError("SpecsResponseFull".to_string(), e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_update(data: String) -> SpecsUpdate {
+        SpecsUpdate {
+            data: data.into_bytes(),
+            source: SpecsSource::Network,
+            received_at: 0,
+            source_api: None,
+        }
+    }
+
+    #[test]
+    fn test_delta_is_applied_while_values_are_held() {
+        let sdk_key = "secret-key-delta-held";
+        let spec_store = SpecStore::new(
+            sdk_key,
+            sdk_key.to_string(),
+            StatsigRuntime::get_runtime(),
+            None,
+        );
+        let dcs = include_str!("../tests/data/eval_proj_dcs.json");
+        spec_store.set_values(make_update(dcs.to_string())).unwrap();
+
+        let held = spec_store.data.read().values.clone();
+        let delta = serde_json::json!({
+            "is_delta": true,
+            "has_updates": true,
+            "base_time": held.time,
+            "time": held.time + 1,
+            "deleted_feature_gates": ["test_public"],
+        });
+        spec_store
+            .set_values(make_update(delta.to_string()))
+            .unwrap();
+
+        let data = spec_store.data.read();
+        assert_eq!(data.values.time, held.time + 1);
+        assert!(data.values.feature_gates.get("test_public").is_none());
+        assert!(held.feature_gates.get("test_public").is_some());
     }
 }
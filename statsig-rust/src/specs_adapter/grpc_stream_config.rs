@@ -0,0 +1,50 @@
+use serde::Serialize;
+
+/// Reconnect and connection settings for `StatsigGrpcSpecsAdapter`.
+#[derive(Debug, Clone, Serialize)]
+pub struct GrpcStreamConfig {
+    /// Wait before the first reconnect attempt. Doubles (see `backoff_multiplier`) on every
+    /// consecutive failure, up to `max_backoff_ms`.
+    pub initial_backoff_ms: u64,
+    pub backoff_multiplier: u64,
+    pub max_backoff_ms: u64,
+    /// Randomizes every wait by up to +/- this ratio (0.0 - 1.0), so a fleet of SDKs does not
+    /// reconnect to the proxy in lockstep.
+    pub backoff_jitter_ratio: f64,
+    /// Stops reconnecting after this many consecutive failures.
+    pub max_reconnect_attempts: u64,
+
+    /// Starts polling `specs_url` over HTTP after this many consecutive failures. Polling stops
+    /// again as soon as the stream delivers an update.
+    pub fallback_to_polling_after_attempts: u64,
+    pub fallback_polling_interval_ms: u64,
+
+    pub connect_timeout_ms: u64,
+    /// TCP-level keepalive on the channel's socket. `None` disables it.
+    pub tcp_keepalive_ms: Option<u64>,
+    pub keep_alive_interval_ms: u64,
+    pub keep_alive_timeout_ms: u64,
+    pub keep_alive_while_idle: bool,
+    /// Treats the stream as dead and reconnects if no update arrives within this window.
+    pub stream_idle_timeout_ms: Option<u64>,
+}
+
+impl Default for GrpcStreamConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff_ms: 3000,
+            backoff_multiplier: 2,
+            max_backoff_ms: 60 * 1000,
+            backoff_jitter_ratio: 0.2,
+            max_reconnect_attempts: 10 * 24 * 60 * 60,
+            fallback_to_polling_after_attempts: 30,
+            fallback_polling_interval_ms: 3000,
+            connect_timeout_ms: 5000,
+            tcp_keepalive_ms: Some(30 * 1000),
+            keep_alive_interval_ms: 30 * 1000,
+            keep_alive_timeout_ms: 20 * 1000,
+            keep_alive_while_idle: true,
+            stream_idle_timeout_ms: None,
+        }
+    }
+}
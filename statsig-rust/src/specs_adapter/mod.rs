@@ -2,13 +2,16 @@ use serde::Serialize;
 pub use specs_adapter_trait::*;
 
 use crate::log_w;
+pub use grpc_stream_config::GrpcStreamConfig;
 pub use statsig_bootstrap_specs_adapter::StatsigBootstrapSpecsAdapter;
 pub use statsig_customized_specs_adapter::StatsigCustomizedSpecsAdapter;
 #[cfg(feature = "with_grpc")]
 pub use statsig_grpc_specs_adapter::StatsigGrpcSpecsAdapter;
 pub use statsig_http_specs_adapter::StatsigHttpSpecsAdapter;
 pub use statsig_local_file_specs_adapter::StatsigLocalFileSpecsAdapter;
+pub use statsig_shared_specs_adapter::StatsigSharedSpecsAdapter;
 
+mod grpc_stream_config;
 mod statsig_bootstrap_specs_adapter;
 mod statsig_customized_specs_adapter;
 mod statsig_data_store_specs_adapter;
@@ -16,6 +19,7 @@ mod statsig_data_store_specs_adapter;
 mod statsig_grpc_specs_adapter;
 mod statsig_http_specs_adapter;
 mod statsig_local_file_specs_adapter;
+mod statsig_shared_specs_adapter;
 
 mod specs_adapter_trait;
 
@@ -35,6 +39,9 @@ pub struct SpecAdapterConfig {
     pub client_cert_path: Option<String>,
     pub client_key_path: Option<String>,
     pub domain_name: Option<String>,
+
+    /// Only used by the `network_grpc_websocket` adapter. Defaults to `GrpcStreamConfig::default()`.
+    pub grpc_stream_config: Option<GrpcStreamConfig>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1,6 +1,8 @@
+use crate::specs_response::specs_payload::SpecsPayload;
 use crate::statsig_err::StatsigErr;
 use crate::StatsigRuntime;
 use async_trait::async_trait;
+use chrono::Utc;
 use serde::Deserialize;
 use serde::Serialize;
 use std::fmt::{self, Debug};
@@ -144,6 +146,33 @@ impl SpecsInfo {
 pub trait SpecsUpdateListener: Send + Sync {
     fn did_receive_specs_update(&self, update: SpecsUpdate) -> Result<(), StatsigErr>;
 
+    /// Same as `did_receive_specs_update`, for payloads that can be read in place, such as
+    /// memory-mapped files. Listeners that cannot retain the payload receive a copy of its bytes.
+    fn did_receive_specs_payload(
+        &self,
+        payload: SpecsPayload,
+        source: SpecsSource,
+        source_api: Option<String>,
+    ) -> Result<(), StatsigErr> {
+        self.did_receive_specs_update(SpecsUpdate {
+            data: payload.as_bytes().to_vec(),
+            source,
+            received_at: Utc::now().timestamp_millis() as u64,
+            source_api,
+        })
+    }
+
+    /// Whether payloads passed to `did_receive_specs_payload` are kept after parsing, so that
+    /// memory-mapping them saves a copy. See `StatsigOptions::enable_zero_copy_specs`.
+    fn retains_specs_payloads(&self) -> bool {
+        false
+    }
+
+    /// The current specs as a complete specs response, if the listener can provide them.
+    fn serialize_current_specs(&self) -> Option<Vec<u8>> {
+        None
+    }
+
     fn get_current_specs_info(&self) -> SpecsInfo;
 }
 
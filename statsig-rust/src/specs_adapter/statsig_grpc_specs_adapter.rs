@@ -1,4 +1,4 @@
-use super::{SpecsInfo, StatsigHttpSpecsAdapter};
+use super::{GrpcStreamConfig, SpecsInfo, StatsigHttpSpecsAdapter};
 use crate::observability::observability_client_adapter::{MetricType, ObservabilityEvent};
 use crate::observability::ops_stats::{OpsStatsForInstance, OPS_STATS};
 use crate::observability::ErrorBoundaryEvent;
@@ -11,20 +11,15 @@ use crate::{
 use async_trait::async_trait;
 use chrono::Utc;
 use parking_lot::{Mutex, RwLock};
-use sigstat_grpc::statsig_grpc_client::StatsigGrpcClient;
+use rand::Rng;
+use sigstat_grpc::statsig_grpc_client::{StatsigGrpcChannelConfig, StatsigGrpcClient};
 use std::cmp;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, Notify};
 use tokio::time::{sleep, timeout};
 
-// Todo make those configurable
-const DEFAULT_BACKOFF_INTERVAL_MS: u64 = 3000;
-const DEFAULT_BACKOFF_MULTIPLIER: u64 = 2;
-const MAX_BACKOFF_INTERVAL_MS: u64 = 60 * 1000;
-const RETRY_LIMIT: u64 = 10 * 24 * 60 * 60;
-const FALL_BACK_TO_POLLING_THREASHOLD: u64 = 30; //Fallback after 30 minutes
 struct StreamingRetryState {
     backoff_interval_ms: AtomicU64,
     retry_attempts: AtomicU64,
@@ -40,11 +35,14 @@ pub struct StatsigGrpcSpecsAdapter {
     initialization_tx: Arc<broadcast::Sender<Result<(), StatsigErr>>>,
     task_handle_id: Mutex<Option<tokio::task::Id>>,
     grpc_client: StatsigGrpcClient,
+    stream_config: GrpcStreamConfig,
     retry_state: StreamingRetryState,
     init_timeout: Duration,
     ops_stats: Arc<OpsStatsForInstance>,
     // For fallback to poll job behavior
     http_specs_adapter: Arc<StatsigHttpSpecsAdapter>,
+    is_polling: AtomicBool,
+    polling_generation: Arc<AtomicU64>,
     cancel_poll_notify: Arc<Notify>,
 }
 
@@ -133,6 +131,7 @@ impl SpecsAdapter for StatsigGrpcSpecsAdapter {
         statsig_runtime: &Arc<StatsigRuntime>,
     ) -> Result<(), StatsigErr> {
         self.shutdown_notify.notify_one();
+        self.stop_polling_fallback();
 
         let opt_handle_id = match self.task_handle_id.try_lock_for(Duration::from_secs(5)) {
             Some(mut lock) => lock.take(),
@@ -181,6 +180,14 @@ impl StatsigGrpcSpecsAdapter {
     ) -> Self {
         let fallback_adapter = StatsigHttpSpecsAdapter::new(sdk_key, options, None);
         let (init_tx, _) = broadcast::channel(1);
+        let stream_config = config.grpc_stream_config.clone().unwrap_or_default();
+        let channel_config = StatsigGrpcChannelConfig {
+            connect_timeout: Duration::from_millis(stream_config.connect_timeout_ms),
+            tcp_keepalive: stream_config.tcp_keepalive_ms.map(Duration::from_millis),
+            http2_keep_alive_interval: Duration::from_millis(stream_config.keep_alive_interval_ms),
+            keep_alive_timeout: Duration::from_millis(stream_config.keep_alive_timeout_ms),
+            keep_alive_while_idle: stream_config.keep_alive_while_idle,
+        };
         Self {
             listener: RwLock::new(None),
             shutdown_notify: Arc::new(Notify::new()),
@@ -193,30 +200,78 @@ impl StatsigGrpcSpecsAdapter {
                 config.client_cert_path.clone(),
                 config.client_key_path.clone(),
                 config.domain_name.clone(),
-            ),
+            )
+            .with_channel_config(channel_config),
             initialization_tx: Arc::new(init_tx),
             retry_state: StreamingRetryState {
-                backoff_interval_ms: DEFAULT_BACKOFF_INTERVAL_MS.into(),
+                backoff_interval_ms: stream_config.initial_backoff_ms.into(),
                 retry_attempts: 0.into(),
                 is_retrying: false.into(),
             },
             init_timeout: Duration::from_millis(config.init_timeout_ms),
             ops_stats: OPS_STATS.get_for_instance(sdk_key),
             http_specs_adapter: Arc::new(fallback_adapter),
+            is_polling: AtomicBool::new(false),
+            polling_generation: Arc::new(AtomicU64::new(0)),
             cancel_poll_notify: Arc::new(Notify::new()),
+            stream_config,
+        }
+    }
+
+    fn start_polling_fallback(&self) {
+        if self.is_polling.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        log_d!(
+            TAG,
+            "SFP is not reachable after {} tries: Falling back to polling from statsig",
+            self.stream_config.fallback_to_polling_after_attempts
+        );
+        self.ops_stats.log(ObservabilityEvent::new_event(
+            MetricType::Increment,
+            "grpc_fallback_to_polling_started".to_string(),
+            1.0,
+            None,
+        ));
+
+        let generation = self.polling_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        Self::spawn_poll_from_statsig_thread(
+            self.http_specs_adapter.clone(),
+            self.polling_generation.clone(),
+            generation,
+            self.cancel_poll_notify.clone(),
+            Duration::from_millis(self.stream_config.fallback_polling_interval_ms),
+        );
+    }
+
+    fn stop_polling_fallback(&self) -> bool {
+        if !self.is_polling.swap(false, Ordering::SeqCst) {
+            return false;
         }
+
+        // Bumping the generation also stops a poll thread that is mid-sync and misses the notify
+        self.polling_generation.fetch_add(1, Ordering::SeqCst);
+        self.cancel_poll_notify.notify_waiters();
+        true
     }
 
     fn spawn_poll_from_statsig_thread(
         http_spec_adapter: Arc<StatsigHttpSpecsAdapter>,
+        polling_generation: Arc<AtomicU64>,
+        generation: u64,
         cancel_notify: Arc<Notify>,
-        shutdown_notify: Arc<Notify>,
+        interval: Duration,
     ) {
         let weak_http_adapter = Arc::downgrade(&http_spec_adapter);
         tokio::task::spawn(async move {
             loop {
                 tokio::select! {
-                    _ = sleep(Duration::from_millis(3000)) => {
+                    _ = sleep(interval) => {
+                        if polling_generation.load(Ordering::SeqCst) != generation {
+                            break;
+                        }
+
                         if let Some(strong_http_adapter) = weak_http_adapter.upgrade() {
                             StatsigHttpSpecsAdapter::run_background_sync(strong_http_adapter).await;
                         } else {
@@ -228,10 +283,6 @@ impl StatsigGrpcSpecsAdapter {
                         log_d!(TAG, "Cancel grpc fallback background specs sync");
                         break;
                     }
-                    _ = shutdown_notify.notified() => {
-                        log_d!(TAG, "Shutting down grpc fallback specs background sync");
-                        break;
-                    }
                 }
             }
         });
@@ -271,27 +322,28 @@ impl StatsigGrpcSpecsAdapter {
                 result = self.handle_grpc_request_stream() => {
                     if let Err(err) = result {
                         let attempt = self.retry_state.retry_attempts.fetch_add(1, Ordering::SeqCst);
-                        if attempt > RETRY_LIMIT {
+                        if attempt > self.stream_config.max_reconnect_attempts {
                             log_error_to_statsig_and_console!(&self.ops_stats, TAG, StatsigErr::GrpcError(format!("gRPC stream failure, exhaust retry limit: {err:?}")));
                            break;
                         }
-                        if attempt == FALL_BACK_TO_POLLING_THREASHOLD {
-                            log_d!(TAG, "SFP is not reachable after {} tries: Falling back to polling from statsig", FALL_BACK_TO_POLLING_THREASHOLD);
-                            Self::spawn_poll_from_statsig_thread(self.http_specs_adapter.clone(), self.cancel_poll_notify.clone(), self.shutdown_notify.clone());
+                        if attempt + 1 >= self.stream_config.fallback_to_polling_after_attempts {
+                            self.start_polling_fallback();
                         }
                         self.grpc_client.reset_client();
 
                         // Update retry state
                         let curr_backoff = self.retry_state.backoff_interval_ms.load(Ordering::SeqCst);
-                        let new_backoff = if curr_backoff < MAX_BACKOFF_INTERVAL_MS {
-                            cmp::min(curr_backoff * DEFAULT_BACKOFF_MULTIPLIER, MAX_BACKOFF_INTERVAL_MS)
-                        } else  {
-                            MAX_BACKOFF_INTERVAL_MS
-                        };
+                        let max_backoff = self.stream_config.max_backoff_ms;
+                        let new_backoff = cmp::min(
+                            curr_backoff.saturating_mul(self.stream_config.backoff_multiplier),
+                            max_backoff,
+                        );
                         self.retry_state.backoff_interval_ms.store(new_backoff,Ordering::SeqCst);
                         self.retry_state.is_retrying.store(true, Ordering::SeqCst);
-                        self.log_streaming_err(err, attempt, curr_backoff);
-                        tokio::time::sleep(Duration::from_millis(curr_backoff)).await;
+
+                        let wait_ms = self.with_jitter(curr_backoff);
+                        self.log_streaming_err(err, attempt, wait_ms);
+                        tokio::time::sleep(Duration::from_millis(wait_ms)).await;
                     }
                 },
                 _ = self.shutdown_notify.notified() => {
@@ -315,17 +367,47 @@ impl StatsigGrpcSpecsAdapter {
             .get_specs_stream(specs_info.as_ref().and_then(|s| s.lcut), zstd_id)
             .await
             .map_err(|e| StatsigErr::GrpcError(format!("{e}")))?;
-        loop {
-            match stream.message().await {
+
+        let connected_at = Instant::now();
+        self.log_stream_connected(true);
+
+        let idle_timeout = self
+            .stream_config
+            .stream_idle_timeout_ms
+            .map(Duration::from_millis);
+
+        let result = loop {
+            let message = match idle_timeout {
+                Some(idle_timeout) => match timeout(idle_timeout, stream.message()).await {
+                    Ok(message) => message,
+                    Err(_) => {
+                        break Err(StatsigErr::GrpcError(format!(
+                            "No update received for {} ms",
+                            idle_timeout.as_millis()
+                        )));
+                    }
+                },
+                None => stream.message().await,
+            };
+
+            match message {
                 Ok(Some(config_spec)) => {
-                    self.cancel_poll_notify.notify_one();
+                    if self.stop_polling_fallback() {
+                        log_d!(TAG, "gRPC stream recovered, stopping fallback polling");
+                        self.ops_stats.log(ObservabilityEvent::new_event(
+                            MetricType::Increment,
+                            "grpc_fallback_to_polling_stopped".to_string(),
+                            1.0,
+                            None,
+                        ));
+                    }
                     if self.retry_state.is_retrying.load(Ordering::SeqCst) {
                         // Reset retry state
                         self.retry_state.is_retrying.store(false, Ordering::SeqCst);
                         self.retry_state.retry_attempts.store(0, Ordering::SeqCst);
                         self.retry_state
                             .backoff_interval_ms
-                            .store(DEFAULT_BACKOFF_INTERVAL_MS, Ordering::SeqCst);
+                            .store(self.stream_config.initial_backoff_ms, Ordering::SeqCst);
                         self.ops_stats.log(ObservabilityEvent::new_event(
                             MetricType::Increment,
                             "grpc_reconnected".to_string(),
@@ -333,6 +415,7 @@ impl StatsigGrpcSpecsAdapter {
                             None,
                         ));
                     }
+                    self.log_update_latency(config_spec.last_updated);
                     let _ = self
                         .initialization_tx
                         .send(self.send_spec_update_to_listener(config_spec.spec));
@@ -344,12 +427,58 @@ impl StatsigGrpcSpecsAdapter {
                     ));
                 }
                 err => {
-                    return Err(StatsigErr::GrpcError(format!(
+                    break Err(StatsigErr::GrpcError(format!(
                         "Error while receiving stream: {err:?}"
                     )));
                 }
             }
+        };
+
+        self.log_stream_connected(false);
+        self.ops_stats.log(ObservabilityEvent::new_event(
+            MetricType::Dist,
+            "grpc_stream_uptime_ms".to_string(),
+            connected_at.elapsed().as_millis() as f64,
+            None,
+        ));
+
+        result
+    }
+
+    fn with_jitter(&self, backoff_ms: u64) -> u64 {
+        let ratio = self.stream_config.backoff_jitter_ratio.clamp(0.0, 1.0);
+        let spread = (backoff_ms as f64 * ratio) as u64;
+        if spread == 0 {
+            return backoff_ms;
         }
+
+        let jittered = rand::thread_rng()
+            .gen_range(backoff_ms.saturating_sub(spread)..=backoff_ms.saturating_add(spread));
+        cmp::min(jittered, self.stream_config.max_backoff_ms)
+    }
+
+    fn log_stream_connected(&self, connected: bool) {
+        self.ops_stats.log(ObservabilityEvent::new_event(
+            MetricType::Gauge,
+            "grpc_stream_connected".to_string(),
+            if connected { 1.0 } else { 0.0 },
+            None,
+        ));
+    }
+
+    // Time between the config change (lcut) and the update reaching this SDK
+    fn log_update_latency(&self, last_updated: u64) {
+        let now = Utc::now().timestamp_millis() as u64;
+        if last_updated == 0 || now < last_updated {
+            return;
+        }
+
+        self.ops_stats.log(ObservabilityEvent::new_event(
+            MetricType::Dist,
+            "grpc_update_latency_ms".to_string(),
+            (now - last_updated) as f64,
+            None,
+        ));
     }
 
     fn set_task_handle_id(&self, handle_id: tokio::task::Id) -> Result<(), StatsigErr> {
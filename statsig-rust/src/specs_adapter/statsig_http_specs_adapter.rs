@@ -32,6 +32,10 @@ pub const DEFAULT_SYNC_INTERVAL_MS: u32 = 10_000;
 #[allow(unused)]
 pub const INIT_DICT_ID: &str = "null";
 
+// hosts that serve the standard specs format, which has no deltas
+const STATSIG_API_DOMAINS: [&str; 3] = ["statsigcdn.com", "statsigapi.net", "statsig.com"];
+const ACCEPT_DELTAS_PARAM: &str = "accept_deltas";
+
 const TAG: &str = stringify!(StatsigHttpSpecsAdapter);
 pub struct StatsigHttpSpecsAdapter {
     listener: RwLock<Option<Arc<dyn SpecsUpdateListener>>>,
@@ -40,6 +44,7 @@ pub struct StatsigHttpSpecsAdapter {
     specs_url: String,
     fallback_url: Option<String>,
     sync_interval_duration: Duration,
+    enable_delta_specs_updates: bool,
     ops_stats: Arc<OpsStatsForInstance>,
     shutdown_notify: Arc<Notify>,
 }
@@ -72,6 +77,9 @@ impl StatsigHttpSpecsAdapter {
             None
         };
 
+        let enable_delta_specs_updates = options_ref.enable_delta_specs_updates.unwrap_or(false)
+            && !is_statsig_api_url(&specs_url);
+
         let headers = StatsigMetadata::get_constant_request_headers(sdk_key);
 
         Self {
@@ -85,11 +93,20 @@ impl StatsigHttpSpecsAdapter {
                     .specs_sync_interval_ms
                     .unwrap_or(DEFAULT_SYNC_INTERVAL_MS),
             )),
+            enable_delta_specs_updates,
             ops_stats: OPS_STATS.get_for_instance(sdk_key),
             shutdown_notify: Arc::new(Notify::new()),
         }
     }
 
+    /// Overrides `StatsigOptions::enable_delta_specs_updates`, for callers that need every
+    /// response to be a complete set of specs. Deltas are never requested from Statsig's endpoints.
+    #[must_use]
+    pub fn with_delta_specs_updates(mut self, enabled: bool) -> Self {
+        self.enable_delta_specs_updates = enabled && !is_statsig_api_url(&self.specs_url);
+        self
+    }
+
     pub fn force_shutdown(&self) {
         self.shutdown_notify.notify_one();
     }
@@ -120,6 +137,10 @@ impl StatsigHttpSpecsAdapter {
                 percent_encode(cs.as_bytes(), percent_encoding::NON_ALPHANUMERIC).to_string(),
             );
         }
+        // only set for a forward proxy, see SpecsResponseDelta
+        if self.enable_delta_specs_updates && current_specs_info.lcut.is_some_and(|t| t > 0) {
+            params.insert(ACCEPT_DELTAS_PARAM.to_string(), "true".to_string());
+        }
 
         RequestArgs {
             url: construct_specs_url(
@@ -155,6 +176,9 @@ impl StatsigHttpSpecsAdapter {
         };
 
         request_args.url = fallback_url.clone();
+        if let Some(params) = request_args.query_params.as_mut() {
+            params.remove(ACCEPT_DELTAS_PARAM);
+        }
 
         // TODO logging
 
@@ -208,7 +232,10 @@ impl StatsigHttpSpecsAdapter {
         );
     }
 
-    async fn manually_sync_specs(&self, current_specs_info: SpecsInfo) -> Result<(), StatsigErr> {
+    async fn manually_sync_specs(
+        &self,
+        mut current_specs_info: SpecsInfo,
+    ) -> Result<(), StatsigErr> {
         if let Some(lock) = self
             .listener
             .try_read_for(std::time::Duration::from_secs(5))
@@ -221,7 +248,17 @@ impl StatsigHttpSpecsAdapter {
         let response = self
             .fetch_specs_from_network(current_specs_info.clone())
             .await;
-        let result = self.process_spec_data(response).await;
+        let mut result = self.process_spec_data(response).await;
+
+        if let Err(StatsigErr::SpecsDeltaMismatch(reason)) = &result {
+            log_d!(TAG, "{}. Fetching full specs", reason);
+            current_specs_info.lcut = None;
+            current_specs_info.checksum = None;
+            let response = self
+                .fetch_specs_from_network(current_specs_info.clone())
+                .await;
+            result = self.process_spec_data(response).await;
+        }
 
         if result.is_err() && self.fallback_url.is_some() {
             log_d!(TAG, "Falling back to statsig api");
@@ -378,3 +415,56 @@ fn construct_specs_url(spec_url: &str, sdk_key: &str, dict_id: Option<&str>) ->
     #[cfg(not(feature = "with_shared_dict_compression"))]
     format!("{spec_url}/{sdk_key}.json")
 }
+
+fn is_statsig_api_url(url: &str) -> bool {
+    let api = get_api_from_url(url);
+    let host = api.split_once("://").map_or(api.as_str(), |(_, host)| host);
+    let host = host.split(':').next().unwrap_or_default();
+
+    STATSIG_API_DOMAINS
+        .iter()
+        .any(|domain| host == *domain || host.ends_with(&format!(".{domain}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_query_params(specs_url: &str) -> HashMap<String, String> {
+        let options = StatsigOptions {
+            specs_url: Some(specs_url.to_string()),
+            enable_delta_specs_updates: Some(true),
+            ..StatsigOptions::new()
+        };
+        let adapter = StatsigHttpSpecsAdapter::new("secret-key", Some(&options), None);
+        let specs_info = SpecsInfo {
+            lcut: Some(1),
+            ..SpecsInfo::empty()
+        };
+
+        adapter
+            .get_request_args(&specs_info)
+            .query_params
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn test_deltas_are_only_requested_from_forward_proxies() {
+        let proxy = get_query_params("http://localhost:8000/v2/download_config_specs");
+        assert_eq!(
+            proxy.get(ACCEPT_DELTAS_PARAM).map(String::as_str),
+            Some("true")
+        );
+
+        for url in [
+            DEFAULT_SPECS_URL,
+            "https://statsigapi.net/v2/download_config_specs",
+            "https://api.statsig.com:443/v2/download_config_specs",
+        ] {
+            assert!(
+                !get_query_params(url).contains_key(ACCEPT_DELTAS_PARAM),
+                "{url}"
+            );
+        }
+    }
+}
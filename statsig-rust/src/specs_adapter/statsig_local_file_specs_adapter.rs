@@ -1,11 +1,11 @@
 use crate::hashing::djb2;
-use crate::specs_adapter::{SpecsAdapter, SpecsSource, SpecsUpdate, SpecsUpdateListener};
+use crate::specs_adapter::{SpecsAdapter, SpecsSource, SpecsUpdateListener};
 use crate::specs_response::spec_types::SpecsResponseFull;
 use crate::specs_response::spec_types_encoded::DecodedSpecsResponse;
+use crate::specs_response::specs_payload::SpecsPayload;
 use crate::statsig_err::StatsigErr;
 use crate::{log_e, log_w, StatsigOptions, StatsigRuntime};
 use async_trait::async_trait;
-use chrono::Utc;
 use parking_lot::RwLock;
 use std::sync::Arc;
 use std::time::Duration;
@@ -80,24 +80,16 @@ impl StatsigLocalFileSpecsAdapter {
     }
 
     pub fn resync_from_file(&self) -> Result<(), StatsigErr> {
-        let data = match std::fs::read_to_string(&self.file_path) {
-            Ok(data) => data,
-            Err(e) => {
-                return Err(StatsigErr::FileError(e.to_string()));
-            }
-        };
-
         match &self
             .listener
             .try_read_for(std::time::Duration::from_secs(5))
         {
             Some(lock) => match lock.as_ref() {
-                Some(listener) => listener.did_receive_specs_update(SpecsUpdate {
-                    data: data.into_bytes(),
-                    source: SpecsSource::Adapter("FileBased".to_owned()),
-                    received_at: Utc::now().timestamp_millis() as u64,
-                    source_api: None,
-                }),
+                Some(listener) => listener.did_receive_specs_payload(
+                    SpecsPayload::from_file(&self.file_path, listener.retains_specs_payloads())?,
+                    SpecsSource::Adapter("FileBased".to_owned()),
+                    None,
+                ),
                 None => Err(StatsigErr::UnstartedAdapter("Listener not set".to_string())),
             },
             None => Err(StatsigErr::LockFailure(
@@ -132,10 +124,15 @@ impl StatsigLocalFileSpecsAdapter {
         }
     }
 
+    // Written to a temporary file and renamed over the old one, as the old one may be mapped
     fn write_specs_to_file(&self, data: &str) {
-        match std::fs::write(&self.file_path, data) {
-            Ok(()) => (),
-            Err(e) => log_w!(TAG, "Failed to write specs to file: {}", e),
+        let temp_path = format!("{}.{}.tmp", self.file_path, std::process::id());
+        let result = std::fs::write(&temp_path, data)
+            .and_then(|()| std::fs::rename(&temp_path, &self.file_path));
+
+        if let Err(e) = result {
+            log_w!(TAG, "Failed to write specs to file: {}", e);
+            let _ = std::fs::remove_file(&temp_path);
         }
     }
 }
@@ -0,0 +1,585 @@
+use super::{
+    SpecsAdapter, SpecsInfo, SpecsSource, SpecsUpdate, SpecsUpdateListener,
+    StatsigHttpSpecsAdapter, DEFAULT_INIT_TIMEOUT_MS,
+};
+use crate::specs_response::specs_payload::SpecsPayload;
+use crate::{log_d, log_e, log_w, StatsigErr, StatsigOptions, StatsigRuntime};
+use async_trait::async_trait;
+use file_guard::{FileGuard, Lock};
+use memmap2::{Mmap, MmapMut};
+use parking_lot::{Mutex, RwLock};
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::Read;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+use tokio::time::sleep;
+
+const TAG: &str = stringify!(StatsigSharedSpecsAdapter);
+
+const MAGIC: u64 = 0x5354_4753_5045_4331; // "STGSPEC1"
+const HEADER_SIZE: usize = 64;
+const GENERATION_OFFSET: usize = 8;
+
+const DEFAULT_POLL_INTERVAL_MS: u64 = 1000;
+
+lazy_static::lazy_static! {
+    // fcntl locks are held per process, so leadership within this process is tracked here
+    static ref LEADER_PATHS: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+/// A [`SpecsAdapter`] for pre-fork worker models, where every process on a host shares one
+/// specs sync.
+///
+/// The first process to lock `{path}.lock` becomes the leader: it syncs through its source
+/// adapter (HTTP by default). Whenever that updates its own specs, it publishes a snapshot of the
+/// parsed values to `{path}.{generation}`, then bumps the generation counter in the header at
+/// `{path}`. Other processes are followers. They attach to the header read-only, never fetch, and
+/// map the latest snapshot whenever the generation changes. If the leader exits, the next follower
+/// to poll takes over.
+///
+/// Snapshots are complete, uncompressed specs responses, whatever the leader received (deltas,
+/// dictionary compressed payloads). Followers always load them with zero-copy spec loading (see
+/// `StatsigOptions::enable_zero_copy_specs`): return values are read in place from the mapped
+/// snapshot, so the pages holding them are shared by every process on the host rather than copied
+/// into each one. Specs, rules and conditions are still parsed into each process's own memory, so
+/// only the return values stop costing memory per worker. All processes sharing a path must run
+/// the same SDK version.
+///
+/// Set `StatsigOptions::shared_specs_path` to use this adapter with the default HTTP source, which
+/// also turns on `enable_zero_copy_specs` for the leader.
+pub struct StatsigSharedSpecsAdapter {
+    path: String,
+    source_adapter: Arc<dyn SpecsAdapter>,
+    poll_interval: Duration,
+    init_timeout: Duration,
+    listener: RwLock<Option<Arc<dyn SpecsUpdateListener>>>,
+    leader: Mutex<Option<LeaderState>>,
+    header: Mutex<Option<FollowerHeader>>,
+    loaded_generation: AtomicU64,
+    shutdown_notify: Arc<Notify>,
+}
+
+struct LeaderState {
+    _lock: FileGuard<Arc<File>>,
+    header: MmapMut,
+}
+
+struct FollowerHeader {
+    mmap: Mmap,
+    file_id: HeaderFileId,
+}
+
+/// Tells apart the files a header path has pointed to, as a leader replaces a header it finds
+/// missing or unfinished (see `create_header`).
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct HeaderFileId {
+    len: u64,
+    #[cfg(unix)]
+    inode: (u64, u64),
+    #[cfg(not(unix))]
+    modified: Option<std::time::SystemTime>,
+}
+
+impl HeaderFileId {
+    fn from_metadata(metadata: &std::fs::Metadata) -> Self {
+        #[cfg(unix)]
+        use std::os::unix::fs::MetadataExt;
+
+        Self {
+            len: metadata.len(),
+            #[cfg(unix)]
+            inode: (metadata.dev(), metadata.ino()),
+            #[cfg(not(unix))]
+            modified: metadata.modified().ok(),
+        }
+    }
+}
+
+impl StatsigSharedSpecsAdapter {
+    #[must_use]
+    pub fn new(sdk_key: &str, path: &str, options: Option<&StatsigOptions>) -> Self {
+        let init_timeout_ms = options
+            .and_then(|o| o.init_timeout_ms)
+            .unwrap_or(DEFAULT_INIT_TIMEOUT_MS);
+
+        Self::with_source_adapter(
+            path,
+            Arc::new(StatsigHttpSpecsAdapter::new(sdk_key, options, None)),
+        )
+        .with_init_timeout_ms(init_timeout_ms)
+    }
+
+    /// `source_adapter` is only started in the leader process.
+    #[must_use]
+    pub fn with_source_adapter(path: &str, source_adapter: Arc<dyn SpecsAdapter>) -> Self {
+        Self {
+            path: path.to_string(),
+            source_adapter,
+            poll_interval: Duration::from_millis(DEFAULT_POLL_INTERVAL_MS),
+            init_timeout: Duration::from_millis(DEFAULT_INIT_TIMEOUT_MS),
+            listener: RwLock::new(None),
+            leader: Mutex::new(None),
+            header: Mutex::new(None),
+            loaded_generation: AtomicU64::new(0),
+            shutdown_notify: Arc::new(Notify::new()),
+        }
+    }
+
+    /// How often followers check the generation counter and whether the leader is still alive.
+    #[must_use]
+    pub fn with_poll_interval_ms(mut self, poll_interval_ms: u64) -> Self {
+        self.poll_interval = Duration::from_millis(poll_interval_ms);
+        self
+    }
+
+    /// How long a follower started before any snapshot was published waits for one.
+    #[must_use]
+    pub fn with_init_timeout_ms(mut self, init_timeout_ms: u64) -> Self {
+        self.init_timeout = Duration::from_millis(init_timeout_ms);
+        self
+    }
+
+    pub fn is_leader(&self) -> bool {
+        self.leader.lock().is_some()
+    }
+
+    fn get_listener(&self) -> Result<Arc<dyn SpecsUpdateListener>, StatsigErr> {
+        match self.listener.try_read_for(Duration::from_secs(5)) {
+            Some(lock) => lock
+                .clone()
+                .ok_or_else(|| StatsigErr::UnstartedAdapter("Listener not set".to_string())),
+            None => Err(StatsigErr::LockFailure(
+                "Failed to acquire read lock on listener".to_string(),
+            )),
+        }
+    }
+
+    // ------------------------------------------------------------------------------- [Leader]
+
+    fn try_become_leader(&self) -> bool {
+        let mut leader = self.leader.lock();
+        if leader.is_some() {
+            return true;
+        }
+
+        let mut leader_paths = LEADER_PATHS.lock();
+        if leader_paths.contains(&self.path) {
+            return false;
+        }
+
+        match self.acquire_leader_state() {
+            Ok(Some(state)) => {
+                leader_paths.insert(self.path.clone());
+                *leader = Some(state);
+                log_d!(TAG, "Became the leader for {}", self.path);
+                true
+            }
+            Ok(None) => false,
+            Err(e) => {
+                log_w!(TAG, "Failed to become the leader for {}: {}", self.path, e);
+                false
+            }
+        }
+    }
+
+    fn acquire_leader_state(&self) -> Result<Option<LeaderState>, StatsigErr> {
+        let lock_file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(format!("{}.lock", self.path))
+            .map_err(|e| StatsigErr::FileError(e.to_string()))?;
+
+        let lock = match file_guard::try_lock(Arc::new(lock_file), Lock::Exclusive, 0, 1) {
+            Ok(lock) => lock,
+            Err(_) => return Ok(None), // held by another process
+        };
+
+        Ok(Some(LeaderState {
+            _lock: lock,
+            header: open_header_mut(&self.path)?,
+        }))
+    }
+
+    fn release_leadership(&self) {
+        if self.leader.lock().take().is_some() {
+            LEADER_PATHS.lock().remove(&self.path);
+        }
+    }
+
+    async fn start_as_leader(
+        self: &Arc<Self>,
+        statsig_runtime: &Arc<StatsigRuntime>,
+    ) -> Result<(), StatsigErr> {
+        self.source_adapter.initialize(Arc::new(PublishingListener {
+            adapter: Arc::downgrade(self),
+            listener: self.get_listener()?,
+        }));
+
+        self.source_adapter.clone().start(statsig_runtime).await
+    }
+
+    fn publish_snapshot(&self, listener: &Arc<dyn SpecsUpdateListener>) {
+        let Some(snapshot) = listener.serialize_current_specs() else {
+            log_w!(TAG, "No specs snapshot to publish");
+            return;
+        };
+
+        let staged_path = format!("{}.{}.tmp", self.path, std::process::id());
+        match std::fs::write(&staged_path, snapshot) {
+            Ok(()) => self.publish_staged_snapshot(&staged_path),
+            Err(e) => {
+                log_w!(TAG, "Failed to stage specs snapshot: {}", e);
+                let _ = std::fs::remove_file(staged_path);
+            }
+        }
+    }
+
+    fn publish_staged_snapshot(&self, staged_path: &str) {
+        let mut leader = self.leader.lock();
+        let Some(leader) = leader.as_mut() else {
+            let _ = std::fs::remove_file(staged_path);
+            return;
+        };
+
+        let generation_counter = generation_counter_mut(&mut leader.header);
+        let generation = generation_counter.load(Ordering::Acquire) + 1;
+
+        if let Err(e) = std::fs::rename(staged_path, payload_path(&self.path, generation)) {
+            log_e!(TAG, "Failed to publish specs snapshot: {}", e);
+            let _ = std::fs::remove_file(staged_path);
+            return;
+        }
+
+        generation_counter.store(generation, Ordering::Release);
+        self.loaded_generation.store(generation, Ordering::Release);
+
+        // followers still loading the previous generation keep it, older ones are unused
+        if generation > 2 {
+            let _ = std::fs::remove_file(payload_path(&self.path, generation - 2));
+        }
+
+        log_d!(TAG, "Published generation {}", generation);
+    }
+
+    // ------------------------------------------------------------------------------- [Follower]
+
+    fn read_published_generation(&self) -> Option<u64> {
+        let file_id = std::fs::metadata(&self.path)
+            .ok()
+            .map(|metadata| HeaderFileId::from_metadata(&metadata));
+
+        let mut header = self.header.lock();
+        let is_current = match (header.as_ref(), file_id) {
+            (Some(header), Some(file_id)) => header.file_id == file_id,
+            _ => false,
+        };
+
+        if !is_current {
+            // a replaced header counts generations from zero again
+            *header = open_header(&self.path);
+            self.loaded_generation.store(0, Ordering::Release);
+        }
+
+        header.as_ref().map(|header| {
+            // Safety: headers are at least HEADER_SIZE long and page aligned, so the counter is in
+            // bounds and 8 byte aligned
+            let counter =
+                unsafe { &*(header.mmap.as_ptr().add(GENERATION_OFFSET) as *const AtomicU64) };
+            counter.load(Ordering::Acquire)
+        })
+    }
+
+    /// Loads the latest published snapshot if it is not the one already loaded.
+    fn load_published_snapshot(&self) -> Result<(), StatsigErr> {
+        let generation = match self.read_published_generation() {
+            Some(generation) if generation > 0 => generation,
+            _ => {
+                return Err(StatsigErr::UnstartedAdapter(
+                    "No specs have been published yet".to_string(),
+                ))
+            }
+        };
+
+        if generation == self.loaded_generation.load(Ordering::Acquire) {
+            return Ok(());
+        }
+
+        // snapshots are never modified once published, so they are always read in place
+        let payload = SpecsPayload::map_file(&payload_path(&self.path, generation))?;
+        self.get_listener()?.did_receive_specs_payload(
+            payload,
+            SpecsSource::Adapter("Shared".to_string()),
+            None,
+        )?;
+
+        self.loaded_generation.store(generation, Ordering::Release);
+        log_d!(TAG, "Loaded generation {}", generation);
+        Ok(())
+    }
+
+    async fn execute_background_sync(
+        self: &Arc<Self>,
+        statsig_runtime: &Arc<StatsigRuntime>,
+        rt_shutdown_notify: &Arc<Notify>,
+    ) {
+        loop {
+            tokio::select! {
+                () = sleep(self.poll_interval) => {
+                    if self.is_leader() {
+                        continue;
+                    }
+
+                    if self.try_become_leader() {
+                        self.take_over_syncing(statsig_runtime).await;
+                        continue;
+                    }
+
+                    if let Err(e) = self.load_published_snapshot() {
+                        log_d!(TAG, "Failed to load shared specs: {}", e);
+                    }
+                }
+                () = rt_shutdown_notify.notified() => {
+                    log_d!(TAG, "Runtime shutdown. Shutting down shared specs background sync");
+                    break;
+                }
+                () = self.shutdown_notify.notified() => {
+                    log_d!(TAG, "Shutting down shared specs background sync");
+                    break;
+                }
+            }
+        }
+    }
+
+    async fn take_over_syncing(self: &Arc<Self>, statsig_runtime: &Arc<StatsigRuntime>) {
+        if let Err(e) = self.start_as_leader(statsig_runtime).await {
+            log_w!(
+                TAG,
+                "Failed to start source adapter after taking over: {}",
+                e
+            );
+        }
+
+        if let Err(e) = self
+            .source_adapter
+            .clone()
+            .schedule_background_sync(statsig_runtime)
+            .await
+        {
+            log_w!(
+                TAG,
+                "Failed to schedule source adapter background sync: {}",
+                e
+            );
+        }
+    }
+}
+
+impl Drop for StatsigSharedSpecsAdapter {
+    fn drop(&mut self) {
+        self.release_leadership();
+    }
+}
+
+#[async_trait]
+impl SpecsAdapter for StatsigSharedSpecsAdapter {
+    fn initialize(&self, listener: Arc<dyn SpecsUpdateListener>) {
+        match self.listener.try_write_for(Duration::from_secs(5)) {
+            Some(mut lock) => *lock = Some(listener),
+            None => {
+                log_e!(TAG, "Failed to acquire write lock on listener");
+            }
+        }
+    }
+
+    async fn start(
+        self: Arc<Self>,
+        statsig_runtime: &Arc<StatsigRuntime>,
+    ) -> Result<(), StatsigErr> {
+        let deadline = Instant::now() + self.init_timeout;
+
+        loop {
+            if self.try_become_leader() {
+                return self.start_as_leader(statsig_runtime).await;
+            }
+
+            let result = self.load_published_snapshot();
+            if result.is_ok() || Instant::now() >= deadline {
+                return result;
+            }
+
+            sleep(self.poll_interval).await;
+        }
+    }
+
+    async fn schedule_background_sync(
+        self: Arc<Self>,
+        statsig_runtime: &Arc<StatsigRuntime>,
+    ) -> Result<(), StatsigErr> {
+        if self.is_leader() {
+            self.source_adapter
+                .clone()
+                .schedule_background_sync(statsig_runtime)
+                .await?;
+        }
+
+        let weak_self = Arc::downgrade(&self);
+        let runtime = statsig_runtime.clone();
+
+        statsig_runtime.spawn(
+            "shared_specs_adapter",
+            move |rt_shutdown_notify| async move {
+                let Some(strong_self) = weak_self.upgrade() else {
+                    log_w!(TAG, "Failed to upgrade weak instance");
+                    return;
+                };
+
+                strong_self
+                    .execute_background_sync(&runtime, &rt_shutdown_notify)
+                    .await;
+            },
+        )?;
+
+        Ok(())
+    }
+
+    async fn shutdown(
+        &self,
+        timeout: Duration,
+        statsig_runtime: &Arc<StatsigRuntime>,
+    ) -> Result<(), StatsigErr> {
+        self.shutdown_notify.notify_one();
+
+        let result = match self.is_leader() {
+            true => self.source_adapter.shutdown(timeout, statsig_runtime).await,
+            false => Ok(()),
+        };
+
+        self.release_leadership();
+        result
+    }
+
+    fn get_type_name(&self) -> String {
+        stringify!(StatsigSharedSpecsAdapter).to_string()
+    }
+}
+
+/// Forwards the leader's updates and publishes a snapshot after each one that changed its specs.
+struct PublishingListener {
+    adapter: Weak<StatsigSharedSpecsAdapter>,
+    listener: Arc<dyn SpecsUpdateListener>,
+}
+
+impl SpecsUpdateListener for PublishingListener {
+    fn did_receive_specs_update(&self, update: SpecsUpdate) -> Result<(), StatsigErr> {
+        let adapter = match self.adapter.upgrade() {
+            Some(adapter) => adapter,
+            None => return self.listener.did_receive_specs_update(update),
+        };
+
+        let before = self.listener.get_current_specs_info();
+        let result = self.listener.did_receive_specs_update(update);
+        let after = self.listener.get_current_specs_info();
+
+        if result.is_ok() && (after.lcut != before.lcut || after.checksum != before.checksum) {
+            adapter.publish_snapshot(&self.listener);
+        }
+
+        result
+    }
+
+    fn get_current_specs_info(&self) -> SpecsInfo {
+        self.listener.get_current_specs_info()
+    }
+}
+
+fn payload_path(path: &str, generation: u64) -> String {
+    format!("{path}.{generation}")
+}
+
+fn generation_counter_mut(header: &mut MmapMut) -> &AtomicU64 {
+    // Safety: headers are at least HEADER_SIZE long and page aligned, so the counter is in bounds
+    // and 8 byte aligned. Other processes only ever access it atomically.
+    unsafe { &*(header.as_mut_ptr().add(GENERATION_OFFSET) as *const AtomicU64) }
+}
+
+/// Read-only view of the header, None until a leader has created it.
+fn open_header(path: &str) -> Option<FollowerHeader> {
+    let file = File::open(path).ok()?;
+    let metadata = file.metadata().ok()?;
+    if metadata.len() < HEADER_SIZE as u64 {
+        return None;
+    }
+
+    // Safety: the header is only written by the leader, through the counter's atomics
+    let mmap = unsafe { Mmap::map(&file) }.ok()?;
+    let magic = u64::from_le_bytes(mmap[0..8].try_into().ok()?);
+    if magic == 0 {
+        return None; // not created yet, see open_header_mut
+    }
+
+    if magic != MAGIC {
+        log_w!(TAG, "Shared specs header {} has an unknown format", path);
+        return None;
+    }
+
+    Some(FollowerHeader {
+        mmap,
+        file_id: HeaderFileId::from_metadata(&metadata),
+    })
+}
+
+/// Opens the header writable, creating it if it is missing or was left without a magic number by a
+/// leader that crashed while creating it.
+fn open_header_mut(path: &str) -> Result<MmapMut, StatsigErr> {
+    match read_header_magic(path) {
+        Some(MAGIC) => {}
+        Some(0) | None => create_header(path)?,
+        Some(_) => {
+            return Err(StatsigErr::FileError(format!(
+                "Shared specs header {path} has an unknown format"
+            )))
+        }
+    }
+
+    let file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .map_err(|e| StatsigErr::FileError(format!("Failed to open {path}: {e}")))?;
+
+    // Safety: only the leader, which holds the lock, maps the header writable
+    unsafe { MmapMut::map_mut(&file) }
+        .map_err(|e| StatsigErr::FileError(format!("Failed to map {path}: {e}")))
+}
+
+/// None if the header is missing or shorter than the magic number.
+fn read_header_magic(path: &str) -> Option<u64> {
+    let mut magic = [0u8; 8];
+    File::open(path).ok()?.read_exact(&mut magic).ok()?;
+    Some(u64::from_le_bytes(magic))
+}
+
+// Written in full to a temporary file and renamed into place, so the header at `path` is never
+// partially written
+fn create_header(path: &str) -> Result<(), StatsigErr> {
+    let mut header = [0u8; HEADER_SIZE];
+    header[0..8].copy_from_slice(&MAGIC.to_le_bytes());
+
+    let temp_path = format!("{}.header.{}.tmp", path, std::process::id());
+    let result =
+        std::fs::write(&temp_path, header).and_then(|()| std::fs::rename(&temp_path, path));
+
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(StatsigErr::FileError(format!(
+            "Failed to create header {path}: {e}"
+        )));
+    }
+
+    Ok(())
+}
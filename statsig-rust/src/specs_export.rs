@@ -0,0 +1,331 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::gcir::gcir_filters::name_filter_matches;
+use crate::specs_response::cmab_types::CMABConfig;
+use crate::specs_response::condition_key::ConditionKey;
+use crate::specs_response::param_store_types::{Parameter, ParameterStore};
+use crate::specs_response::spec_directory::SpecDirectory;
+use crate::specs_response::spec_types::{
+    Condition, ConfigMapping, Rule, SessionReplayInfo, Spec, SpecsResponseFull,
+};
+use crate::{DynamicValue, StatsigErr};
+
+/// Selects what `Statsig::export_specs` includes. Names may be globs such as `checkout_*`, as in
+/// `ClientInitResponseOptions`. With no filter set everything is exported. Otherwise only the types
+/// with a filter are, so a gate filter alone exports no configs, layers or param stores.
+///
+/// Everything the selected entities depend on (nested gates and segments, layer delegates,
+/// config overrides, param store references) is always included, so the export evaluates
+/// the same as the full payload for the selected entities.
+#[derive(Default, Deserialize)]
+pub struct SpecsExportFilter {
+    pub feature_gate_filter: Option<HashSet<String>>,
+    pub experiment_filter: Option<HashSet<String>>,
+    pub dynamic_config_filter: Option<HashSet<String>>,
+    pub layer_filter: Option<HashSet<String>>,
+    pub param_store_filter: Option<HashSet<String>>,
+}
+
+impl SpecsExportFilter {
+    fn has_any_filter(&self) -> bool {
+        [
+            &self.feature_gate_filter,
+            &self.experiment_filter,
+            &self.dynamic_config_filter,
+            &self.layer_filter,
+            &self.param_store_filter,
+        ]
+        .iter()
+        .any(|f| f.is_some())
+    }
+}
+
+// Mirrors the serialized shape of SpecsResponseFull, so exports can be passed to
+// StatsigBootstrapSpecsAdapter. The checksum is left out as it no longer matches.
+#[skip_serializing_none]
+#[derive(Serialize)]
+struct SpecsExport<'a> {
+    company_id: &'a Option<String>,
+    feature_gates: HashMap<&'a str, &'a Spec>,
+    dynamic_configs: HashMap<&'a str, &'a Spec>,
+    layer_configs: HashMap<&'a str, &'a Spec>,
+    condition_map: HashMap<&'a ConditionKey, &'a Condition>,
+    experiment_to_layer: HashMap<&'a str, &'a str>,
+    has_updates: bool,
+    time: u64,
+    default_environment: &'a Option<String>,
+    app_id: &'a Option<DynamicValue>,
+    sdk_keys_to_app_ids: &'a Option<HashMap<String, DynamicValue>>,
+    hashed_sdk_keys_to_app_ids: &'a Option<HashMap<String, DynamicValue>>,
+    param_stores: Option<HashMap<&'a str, &'a ParameterStore>>,
+    sdk_configs: &'a Option<HashMap<String, DynamicValue>>,
+    cmab_configs: Option<HashMap<&'a str, &'a CMABConfig>>,
+    overrides: Option<HashMap<&'a str, &'a Vec<ConfigMapping>>>,
+    override_rules: Option<HashMap<&'a str, &'a Rule>>,
+    id_lists: &'a Option<HashMap<String, bool>>,
+    response_format: &'a Option<String>,
+    session_replay_info: &'a Option<SessionReplayInfo>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum SpecKind {
+    Gate,
+    Config,
+    Layer,
+}
+
+pub(crate) fn export_specs(
+    values: &SpecsResponseFull,
+    filter: &SpecsExportFilter,
+) -> Result<String, StatsigErr> {
+    let mut collector = DependencyCollector {
+        values,
+        specs: HashSet::new(),
+        conditions: HashSet::new(),
+        override_rules: HashSet::new(),
+    };
+
+    for (kind, directory) in [
+        (SpecKind::Gate, &values.feature_gates),
+        (SpecKind::Config, &values.dynamic_configs),
+        (SpecKind::Layer, &values.layer_configs),
+    ] {
+        for spec_addy in directory.specs.values() {
+            let name = spec_addy.name.as_str();
+            if is_selected(filter, &spec_addy.spec.entity, name) {
+                collector.visit_spec(kind, name);
+            }
+        }
+    }
+
+    let param_stores = values.param_stores.as_ref().map(|stores| {
+        stores
+            .iter()
+            .filter(|(name, _)| matches_filter(filter, &filter.param_store_filter, name))
+            .map(|(name, store)| {
+                collector.visit_param_store(store);
+                (name.as_str(), store)
+            })
+            .collect::<HashMap<_, _>>()
+    });
+
+    if let Some(gate) = values
+        .session_replay_info
+        .as_ref()
+        .and_then(|info| info.targeting_gate.as_ref())
+    {
+        collector.visit_spec(SpecKind::Gate, gate);
+    }
+
+    let export = collector.build(param_stores);
+    serde_json::to_string(&export).map_err(|e| StatsigErr::SerializationError(e.to_string()))
+}
+
+fn is_selected(filter: &SpecsExportFilter, entity: &str, name: &str) -> bool {
+    let entity_filter = match entity {
+        "feature_gate" | "segment" | "holdout" => &filter.feature_gate_filter,
+        "experiment" | "autotune" => &filter.experiment_filter,
+        "dynamic_config" => &filter.dynamic_config_filter,
+        "layer" => &filter.layer_filter,
+        _ => return false,
+    };
+
+    matches_filter(filter, entity_filter, name)
+}
+
+fn matches_filter(
+    filter: &SpecsExportFilter,
+    type_filter: &Option<HashSet<String>>,
+    name: &str,
+) -> bool {
+    match type_filter {
+        Some(type_filter) => name_filter_matches(type_filter, name),
+        None => !filter.has_any_filter(),
+    }
+}
+
+struct DependencyCollector<'a> {
+    values: &'a SpecsResponseFull,
+    specs: HashSet<(SpecKind, &'a str)>,
+    conditions: HashSet<&'a ConditionKey>,
+    override_rules: HashSet<&'a str>,
+}
+
+impl<'a> DependencyCollector<'a> {
+    fn directory(&self, kind: SpecKind) -> &'a SpecDirectory {
+        match kind {
+            SpecKind::Gate => &self.values.feature_gates,
+            SpecKind::Config => &self.values.dynamic_configs,
+            SpecKind::Layer => &self.values.layer_configs,
+        }
+    }
+
+    fn visit_spec(&mut self, kind: SpecKind, name: &str) {
+        let values = self.values;
+        let spec_addy = match self.directory(kind).get(name) {
+            Some(spec_addy) => spec_addy,
+            None => return,
+        };
+
+        if !self.specs.insert((kind, spec_addy.name.as_str())) {
+            return;
+        }
+
+        for rule in &spec_addy.spec.rules {
+            self.visit_rule(rule);
+
+            if let Some(delegate) = &rule.config_delegate {
+                self.visit_spec(SpecKind::Config, delegate);
+            }
+        }
+
+        let mappings = values
+            .overrides
+            .as_ref()
+            .and_then(|overrides| overrides.get(name));
+
+        for mapping in mappings.into_iter().flatten() {
+            // overrides are evaluated as the same type as the spec they replace
+            self.visit_spec(kind, &mapping.new_config_name);
+
+            for override_rule in &mapping.rules {
+                let rule = values
+                    .override_rules
+                    .as_ref()
+                    .and_then(|rules| rules.get_key_value(&override_rule.rule_name));
+
+                if let Some((rule_name, rule)) = rule {
+                    self.override_rules.insert(rule_name.as_str());
+                    self.visit_rule(rule);
+                }
+            }
+        }
+    }
+
+    fn visit_rule(&mut self, rule: &'a Rule) {
+        let values = self.values;
+
+        for condition_key in &rule.conditions {
+            let (key, condition) = match values.condition_map.get_key_value(condition_key) {
+                Some(entry) => entry,
+                None => continue,
+            };
+
+            if !self.conditions.insert(key) {
+                continue;
+            }
+
+            if condition.condition_type != "pass_gate" && condition.condition_type != "fail_gate" {
+                continue;
+            }
+
+            let gate_name = condition
+                .target_value
+                .as_ref()
+                .and_then(|v| v.string_value.as_ref());
+
+            if let Some(gate_name) = gate_name {
+                self.visit_spec(SpecKind::Gate, &gate_name.value);
+            }
+        }
+    }
+
+    fn visit_param_store(&mut self, store: &ParameterStore) {
+        for parameter in store.parameters.values() {
+            match parameter {
+                Parameter::StaticValue(_) => {}
+                Parameter::Gate(gate) => self.visit_spec(SpecKind::Gate, &gate.gate_name),
+                Parameter::DynamicConfig(config) => {
+                    self.visit_spec(SpecKind::Config, &config.config_name);
+                }
+                Parameter::Experiment(experiment) => {
+                    self.visit_spec(SpecKind::Config, &experiment.experiment_name);
+                }
+                Parameter::Layer(layer) => self.visit_spec(SpecKind::Layer, &layer.layer_name),
+            }
+        }
+    }
+
+    fn get_specs(&self, kind: SpecKind) -> HashMap<&'a str, &'a Spec> {
+        self.directory(kind)
+            .specs
+            .values()
+            .filter(|spec_addy| self.specs.contains(&(kind, spec_addy.name.as_str())))
+            .map(|spec_addy| (spec_addy.name.as_str(), spec_addy.spec.as_ref()))
+            .collect()
+    }
+
+    fn build(self, param_stores: Option<HashMap<&'a str, &'a ParameterStore>>) -> SpecsExport<'a> {
+        let values = self.values;
+        let feature_gates = self.get_specs(SpecKind::Gate);
+        let dynamic_configs = self.get_specs(SpecKind::Config);
+        let layer_configs = self.get_specs(SpecKind::Layer);
+
+        let condition_map = values
+            .condition_map
+            .iter()
+            .filter(|(key, _)| self.conditions.contains(key))
+            .collect();
+
+        let experiment_to_layer = values
+            .experiment_to_layer
+            .iter()
+            .filter(|(experiment, _)| dynamic_configs.contains_key(experiment.as_str()))
+            .map(|(experiment, layer)| (experiment.as_str(), layer.as_str()))
+            .collect();
+
+        let cmab_configs = values.cmab_configs.as_ref().map(|cmabs| {
+            cmabs
+                .iter()
+                .filter(|(name, _)| dynamic_configs.contains_key(name.as_str()))
+                .map(|(name, cmab)| (name.as_str(), cmab))
+                .collect()
+        });
+
+        let overrides = values.overrides.as_ref().map(|overrides| {
+            overrides
+                .iter()
+                .filter(|(name, _)| {
+                    feature_gates.contains_key(name.as_str())
+                        || dynamic_configs.contains_key(name.as_str())
+                        || layer_configs.contains_key(name.as_str())
+                })
+                .map(|(name, mappings)| (name.as_str(), mappings))
+                .collect()
+        });
+
+        let override_rules = values.override_rules.as_ref().map(|rules| {
+            rules
+                .iter()
+                .filter(|(name, _)| self.override_rules.contains(name.as_str()))
+                .map(|(name, rule)| (name.as_str(), rule))
+                .collect()
+        });
+
+        SpecsExport {
+            company_id: &values.company_id,
+            feature_gates,
+            dynamic_configs,
+            layer_configs,
+            condition_map,
+            experiment_to_layer,
+            has_updates: true,
+            time: values.time,
+            default_environment: &values.default_environment,
+            app_id: &values.app_id,
+            sdk_keys_to_app_ids: &values.sdk_keys_to_app_ids,
+            hashed_sdk_keys_to_app_ids: &values.hashed_sdk_keys_to_app_ids,
+            param_stores,
+            sdk_configs: &values.sdk_configs,
+            cmab_configs,
+            overrides,
+            override_rules,
+            id_lists: &values.id_lists,
+            response_format: &values.response_format,
+            session_replay_info: &values.session_replay_info,
+        }
+    }
+}
@@ -2,6 +2,8 @@ use std::hash::{Hash, Hasher};
 
 use serde::{Deserialize, Deserializer, Serialize};
 
+use super::interned_string::InternedString;
+
 /**
 DCS v2 stores conditions in a map. This means that for each evaluation, we do a lookup from
 the Rule.conditions vec to the top level condition_map.
@@ -10,12 +12,14 @@ Given that we know the keys are a djb2 hash, we can convert them to u64 numbers
 Having these keys as numbers makes the lookup a lot faster as the "hash" for the HashMap is just the u64 integer.
 
 I have included fallback support for Strings in-case the DCS payload changes in the future
+
+The same keys are repeated by every rule using a condition, so the strings are interned.
 */
 
 #[derive(PartialEq, Eq, Debug)]
 pub struct ConditionKey {
     pub u64_value: Option<u64>,
-    pub string_value: String,
+    pub string_value: InternedString,
 }
 
 impl<'de> Deserialize<'de> for ConditionKey {
@@ -23,7 +27,7 @@ impl<'de> Deserialize<'de> for ConditionKey {
     where
         D: Deserializer<'de>,
     {
-        let string_value = String::deserialize(deserializer)?;
+        let string_value = InternedString::deserialize(deserializer)?;
         let u64_value = string_value.parse::<u64>().ok();
         Ok(ConditionKey {
             u64_value,
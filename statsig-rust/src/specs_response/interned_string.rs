@@ -0,0 +1,219 @@
+use crate::hashing::ahash_str;
+use parking_lot::Mutex;
+use serde::de::{Error, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::{Arc, Weak};
+
+lazy_static::lazy_static! {
+    static ref INTERNED_STRINGS: Interner<str> = Interner::default();
+}
+
+const MIN_SWEEP_LEN: usize = 1024;
+
+/// Deduplicates values that repeat across (and between) specs payloads, such as salts and
+/// condition keys.
+///
+/// Only weak references are held. Dead entries are swept once the map has doubled in size since
+/// the last sweep, so dropping an interned value never takes the lock.
+pub(crate) struct Interner<T: ?Sized> {
+    state: Mutex<InternerState<T>>,
+}
+
+struct InternerState<T: ?Sized> {
+    values: HashMap<u64, Weak<T>>,
+    len_after_sweep: usize,
+}
+
+impl<T: ?Sized> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(InternerState {
+                values: HashMap::new(),
+                len_after_sweep: 0,
+            }),
+        }
+    }
+}
+
+impl<T: ?Sized> Interner<T> {
+    /// Returns the live value interned under `hash` if `matches` accepts it, otherwise interns the
+    /// result of `create`. On a hash collision the new value is returned without being interned.
+    pub fn get_or_insert(
+        &self,
+        hash: u64,
+        matches: impl FnOnce(&T) -> bool,
+        create: impl FnOnce() -> Arc<T>,
+    ) -> Arc<T> {
+        let mut state = self.state.lock();
+
+        if let Some(found) = state.values.get(&hash).and_then(Weak::upgrade) {
+            if matches(&found) {
+                return found;
+            }
+
+            return create();
+        }
+
+        let value = create();
+        state.values.insert(hash, Arc::downgrade(&value));
+
+        if state.values.len() >= MIN_SWEEP_LEN.max(state.len_after_sweep * 2) {
+            state.values.retain(|_, weak| weak.strong_count() > 0);
+            state.len_after_sweep = state.values.len();
+        }
+
+        value
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.state.lock().values.len()
+    }
+}
+
+/// An immutable string shared by every spec field holding the same value.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct InternedString(Arc<str>);
+
+impl InternedString {
+    pub fn new(value: &str) -> Self {
+        let value = INTERNED_STRINGS.get_or_insert(
+            ahash_str(value),
+            |found| found == value,
+            || Arc::from(value),
+        );
+
+        Self(value)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Default for InternedString {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl Deref for InternedString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for InternedString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for InternedString {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for InternedString {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for InternedString {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl fmt::Display for InternedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl fmt::Debug for InternedString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl Serialize for InternedString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for InternedString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct InternedStringVisitor;
+
+        impl Visitor<'_> for InternedStringVisitor {
+            type Value = InternedString;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a string")
+            }
+
+            // borrowed or not, repeated values are looked up without allocating
+            fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(InternedString::new(value))
+            }
+        }
+
+        deserializer.deserialize_str(InternedStringVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equal_values_share_storage() {
+        let a = InternedString::new("a-salt-for-interning-tests");
+        let b: InternedString = serde_json::from_str(r#""a-salt-for-interning-tests""#).unwrap();
+
+        assert_eq!(a, b);
+        assert!(a.ptr_eq(&b));
+        assert!(!a.ptr_eq(&InternedString::new("another-salt-for-interning-tests")));
+    }
+
+    #[test]
+    fn test_dead_entries_are_swept() {
+        let interner = Interner::<str>::default();
+        for i in 0..MIN_SWEEP_LEN * 3 {
+            let value = i.to_string();
+            interner.get_or_insert(i as u64, |found| found == value, || Arc::from(&*value));
+        }
+
+        assert!(interner.len() < MIN_SWEEP_LEN);
+    }
+
+    #[test]
+    fn test_hash_collisions_are_not_merged() {
+        let interner = Interner::<str>::default();
+        let a = interner.get_or_insert(1, |found| found == "a", || Arc::from("a"));
+        let b = interner.get_or_insert(1, |found| found == "b", || Arc::from("b"));
+
+        assert_eq!(&*a, "a");
+        assert_eq!(&*b, "b");
+    }
+}
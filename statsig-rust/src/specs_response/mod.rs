@@ -1,6 +1,8 @@
 pub mod cmab_types;
 pub mod condition_key;
+pub mod interned_string;
 pub mod param_store_types;
 pub mod spec_directory;
 pub mod spec_types;
 pub mod spec_types_encoded;
+pub mod specs_payload;
@@ -21,6 +21,29 @@ impl SpecDirectory {
         self.specs.get(&hash)
     }
 
+    /// Replaces specs by name, then removes the deleted ones.
+    pub fn apply_changes(&mut self, changed: SpecDirectory, deleted: &[String]) {
+        for name in deleted {
+            self.specs.remove(&ahash_str(name));
+        }
+        self.specs.extend(changed.specs);
+    }
+
+    /// The specs there would be after [`Self::apply_changes`], without applying them.
+    pub fn with_changes<'a>(
+        &'a self,
+        changed: &'a SpecDirectory,
+        deleted: &[String],
+    ) -> impl Iterator<Item = &'a AddressableSpec> {
+        let deleted: Vec<u64> = deleted.iter().map(|name| ahash_str(name)).collect();
+
+        self.specs
+            .iter()
+            .filter(move |(hash, _)| !deleted.contains(hash) && !changed.specs.contains_key(hash))
+            .map(|(_, spec)| spec)
+            .chain(changed.specs.values())
+    }
+
     pub fn keys(&self) -> impl Iterator<Item = &str> {
         self.specs.values().map(|v| v.name.as_str())
     }
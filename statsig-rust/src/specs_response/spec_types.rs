@@ -1,6 +1,7 @@
 use crate::evaluation::dynamic_string::DynamicString;
 use crate::evaluation::{dynamic_returnable::DynamicReturnable, evaluator_value::EvaluatorValue};
 use crate::event_logging::exposable_string::ExposableString;
+use crate::hashing::djb2;
 use crate::DynamicValue;
 use ahash::HashMap as AHashMap;
 use serde::{Deserialize, Deserializer, Serialize};
@@ -8,7 +9,8 @@ use serde_with::skip_serializing_none;
 use std::collections::HashMap;
 
 use super::condition_key::ConditionKey;
-use super::spec_directory::SpecDirectory;
+use super::interned_string::InternedString;
+use super::spec_directory::{AddressableSpec, SpecDirectory};
 use super::{cmab_types::CMABConfig, param_store_types::ParameterStore};
 
 // DO_NOT_CLONE: Please do not add the Clone trait to this struct. We intentionally
@@ -20,7 +22,7 @@ use super::{cmab_types::CMABConfig, param_store_types::ParameterStore};
 pub struct Spec {
     #[serde(rename = "type")]
     pub _type: String,
-    pub salt: String,
+    pub salt: InternedString,
     pub default_value: DynamicReturnable,
     pub enabled: bool,
     pub rules: Vec<Rule>,
@@ -44,7 +46,7 @@ pub struct Rule {
     pub pass_percentage: f64,
     pub return_value: DynamicReturnable,
     pub id: ExposableString,
-    pub salt: Option<String>,
+    pub salt: Option<InternedString>,
     pub conditions: Vec<ConditionKey>,
     pub id_type: DynamicString,
     pub group_name: Option<String>,
@@ -122,6 +124,7 @@ pub struct SpecsResponseFull {
     pub id_lists: Option<HashMap<String, bool>>,
     pub response_format: Option<String>,
     pub session_replay_info: Option<SessionReplayInfo>,
+    pub is_delta: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -130,6 +133,172 @@ pub struct SpecsResponseNoUpdates {
     pub has_updates: bool,
 }
 
+/// Changes to apply on top of the values with `base_time` (and `base_checksum`, when given).
+/// Specs are replaced whole, keyed by name. Top level fields that are present replace the
+/// current ones. When given, `specs_checksum` must match [`SpecsResponseFull::get_specs_checksum`]
+/// of the result.
+///
+/// Only served by a forward proxy, never by Statsig's endpoints. `StatsigHttpSpecsAdapter` asks for
+/// it with `accept_deltas=true` when `StatsigOptions::enable_delta_specs_updates` is set and
+/// `specs_url` is not a Statsig host.
+#[derive(Deserialize, Default)] /* DO_NOT_CLONE */
+#[serde(default)]
+pub struct SpecsResponseDelta {
+    pub is_delta: bool,
+    pub base_time: u64,
+    pub base_checksum: Option<String>,
+    pub time: u64,
+    pub checksum: Option<String>,
+    pub specs_checksum: Option<String>,
+    pub feature_gates: SpecDirectory,
+    pub dynamic_configs: SpecDirectory,
+    pub layer_configs: SpecDirectory,
+    pub condition_map: AHashMap<ConditionKey, Condition>,
+    pub experiment_to_layer: HashMap<String, String>,
+    pub deleted_feature_gates: Vec<String>,
+    pub deleted_dynamic_configs: Vec<String>,
+    pub deleted_layer_configs: Vec<String>,
+    pub deleted_conditions: Vec<ConditionKey>,
+    pub deleted_experiment_to_layer: Vec<String>,
+    pub diagnostics: Option<HashMap<String, f64>>,
+    pub param_stores: Option<HashMap<String, ParameterStore>>,
+    pub sdk_configs: Option<HashMap<String, DynamicValue>>,
+    pub company_id: Option<String>,
+    pub default_environment: Option<String>,
+    pub app_id: Option<DynamicValue>,
+    pub sdk_keys_to_app_ids: Option<HashMap<String, DynamicValue>>,
+    pub hashed_sdk_keys_to_app_ids: Option<HashMap<String, DynamicValue>>,
+    pub cmab_configs: Option<HashMap<String, CMABConfig>>,
+    pub overrides: Option<HashMap<String, Vec<ConfigMapping>>>,
+    pub override_rules: Option<HashMap<String, Rule>>,
+    pub id_lists: Option<HashMap<String, bool>>,
+    pub session_replay_info: Option<SessionReplayInfo>,
+}
+
+impl SpecsResponseFull {
+    /// A checksum over the name and version of every gate, config and layer, used to check that a
+    /// delta left the specs as the forward proxy has them. It is the djb2 hash of the sorted
+    /// `{gate|config|layer}:{name}:{version}` entries joined by `;`.
+    pub fn get_specs_checksum(&self) -> String {
+        specs_checksum(
+            self.feature_gates.specs.values(),
+            self.dynamic_configs.specs.values(),
+            self.layer_configs.specs.values(),
+        )
+    }
+}
+
+impl SpecsResponseDelta {
+    /// Checks `specs_checksum`, when given, against what applying the delta to `values` would give.
+    pub fn matches_specs_checksum(&self, values: &SpecsResponseFull) -> bool {
+        let expected = match &self.specs_checksum {
+            Some(expected) => expected,
+            None => return true,
+        };
+
+        let actual = specs_checksum(
+            values
+                .feature_gates
+                .with_changes(&self.feature_gates, &self.deleted_feature_gates),
+            values
+                .dynamic_configs
+                .with_changes(&self.dynamic_configs, &self.deleted_dynamic_configs),
+            values
+                .layer_configs
+                .with_changes(&self.layer_configs, &self.deleted_layer_configs),
+        );
+
+        *expected == actual
+    }
+
+    /// Applies the delta in place. Callers are expected to have checked the base first.
+    pub fn apply_to(self, values: &mut SpecsResponseFull) {
+        values
+            .feature_gates
+            .apply_changes(self.feature_gates, &self.deleted_feature_gates);
+        values
+            .dynamic_configs
+            .apply_changes(self.dynamic_configs, &self.deleted_dynamic_configs);
+        values
+            .layer_configs
+            .apply_changes(self.layer_configs, &self.deleted_layer_configs);
+
+        for key in &self.deleted_conditions {
+            values.condition_map.remove(key);
+        }
+        values.condition_map.extend(self.condition_map);
+
+        for name in &self.deleted_experiment_to_layer {
+            values.experiment_to_layer.remove(name);
+        }
+        values.experiment_to_layer.extend(self.experiment_to_layer);
+
+        if self.diagnostics.is_some() {
+            values.diagnostics = self.diagnostics;
+        }
+        if self.param_stores.is_some() {
+            values.param_stores = self.param_stores;
+        }
+        if self.sdk_configs.is_some() {
+            values.sdk_configs = self.sdk_configs;
+        }
+        if self.company_id.is_some() {
+            values.company_id = self.company_id;
+        }
+        if self.default_environment.is_some() {
+            values.default_environment = self.default_environment;
+        }
+        if self.app_id.is_some() {
+            values.app_id = self.app_id;
+        }
+        if self.sdk_keys_to_app_ids.is_some() {
+            values.sdk_keys_to_app_ids = self.sdk_keys_to_app_ids;
+        }
+        if self.hashed_sdk_keys_to_app_ids.is_some() {
+            values.hashed_sdk_keys_to_app_ids = self.hashed_sdk_keys_to_app_ids;
+        }
+        if self.cmab_configs.is_some() {
+            values.cmab_configs = self.cmab_configs;
+        }
+        if self.overrides.is_some() {
+            values.overrides = self.overrides;
+        }
+        if self.override_rules.is_some() {
+            values.override_rules = self.override_rules;
+        }
+        if self.id_lists.is_some() {
+            values.id_lists = self.id_lists;
+        }
+        if self.session_replay_info.is_some() {
+            values.session_replay_info = self.session_replay_info;
+        }
+
+        values.has_updates = true;
+        values.time = self.time;
+        values.checksum = self.checksum;
+    }
+}
+
+fn specs_checksum<'a>(
+    feature_gates: impl Iterator<Item = &'a AddressableSpec>,
+    dynamic_configs: impl Iterator<Item = &'a AddressableSpec>,
+    layer_configs: impl Iterator<Item = &'a AddressableSpec>,
+) -> String {
+    let describe = |kind: &str, spec: &AddressableSpec| {
+        let version = spec.spec.version.unwrap_or_default();
+        format!("{kind}:{}:{version}", spec.name.as_str())
+    };
+
+    let mut entries: Vec<String> = feature_gates
+        .map(|s| describe("gate", s))
+        .chain(dynamic_configs.map(|s| describe("config", s)))
+        .chain(layer_configs.map(|s| describe("layer", s)))
+        .collect();
+    entries.sort_unstable();
+
+    djb2(&entries.join(";"))
+}
+
 impl<'de> Deserialize<'de> for Condition {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -0,0 +1,166 @@
+use crate::StatsigErr;
+use memmap2::Mmap;
+use serde_json::value::RawValue;
+use std::cell::RefCell;
+use std::fmt;
+use std::fs::File;
+use std::sync::Arc;
+
+thread_local! {
+    static RETAINED_PAYLOAD: RefCell<Option<Arc<SpecsPayload>>> = const { RefCell::new(None) };
+}
+
+/// The bytes of a specs response, either owned or memory-mapped from a file.
+///
+/// With `StatsigOptions::enable_zero_copy_specs`, and always for mapped payloads, the spec store
+/// keeps the payload alive after parsing and return values point into it instead of being copied
+/// out.
+pub enum SpecsPayload {
+    Owned(Vec<u8>),
+    Mapped(Mmap),
+}
+
+impl SpecsPayload {
+    /// Maps `path` into memory. Writers must replace the file (write elsewhere, then rename) rather
+    /// than rewrite it, or values read from the mapping can change underneath the SDK.
+    pub fn map_file(path: &str) -> Result<Self, StatsigErr> {
+        let file = File::open(path).map_err(|e| StatsigErr::FileError(e.to_string()))?;
+
+        // SAFETY: the mapping is read-only and the SDK's own writers replace spec files instead of
+        // truncating them. Rewriting a mapped file in place is documented as unsupported above.
+        let mmap = unsafe { Mmap::map(&file) }.map_err(|e| StatsigErr::FileError(e.to_string()))?;
+
+        Ok(Self::Mapped(mmap))
+    }
+
+    /// Maps `path` if `retained`, as only listeners that keep the payload benefit from the mapping.
+    /// Otherwise reads it into memory.
+    pub fn from_file(path: &str, retained: bool) -> Result<Self, StatsigErr> {
+        if retained {
+            return Self::map_file(path);
+        }
+
+        match std::fs::read_to_string(path) {
+            Ok(data) => Ok(Self::Owned(data.into_bytes())),
+            Err(e) => Err(StatsigErr::FileError(e.to_string())),
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Owned(bytes) => bytes,
+            Self::Mapped(mmap) => mmap,
+        }
+    }
+
+    pub fn is_mapped(&self) -> bool {
+        matches!(self, Self::Mapped(_))
+    }
+
+    pub fn len(&self) -> usize {
+        self.as_bytes().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.as_bytes().is_empty()
+    }
+}
+
+impl From<Vec<u8>> for SpecsPayload {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Owned(bytes)
+    }
+}
+
+impl fmt::Debug for SpecsPayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Owned(bytes) => write!(f, "SpecsPayload::Owned({} bytes)", bytes.len()),
+            Self::Mapped(mmap) => write!(f, "SpecsPayload::Mapped({} bytes)", mmap.len()),
+        }
+    }
+}
+
+/// Runs `parse` with `payload` marked as retained on this thread, so values deserialized from its
+/// bytes can borrow them through [`RetainedRawValue`].
+pub(crate) fn with_retained_payload<T>(
+    payload: &Arc<SpecsPayload>,
+    parse: impl FnOnce() -> T,
+) -> T {
+    struct ResetOnDrop(Option<Arc<SpecsPayload>>);
+
+    impl Drop for ResetOnDrop {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            RETAINED_PAYLOAD.with(|retained| *retained.borrow_mut() = previous);
+        }
+    }
+
+    let previous = RETAINED_PAYLOAD.with(|retained| retained.replace(Some(payload.clone())));
+    let _reset = ResetOnDrop(previous);
+
+    parse()
+}
+
+pub(crate) fn is_retaining_payload(payload: &Arc<SpecsPayload>) -> bool {
+    RETAINED_PAYLOAD.with(|retained| {
+        retained
+            .borrow()
+            .as_ref()
+            .is_some_and(|current| Arc::ptr_eq(current, payload))
+    })
+}
+
+/// A JSON value that lives inside a retained [`SpecsPayload`]. It was checked when deserialized and
+/// is read in place afterwards.
+#[derive(Clone)]
+pub(crate) struct RetainedRawValue {
+    // Points into `payload`, which is never modified and is kept alive alongside it
+    raw_value: &'static RawValue,
+    payload: Arc<SpecsPayload>,
+}
+
+impl RetainedRawValue {
+    /// Some if `raw_value` was deserialized from the payload currently retained on this thread.
+    pub fn from_retained(raw_value: &RawValue) -> Option<Self> {
+        RETAINED_PAYLOAD.with(|retained| {
+            let payload = retained.borrow().clone()?;
+
+            let bytes = payload.as_bytes().as_ptr_range();
+            let value = raw_value.get().as_bytes().as_ptr_range();
+            if value.start < bytes.start || value.end > bytes.end {
+                return None;
+            }
+
+            // SAFETY: `raw_value` borrows from the payload's bytes, checked above. They do not move
+            // or change while the payload is alive, and the payload is held by the returned value.
+            let raw_value = unsafe { &*(raw_value as *const RawValue) };
+
+            Some(Self { raw_value, payload })
+        })
+    }
+
+    pub fn as_raw_value(&self) -> &RawValue {
+        self.raw_value
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.raw_value.get()
+    }
+
+    pub fn payload(&self) -> &Arc<SpecsPayload> {
+        &self.payload
+    }
+}
+
+impl fmt::Debug for RetainedRawValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for RetainedRawValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
@@ -0,0 +1,201 @@
+use crate::evaluation::evaluation_details::EvaluationDetails;
+use crate::observability::observability_client_adapter::{MetricType, ObservabilityEvent};
+use crate::observability::ops_stats::OpsStatsForInstance;
+use crate::sdk_event_emitter::{SdkEvent, SdkEventEmitter};
+use crate::spec_store::{SpecStore, SpecStoreData};
+use crate::{log_d, log_e, log_w, SpecsSource, StatsigOptions, StatsigRuntime};
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const TAG: &str = stringify!(SpecsStalenessMonitor);
+const BG_TASK_TAG: &str = "specs_staleness_monitor";
+
+const DEFAULT_CHECK_INTERVAL_MS: u64 = 10_000;
+const MIN_CHECK_INTERVAL_MS: u64 = 100;
+
+/// What to do once the current specs are older than `StatsigOptions.max_specs_age_ms`.
+///
+/// Every policy logs a warning when the specs first become stale.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StaleSpecsPolicy {
+    /// Keep evaluating on the stale specs.
+    #[default]
+    ContinueWithWarning,
+    /// Return default values with a `<Source>:Stale` evaluation reason.
+    ReturnDefaults,
+    /// Keep evaluating, and emit an `SdkEvent::SpecsStale` through the `SdkEventEmitter`.
+    EmitAlert,
+}
+
+impl From<&str> for StaleSpecsPolicy {
+    fn from(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "return_defaults" | "returndefaults" => StaleSpecsPolicy::ReturnDefaults,
+            "emit_alert" | "emitalert" => StaleSpecsPolicy::EmitAlert,
+            _ => StaleSpecsPolicy::ContinueWithWarning,
+        }
+    }
+}
+
+pub(crate) struct SpecsStalenessMonitor {
+    max_age_ms: Option<u64>,
+    policy: StaleSpecsPolicy,
+    is_stale: AtomicBool,
+}
+
+impl SpecsStalenessMonitor {
+    pub fn new(options: &StatsigOptions) -> Self {
+        Self {
+            max_age_ms: options.max_specs_age_ms,
+            policy: options.stale_specs_policy.unwrap_or_default(),
+            is_stale: AtomicBool::new(false),
+        }
+    }
+
+    /// Only returns details when the specs are stale and the policy is `ReturnDefaults`.
+    pub fn get_stale_evaluation_details(&self, data: &SpecStoreData) -> Option<EvaluationDetails> {
+        if self.policy != StaleSpecsPolicy::ReturnDefaults {
+            return None;
+        }
+
+        let max_age_ms = self.max_age_ms?;
+        let age_ms = get_specs_age_ms(data, Utc::now().timestamp_millis() as u64)?;
+        if age_ms <= max_age_ms {
+            return None;
+        }
+
+        Some(EvaluationDetails::stale(data))
+    }
+
+    pub fn spawn(
+        self: &Arc<Self>,
+        statsig_runtime: &Arc<StatsigRuntime>,
+        spec_store: &Arc<SpecStore>,
+        ops_stats: &Arc<OpsStatsForInstance>,
+        event_emitter: &SdkEventEmitter,
+    ) {
+        let me = self.clone();
+        let spec_store = spec_store.clone();
+        let ops_stats = ops_stats.clone();
+        let event_emitter = event_emitter.clone();
+        let interval = Duration::from_millis(self.get_check_interval_ms());
+
+        let spawn_result =
+            statsig_runtime.spawn(BG_TASK_TAG, move |rt_shutdown_notify| async move {
+                loop {
+                    tokio::select! {
+                        () = tokio::time::sleep(interval) => {
+                            me.check(&spec_store, &ops_stats, &event_emitter);
+                        }
+                        () = rt_shutdown_notify.notified() => {
+                            return;
+                        }
+                    }
+                }
+            });
+
+        if let Err(e) = spawn_result {
+            log_e!(TAG, "Failed to spawn specs staleness monitor: {e}");
+        }
+    }
+
+    fn get_check_interval_ms(&self) -> u64 {
+        match self.max_age_ms {
+            Some(max_age_ms) => {
+                (max_age_ms / 2).clamp(MIN_CHECK_INTERVAL_MS, DEFAULT_CHECK_INTERVAL_MS)
+            }
+            None => DEFAULT_CHECK_INTERVAL_MS,
+        }
+    }
+
+    fn check(
+        &self,
+        spec_store: &SpecStore,
+        ops_stats: &OpsStatsForInstance,
+        event_emitter: &SdkEventEmitter,
+    ) {
+        let now = Utc::now().timestamp_millis() as u64;
+        let (age_ms, source, lcut, received_at) =
+            match spec_store.data.try_read_for(Duration::from_secs(5)) {
+                Some(data) => (
+                    get_specs_age_ms(&data, now),
+                    data.source.clone(),
+                    data.values.time,
+                    data.time_received_at,
+                ),
+                None => {
+                    log_e!(TAG, "Failed to acquire read lock: Failed to lock data");
+                    return;
+                }
+            };
+
+        let age_ms = match age_ms {
+            Some(age_ms) => age_ms,
+            None => return,
+        };
+
+        ops_stats.log(ObservabilityEvent::new_event(
+            MetricType::Gauge,
+            "specs_age_ms".to_string(),
+            age_ms as f64,
+            Some(HashMap::from([("source".to_string(), source.to_string())])),
+        ));
+
+        let max_age_ms = match self.max_age_ms {
+            Some(max_age_ms) => max_age_ms,
+            None => return,
+        };
+
+        let is_stale = age_ms > max_age_ms;
+        let was_stale = self.is_stale.swap(is_stale, Ordering::SeqCst);
+
+        if !is_stale {
+            if was_stale {
+                log_d!(TAG, "Specs are no longer stale ({}ms old)", age_ms);
+            }
+            return;
+        }
+
+        if was_stale {
+            return;
+        }
+
+        log_w!(
+            TAG,
+            "Specs from {} are {}ms old, exceeding max_specs_age_ms of {}ms. Policy: {:?}",
+            source,
+            age_ms,
+            max_age_ms,
+            self.policy
+        );
+
+        if self.policy == StaleSpecsPolicy::EmitAlert {
+            event_emitter.emit(SdkEvent::SpecsStale {
+                age_ms,
+                lcut,
+                received_at,
+            });
+        }
+    }
+}
+
+/// Time since a specs source last confirmed the current values. Falls back to `received_at`,
+/// then `lcut`, when nothing has checked in yet.
+fn get_specs_age_ms(data: &SpecStoreData, now: u64) -> Option<u64> {
+    if matches!(
+        data.source,
+        SpecsSource::Uninitialized | SpecsSource::Loading | SpecsSource::NoValues
+    ) {
+        return None;
+    }
+
+    let last_known_fresh_at = data
+        .time_checked_at
+        .or(data.time_received_at)
+        .or(Some(data.values.time).filter(|t| *t != 0))?;
+
+    Some(now.saturating_sub(last_known_fresh_at))
+}
@@ -9,7 +9,7 @@ use crate::evaluation::evaluator_result::{
     result_to_dynamic_config_eval, result_to_experiment_eval, result_to_gate_eval,
     result_to_layer_eval, EvaluatorResult,
 };
-use crate::evaluation::user_agent_parsing::UserAgentParser;
+use crate::evaluation::user_agent_parsing::CachedUserAgentParser;
 use crate::event_logging::event_logger::{EventLogger, ExposureTrigger};
 use crate::event_logging::event_queue::queued_config_expo::EnqueueConfigExpoOp;
 use crate::event_logging::event_queue::queued_experiment_expo::EnqueueExperimentExpoOp;
@@ -21,6 +21,7 @@ use crate::event_logging_adapter::EventLoggingAdapter;
 use crate::event_logging_adapter::StatsigHttpEventLoggingAdapter;
 use crate::gcir::gcir_formatter::GCIRFormatter;
 use crate::hashing::HashUtil;
+use crate::id_lists_adapter::DEFAULT_ID_LIST_SYNC_INTERVAL_MS;
 use crate::initialize_response::InitializeResponse;
 use crate::networking::NetworkError;
 use crate::observability::diagnostics_observer::DiagnosticsObserver;
@@ -33,8 +34,16 @@ use crate::sdk_diagnostics::diagnostics::{ContextType, Diagnostics};
 use crate::sdk_diagnostics::marker::{ActionType, KeyType, Marker};
 use crate::sdk_event_emitter::SdkEventEmitter;
 use crate::spec_store::SpecStore;
-use crate::specs_adapter::{StatsigCustomizedSpecsAdapter, StatsigHttpSpecsAdapter};
+use crate::specs_adapter::{
+    StatsigCustomizedSpecsAdapter, StatsigHttpSpecsAdapter, StatsigSharedSpecsAdapter,
+};
+use crate::specs_export::{self, SpecsExportFilter};
+use crate::specs_staleness::SpecsStalenessMonitor;
 use crate::statsig_err::StatsigErr;
+use crate::statsig_err_code::StatsigErrInfo;
+use crate::statsig_health::{
+    IdListsHealth, LookupLoadState, RuntimeHealth, SpecsHealth, StatsigHealthReport,
+};
 use crate::statsig_metadata::StatsigMetadata;
 use crate::statsig_options::StatsigOptions;
 use crate::statsig_runtime::StatsigRuntime;
@@ -42,7 +51,7 @@ use crate::statsig_type_factories::{
     make_dynamic_config, make_experiment, make_feature_gate, make_layer,
 };
 use crate::statsig_types::{DynamicConfig, Experiment, FeatureGate, Layer, ParameterStore};
-use crate::user::StatsigUserInternal;
+use crate::user::{PreparedUser, StatsigUserInternal};
 use crate::{
     dyn_value, log_d, log_e, log_w, read_lock_or_else, ClientInitResponseOptions,
     GCIRResponseFormat, IdListsAdapter, ObservabilityClient, OpsStatsEventObserver,
@@ -56,6 +65,8 @@ use crate::{
         LayerEvaluationOptions, ParameterStoreEvaluationOptions,
     },
 };
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use chrono::Utc;
 use parking_lot::Mutex;
 use serde::de::DeserializeOwned;
@@ -93,6 +104,7 @@ pub struct Statsig {
     spec_store: Arc<SpecStore>,
     hashing: Arc<HashUtil>,
     gcir_formatter: Arc<GCIRFormatter>,
+    pub(crate) user_agent_parser: Arc<CachedUserAgentParser>,
     statsig_environment: Option<HashMap<String, DynamicValue>>,
     fallback_environment: Mutex<Option<HashMap<String, DynamicValue>>>,
     ops_stats: Arc<OpsStatsForInstance>,
@@ -101,6 +113,7 @@ pub struct Statsig {
     background_tasks_started: Arc<AtomicBool>,
     persistent_values_manager: Option<Arc<PersistentValuesManager>>,
     initialize_details: Mutex<InitializeDetails>,
+    specs_staleness_monitor: Arc<SpecsStalenessMonitor>,
 }
 
 pub struct StatsigContext {
@@ -116,6 +129,17 @@ pub struct StatsigContext {
 pub struct FailureDetails {
     pub reason: String,
     pub error: Option<StatsigErr>,
+    pub error_info: Option<StatsigErrInfo>,
+}
+
+impl FailureDetails {
+    pub fn new(reason: &str, error: Option<StatsigErr>) -> Self {
+        FailureDetails {
+            reason: reason.to_string(),
+            error_info: error.as_ref().map(StatsigErr::to_info),
+            error,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -151,10 +175,7 @@ impl InitializeDetails {
             is_config_spec_ready: false,
             is_id_list_ready: None,
             source: SpecsSource::Uninitialized,
-            failure_details: Some(FailureDetails {
-                reason: reason.to_string(),
-                error,
-            }),
+            failure_details: Some(FailureDetails::new(reason, error)),
             spec_source_api: None,
         }
     }
@@ -215,12 +236,19 @@ impl Statsig {
             &options.observability_client,
         );
 
-        let spec_store = Arc::new(SpecStore::new(
-            sdk_key,
-            hashing.sha256(sdk_key),
-            statsig_runtime.clone(),
-            options.data_store.clone(),
-        ));
+        let spec_store = Arc::new(
+            SpecStore::new(
+                sdk_key,
+                hashing.sha256(sdk_key),
+                statsig_runtime.clone(),
+                options.data_store.clone(),
+            )
+            // shared snapshots are always read in place, see StatsigSharedSpecsAdapter
+            .with_zero_copy_specs(
+                options.enable_zero_copy_specs.unwrap_or(false)
+                    || options.shared_specs_path.is_some(),
+            ),
+        );
 
         let environment = options
             .environment
@@ -235,14 +263,24 @@ impl Statsig {
 
         StatsigMetadata::update_service_name(options.service_name.clone());
 
+        let event_emitter = SdkEventEmitter::default();
+        let specs_staleness_monitor = Arc::new(SpecsStalenessMonitor::new(&options));
+        specs_staleness_monitor.spawn(&statsig_runtime, &spec_store, &ops_stats, &event_emitter);
+
+        let user_agent_parser = Arc::new(CachedUserAgentParser::new(&options));
+        let gcir_formatter = Arc::new(GCIRFormatter::new(
+            &spec_store,
+            &override_adapter,
+            &user_agent_parser,
+            &ops_stats,
+            &options,
+        ));
+
         Statsig {
             sdk_key: sdk_key.to_string(),
             options,
-            gcir_formatter: Arc::new(GCIRFormatter::new(
-                &spec_store,
-                &override_adapter,
-                &ops_stats,
-            )),
+            gcir_formatter,
+            user_agent_parser,
             hashing,
             statsig_environment: environment,
             fallback_environment: Mutex::new(None),
@@ -259,7 +297,8 @@ impl Statsig {
             background_tasks_started: Arc::new(AtomicBool::new(false)),
             persistent_values_manager,
             initialize_details: Mutex::new(InitializeDetails::default()),
-            event_emitter: SdkEventEmitter::default(),
+            event_emitter,
+            specs_staleness_monitor,
         }
     }
 
@@ -483,7 +522,9 @@ impl Statsig {
         let mut error_message = None;
         let mut id_list_ready = None;
 
-        let init_country_lookup = if !self.options.disable_country_lookup.unwrap_or_default() {
+        let use_bundled_country_lookup = !self.options.disable_country_lookup.unwrap_or_default()
+            && self.options.geo_ip_provider.is_none();
+        let init_country_lookup = if use_bundled_country_lookup {
             Some(self.statsig_runtime.spawn(INIT_IP_TAG, |_| async {
                 CountryLookup::load_country_lookup();
             }))
@@ -492,8 +533,9 @@ impl Statsig {
         };
 
         let init_ua = if !self.options.disable_user_agent_parsing.unwrap_or_default() {
-            Some(self.statsig_runtime.spawn(INIT_UA_TAG, |_| async {
-                UserAgentParser::load_parser();
+            let user_agent_parser = self.user_agent_parser.clone();
+            Some(self.statsig_runtime.spawn(INIT_UA_TAG, |_| async move {
+                user_agent_parser.load_parser();
             }))
         } else {
             None
@@ -624,10 +666,9 @@ impl Statsig {
             if let Some(StatsigErr::NetworkError(NetworkError::DisableNetworkOn(_))) = error {
                 None
             } else {
-                error.as_ref().map(|e| FailureDetails {
-                    reason: e.to_string(),
-                    error: Some(e.clone()),
-                })
+                error
+                    .as_ref()
+                    .map(|e| FailureDetails::new(&e.to_string(), Some(e.clone())))
             };
 
         InitializeDetails {
@@ -647,10 +688,12 @@ impl Statsig {
             is_config_spec_ready: false,
             is_id_list_ready: None,
             source: SpecsSource::Uninitialized,
-            failure_details: Some(FailureDetails {
-                reason: "Initialization timed out".to_string(),
-                error: None,
-            }),
+            failure_details: Some(FailureDetails::new(
+                "Initialization timed out",
+                Some(StatsigErr::Timeout(format!(
+                    "Initialization timed out after {timeout_ms}ms"
+                ))),
+            )),
             duration: timeout_ms as f64,
             spec_source_api: None,
         }
@@ -791,18 +834,44 @@ impl Statsig {
         options: &ClientInitResponseOptions,
     ) -> String {
         let user_internal = self.internalize_user(user);
-        let response = match options.response_format {
+        let mut response = match options.response_format {
             Some(GCIRResponseFormat::InitializeWithSecondaryExposureMapping) => {
                 json!(self
                     .gcir_formatter
                     .get_as_v2_format(user_internal, &self.hashing, options))
             }
+            Some(GCIRResponseFormat::InitializeCompactBinary) => {
+                let bytes =
+                    self.gcir_formatter
+                        .get_as_binary_format(user_internal, &self.hashing, options);
+                return BASE64_STANDARD.encode(bytes);
+            }
             _ => json!(self
                 .gcir_formatter
                 .get_as_v1_format(user_internal, &self.hashing, options)),
         };
 
-        json!(response).to_string()
+        self.gcir_formatter.sign_response(&mut response, options);
+        response.to_string()
+    }
+
+    /// Same as `get_client_init_response_with_options_as_string`, but `InitializeCompactBinary`
+    /// responses are returned as raw bytes instead of base64. Use `GCIRBinaryCodec` to decode them.
+    pub fn get_client_init_response_with_options_as_bytes(
+        &self,
+        user: &StatsigUser,
+        options: &ClientInitResponseOptions,
+    ) -> Vec<u8> {
+        match options.response_format {
+            Some(GCIRResponseFormat::InitializeCompactBinary) => {
+                let user_internal = self.internalize_user(user);
+                self.gcir_formatter
+                    .get_as_binary_format(user_internal, &self.hashing, options)
+            }
+            _ => self
+                .get_client_init_response_with_options_as_string(user, options)
+                .into_bytes(),
+        }
     }
 
     pub fn get_string_parameter_from_store(
@@ -1036,6 +1105,8 @@ impl Statsig {
                 &self.hashing,
                 data.values.app_id.as_ref(),
                 self.override_adapter.as_ref(),
+                self.options.geo_ip_provider.as_ref(),
+                &self.user_agent_parser,
             ),
             cmab_name,
         )
@@ -1340,6 +1411,75 @@ impl Statsig {
 // -------------------------
 
 impl Statsig {
+    /// Summarizes the current state of this instance. See [`StatsigHealthReport`].
+    pub fn health(&self) -> StatsigHealthReport {
+        let now = Utc::now().timestamp_millis() as u64;
+        let is_initialized = self.is_initialized();
+
+        let (specs, id_lists_count, id_lists_synced_at) =
+            match self.spec_store.data.try_read_for(Duration::from_secs(5)) {
+                Some(data) => {
+                    let lcut = Some(data.values.time).filter(|t| *t != 0);
+                    let specs = SpecsHealth {
+                        source: data.source.clone(),
+                        source_api: data.source_api.clone(),
+                        lcut,
+                        checksum: data.values.checksum.clone(),
+                        age_ms: lcut.map(|t| now.saturating_sub(t)),
+                        received_at: data.time_received_at,
+                    };
+                    (specs, data.id_lists.len(), data.id_lists_time_received_at)
+                }
+                None => {
+                    log_e!(TAG, "Failed to acquire read lock for spec store data");
+                    let specs = SpecsHealth {
+                        source: SpecsSource::Error,
+                        source_api: None,
+                        lcut: None,
+                        checksum: None,
+                        age_ms: None,
+                        received_at: None,
+                    };
+                    (specs, 0, None)
+                }
+            };
+
+        let id_lists_sync_interval_ms = u64::from(
+            self.options
+                .id_lists_sync_interval_ms
+                .unwrap_or(DEFAULT_ID_LIST_SYNC_INTERVAL_MS),
+        );
+        let id_lists = IdListsHealth {
+            enabled: self.id_lists_adapter.inner.is_some(),
+            is_current: id_lists_synced_at
+                .is_some_and(|t| now.saturating_sub(t) <= id_lists_sync_interval_ms * 2),
+            list_count: id_lists_count,
+            last_synced_at: id_lists_synced_at,
+        };
+
+        let active_tasks = self.statsig_runtime.get_active_task_tags();
+
+        StatsigHealthReport {
+            is_ready: is_initialized && specs.lcut.is_some(),
+            is_initialized,
+            specs,
+            id_lists,
+            country_lookup: LookupLoadState::from_flags(
+                self.options.disable_country_lookup.unwrap_or_default(),
+                CountryLookup::is_loaded() || self.options.geo_ip_provider.is_some(),
+            ),
+            user_agent_parser: LookupLoadState::from_flags(
+                self.options.disable_user_agent_parsing.unwrap_or_default(),
+                self.user_agent_parser.is_loaded(),
+            ),
+            event_logger: self.event_logger.get_health(),
+            runtime: RuntimeHealth {
+                active_task_count: active_tasks.len(),
+                active_tasks,
+            },
+        }
+    }
+
     pub fn get_feature_gate_list(&self) -> Vec<String> {
         let data = read_lock_or_else!(self.spec_store.data, {
             log_error_to_statsig_and_console!(
@@ -1438,6 +1578,35 @@ impl Statsig {
 
         data.values.layer_configs.unperformant_keys()
     }
+
+    /// Serializes the current specs, restricted to the entities selected by `filter` and
+    /// everything they depend on. The result can seed another instance via
+    /// `StatsigBootstrapSpecsAdapter`.
+    pub fn export_specs(&self, filter: &SpecsExportFilter) -> Result<String, StatsigErr> {
+        let data = read_lock_or_else!(self.spec_store.data, {
+            return Err(StatsigErr::LockFailure(
+                "Failed to acquire read lock for spec store data".to_string(),
+            ));
+        });
+
+        specs_export::export_specs(&data.values, filter)
+    }
+
+    /// Serves this instance's specs over the forward proxy gRPC API until shutdown, so other
+    /// instances can point a `NetworkGrpcWebsocket` spec adapter at `address`.
+    #[cfg(feature = "with_grpc")]
+    pub fn start_forward_proxy_server(
+        &self,
+        address: std::net::SocketAddr,
+    ) -> Result<(), StatsigErr> {
+        crate::forward_proxy_server::spawn_forward_proxy_server(
+            &self.sdk_key,
+            address,
+            &self.spec_store,
+            &self.statsig_runtime,
+            &self.ops_stats,
+        )
+    }
 }
 
 // -------------------------
@@ -1825,6 +1994,13 @@ impl Statsig {
             return make_empty_result(EvaluationDetails::unrecognized_no_data());
         });
 
+        if let Some(stale_details) = self
+            .specs_staleness_monitor
+            .get_stale_evaluation_details(&data)
+        {
+            return make_empty_result(stale_details);
+        }
+
         let app_id = data.values.app_id.as_ref();
         let mut context = EvaluatorContext::new(
             user_internal,
@@ -1832,6 +2008,8 @@ impl Statsig {
             &self.hashing,
             app_id,
             self.override_adapter.as_ref(),
+            self.options.geo_ip_provider.as_ref(),
+            &self.user_agent_parser,
         );
 
         match Evaluator::evaluate_with_details(&mut context, spec_name, spec_type) {
@@ -1915,7 +2093,7 @@ impl Statsig {
             layer_name,
             |eval_details| {
                 make_layer(
-                    user_internal.to_loggable(),
+                    &user_internal,
                     layer_name,
                     None,
                     eval_details,
@@ -1928,7 +2106,7 @@ impl Statsig {
                 let event_logger_ptr = Arc::downgrade(&self.event_logger);
 
                 make_layer(
-                    user_internal.to_loggable(),
+                    &user_internal,
                     layer_name,
                     Some(evaluation),
                     eval_details,
@@ -1968,6 +2146,11 @@ impl Statsig {
         layer
     }
 
+    /// Precomputes the values derived from `user` for repeated evaluations, see [`PreparedUser`].
+    pub fn prepare_user(&self, user: &StatsigUser) -> PreparedUser {
+        PreparedUser::new(user, self)
+    }
+
     fn internalize_user<'s, 'u>(&'s self, user: &'u StatsigUser) -> StatsigUserInternal<'s, 'u> {
         StatsigUserInternal::new(user, Some(self))
     }
@@ -2091,6 +2274,15 @@ fn initialize_specs_adapter(
         };
     }
 
+    if let Some(path) = &options.shared_specs_path {
+        let adapter = Arc::new(StatsigSharedSpecsAdapter::new(sdk_key, path, Some(options)));
+
+        return SpecsAdapterHousing {
+            inner: adapter,
+            as_default_adapter: None,
+        };
+    }
+
     let adapter = Arc::new(StatsigHttpSpecsAdapter::new(sdk_key, Some(options), None));
 
     SpecsAdapterHousing {
@@ -18,6 +18,7 @@ pub enum StatsigErr {
     UnstartedAdapter(String),
     IdListsAdapterFailedToInsertIdList,
     SpecsAdapterSkipPoll(String),
+    SpecsDeltaMismatch(String),
     DataStoreFailure(String),
 
     // Network
@@ -44,6 +45,7 @@ pub enum StatsigErr {
 
     // Initialization / Shutdown
     InitializationError(String),
+    Timeout(String),
     ShutdownFailure(String),
 
     // Task Scheduler
@@ -76,6 +78,9 @@ impl Display for StatsigErr {
             StatsigErr::SpecsAdapterSkipPoll(adapter_name) => {
                 write!(f, "{adapter_name} skips scheduling polling")
             }
+            StatsigErr::SpecsDeltaMismatch(message) => {
+                write!(f, "Specs delta does not apply to current values: {message}")
+            }
             StatsigErr::DataStoreFailure(message) => write!(f, "DataStore Error: {message}"),
 
             StatsigErr::NetworkError(error) => write!(f, "NetworkError|{error}"),
@@ -103,6 +108,7 @@ impl Display for StatsigErr {
             StatsigErr::InitializationError(message) => {
                 write!(f, "Initialization Error: {message}")
             }
+            StatsigErr::Timeout(msg) => write!(f, "{msg}"),
             StatsigErr::ShutdownFailure(e) => write!(f, "Shutdown failure: {e}"),
 
             StatsigErr::ScheduleFailure(e) => write!(f, "Failed to schedule task: {e}"),
@@ -126,6 +132,7 @@ impl StatsigErr {
             StatsigErr::UnstartedAdapter(_) => "UnstartedAdapter",
             StatsigErr::IdListsAdapterFailedToInsertIdList => "IdListsAdapterFailedToInsertIdList",
             StatsigErr::SpecsAdapterSkipPoll(_) => "SpecsAdapterSkipPoll",
+            StatsigErr::SpecsDeltaMismatch(_) => "SpecsDeltaMismatch",
             StatsigErr::DataStoreFailure(_) => "DataStoreFailure",
 
             StatsigErr::NetworkError(e) => e.name(),
@@ -145,6 +152,7 @@ impl StatsigErr {
             StatsigErr::EvaluationError(_) => "EvaluationError",
 
             StatsigErr::InitializationError(_) => "InitializationError",
+            StatsigErr::Timeout(_) => "Timeout",
             StatsigErr::ShutdownFailure(_) => "ShutdownFailure",
 
             StatsigErr::ScheduleFailure(_) => "ScheduleFailure",
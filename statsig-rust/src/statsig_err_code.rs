@@ -0,0 +1,388 @@
+use crate::{networking::NetworkError, StatsigErr};
+use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+
+/// Broad grouping of a [`StatsigErrCode`]. Every binding exposes the same string values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatsigErrCategory {
+    Custom,
+    Lock,
+    System,
+    Adapter,
+    Network,
+    Parse,
+    Compression,
+    Filesystem,
+    Logging,
+    Evaluation,
+    Initialization,
+    Timeout,
+    Shutdown,
+    ClientInitResponse,
+}
+
+impl StatsigErrCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatsigErrCategory::Custom => "custom",
+            StatsigErrCategory::Lock => "lock",
+            StatsigErrCategory::System => "system",
+            StatsigErrCategory::Adapter => "adapter",
+            StatsigErrCategory::Network => "network",
+            StatsigErrCategory::Parse => "parse",
+            StatsigErrCategory::Compression => "compression",
+            StatsigErrCategory::Filesystem => "filesystem",
+            StatsigErrCategory::Logging => "logging",
+            StatsigErrCategory::Evaluation => "evaluation",
+            StatsigErrCategory::Initialization => "initialization",
+            StatsigErrCategory::Timeout => "timeout",
+            StatsigErrCategory::Shutdown => "shutdown",
+            StatsigErrCategory::ClientInitResponse => "client_init_response",
+        }
+    }
+}
+
+/// Stable identifier attached to every [`StatsigErr`].
+///
+/// Both the numeric value and the string name are part of the public contract shared by all
+/// language bindings. Codes are grouped by category in blocks of 100 and are never renumbered
+/// or reused; new errors get new codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum StatsigErrCode {
+    /// A free-form error raised by a caller-provided integration
+    Custom = 100,
+
+    /// A lock could not be acquired within its timeout
+    LockFailure = 200,
+
+    /// A background thread could not be spawned or joined
+    ThreadFailure = 300,
+    /// Evaluation recursed past the maximum depth
+    StackOverflow = 301,
+    /// The shared Statsig instance could not be created or accessed
+    SharedInstanceFailure = 302,
+    /// The configured ObservabilityClient failed
+    ObservabilityClientFailure = 303,
+    /// A task could not be scheduled on the StatsigRuntime
+    ScheduleFailure = 304,
+
+    /// An adapter was used before it was started
+    UnstartedAdapter = 400,
+    /// An ID list could not be stored by the IdListsAdapter
+    IdListsAdapterInsertFailure = 401,
+    /// A specs adapter declined to schedule background polling
+    SpecsAdapterSkipPoll = 402,
+    /// The configured DataStore failed
+    DataStoreFailure = 403,
+    /// A specs delta was based on values other than the current ones
+    SpecsDeltaMismatch = 404,
+
+    /// A request was attempted after the network client was shut down
+    NetworkShutdown = 500,
+    /// A request was attempted while `disable_network` is set
+    NetworkDisabled = 501,
+    /// A request body could not be serialized
+    NetworkSerializationFailure = 502,
+    /// A request failed
+    NetworkRequestFailed = 503,
+    /// A request failed after exhausting all retries
+    NetworkRetriesExhausted = 504,
+    /// A request failed with a status that is not retried
+    NetworkRequestNotRetryable = 505,
+    /// The gRPC streaming connection failed
+    GrpcFailure = 506,
+
+    /// A value could not be serialized
+    SerializationFailure = 600,
+    /// A payload could not be parsed as JSON
+    JsonParseFailure = 601,
+
+    /// Zstd dictionary compression failed
+    ZstdDictCompressionFailure = 700,
+    /// Gzip compression or decompression failed
+    GzipFailure = 701,
+    /// Zstd compression or decompression failed
+    ZstdFailure = 702,
+
+    /// A local file could not be read or written
+    FileFailure = 800,
+
+    /// Events could not be logged
+    LogEventFailure = 900,
+
+    /// Evaluation failed
+    EvaluationFailure = 1000,
+
+    /// Initialization failed
+    InitializationFailure = 1100,
+
+    /// An operation did not complete within its timeout
+    Timeout = 1200,
+
+    /// Shutdown did not complete cleanly
+    ShutdownFailure = 1300,
+    /// The task scheduler could not be shut down
+    TaskShutdownFailure = 1301,
+
+    /// A client initialize response could not be generated
+    ClientInitResponseFailure = 1400,
+}
+
+impl StatsigErrCode {
+    pub fn as_u16(&self) -> u16 {
+        *self as u16
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatsigErrCode::Custom => "CUSTOM",
+            StatsigErrCode::LockFailure => "LOCK_FAILURE",
+            StatsigErrCode::ThreadFailure => "THREAD_FAILURE",
+            StatsigErrCode::StackOverflow => "STACK_OVERFLOW",
+            StatsigErrCode::SharedInstanceFailure => "SHARED_INSTANCE_FAILURE",
+            StatsigErrCode::ObservabilityClientFailure => "OBSERVABILITY_CLIENT_FAILURE",
+            StatsigErrCode::ScheduleFailure => "SCHEDULE_FAILURE",
+            StatsigErrCode::UnstartedAdapter => "UNSTARTED_ADAPTER",
+            StatsigErrCode::IdListsAdapterInsertFailure => "ID_LISTS_ADAPTER_INSERT_FAILURE",
+            StatsigErrCode::SpecsAdapterSkipPoll => "SPECS_ADAPTER_SKIP_POLL",
+            StatsigErrCode::DataStoreFailure => "DATA_STORE_FAILURE",
+            StatsigErrCode::SpecsDeltaMismatch => "SPECS_DELTA_MISMATCH",
+            StatsigErrCode::NetworkShutdown => "NETWORK_SHUTDOWN",
+            StatsigErrCode::NetworkDisabled => "NETWORK_DISABLED",
+            StatsigErrCode::NetworkSerializationFailure => "NETWORK_SERIALIZATION_FAILURE",
+            StatsigErrCode::NetworkRequestFailed => "NETWORK_REQUEST_FAILED",
+            StatsigErrCode::NetworkRetriesExhausted => "NETWORK_RETRIES_EXHAUSTED",
+            StatsigErrCode::NetworkRequestNotRetryable => "NETWORK_REQUEST_NOT_RETRYABLE",
+            StatsigErrCode::GrpcFailure => "GRPC_FAILURE",
+            StatsigErrCode::SerializationFailure => "SERIALIZATION_FAILURE",
+            StatsigErrCode::JsonParseFailure => "JSON_PARSE_FAILURE",
+            StatsigErrCode::ZstdDictCompressionFailure => "ZSTD_DICT_COMPRESSION_FAILURE",
+            StatsigErrCode::GzipFailure => "GZIP_FAILURE",
+            StatsigErrCode::ZstdFailure => "ZSTD_FAILURE",
+            StatsigErrCode::FileFailure => "FILE_FAILURE",
+            StatsigErrCode::LogEventFailure => "LOG_EVENT_FAILURE",
+            StatsigErrCode::EvaluationFailure => "EVALUATION_FAILURE",
+            StatsigErrCode::InitializationFailure => "INITIALIZATION_FAILURE",
+            StatsigErrCode::Timeout => "TIMEOUT",
+            StatsigErrCode::ShutdownFailure => "SHUTDOWN_FAILURE",
+            StatsigErrCode::TaskShutdownFailure => "TASK_SHUTDOWN_FAILURE",
+            StatsigErrCode::ClientInitResponseFailure => "CLIENT_INIT_RESPONSE_FAILURE",
+        }
+    }
+
+    pub fn category(&self) -> StatsigErrCategory {
+        match self {
+            StatsigErrCode::Custom => StatsigErrCategory::Custom,
+
+            StatsigErrCode::LockFailure => StatsigErrCategory::Lock,
+
+            StatsigErrCode::ThreadFailure
+            | StatsigErrCode::StackOverflow
+            | StatsigErrCode::SharedInstanceFailure
+            | StatsigErrCode::ObservabilityClientFailure
+            | StatsigErrCode::ScheduleFailure => StatsigErrCategory::System,
+
+            StatsigErrCode::UnstartedAdapter
+            | StatsigErrCode::IdListsAdapterInsertFailure
+            | StatsigErrCode::SpecsAdapterSkipPoll
+            | StatsigErrCode::DataStoreFailure
+            | StatsigErrCode::SpecsDeltaMismatch => StatsigErrCategory::Adapter,
+
+            StatsigErrCode::NetworkShutdown
+            | StatsigErrCode::NetworkDisabled
+            | StatsigErrCode::NetworkSerializationFailure
+            | StatsigErrCode::NetworkRequestFailed
+            | StatsigErrCode::NetworkRetriesExhausted
+            | StatsigErrCode::NetworkRequestNotRetryable
+            | StatsigErrCode::GrpcFailure => StatsigErrCategory::Network,
+
+            StatsigErrCode::SerializationFailure | StatsigErrCode::JsonParseFailure => {
+                StatsigErrCategory::Parse
+            }
+
+            StatsigErrCode::ZstdDictCompressionFailure
+            | StatsigErrCode::GzipFailure
+            | StatsigErrCode::ZstdFailure => StatsigErrCategory::Compression,
+
+            StatsigErrCode::FileFailure => StatsigErrCategory::Filesystem,
+
+            StatsigErrCode::LogEventFailure => StatsigErrCategory::Logging,
+
+            StatsigErrCode::EvaluationFailure => StatsigErrCategory::Evaluation,
+
+            StatsigErrCode::InitializationFailure => StatsigErrCategory::Initialization,
+
+            StatsigErrCode::Timeout => StatsigErrCategory::Timeout,
+
+            StatsigErrCode::ShutdownFailure | StatsigErrCode::TaskShutdownFailure => {
+                StatsigErrCategory::Shutdown
+            }
+
+            StatsigErrCode::ClientInitResponseFailure => StatsigErrCategory::ClientInitResponse,
+        }
+    }
+}
+
+impl Serialize for StatsigErrCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for StatsigErrCategory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Structured details pulled out of a [`StatsigErr`], where available.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StatsigErrContext {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub adapter_name: Option<String>,
+}
+
+impl StatsigErrContext {
+    /// Flattens the context into string pairs for bindings without structured types, using the
+    /// same keys as the serialized form.
+    pub fn to_map(&self) -> HashMap<String, String> {
+        let mut map = HashMap::new();
+        if let Some(url) = &self.url {
+            map.insert("url".to_string(), url.clone());
+        }
+        if let Some(status_code) = self.status_code {
+            map.insert("status_code".to_string(), status_code.to_string());
+        }
+        if let Some(adapter_name) = &self.adapter_name {
+            map.insert("adapter_name".to_string(), adapter_name.clone());
+        }
+        map
+    }
+}
+
+/// A binding-friendly snapshot of a [`StatsigErr`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StatsigErrInfo {
+    pub code: StatsigErrCode,
+    pub code_value: u16,
+    pub category: StatsigErrCategory,
+    pub message: String,
+    pub context: StatsigErrContext,
+}
+
+impl StatsigErr {
+    pub fn code(&self) -> StatsigErrCode {
+        match self {
+            StatsigErr::CustomError(_) => StatsigErrCode::Custom,
+
+            StatsigErr::LockFailure(_) => StatsigErrCode::LockFailure,
+            StatsigErr::ThreadFailure(_) => StatsigErrCode::ThreadFailure,
+            StatsigErr::StackOverflowError => StatsigErrCode::StackOverflow,
+            StatsigErr::SharedInstanceFailure(_) => StatsigErrCode::SharedInstanceFailure,
+            StatsigErr::ObservabilityClientFailure(_) => StatsigErrCode::ObservabilityClientFailure,
+
+            StatsigErr::UnstartedAdapter(_) => StatsigErrCode::UnstartedAdapter,
+            StatsigErr::IdListsAdapterFailedToInsertIdList => {
+                StatsigErrCode::IdListsAdapterInsertFailure
+            }
+            StatsigErr::SpecsAdapterSkipPoll(_) => StatsigErrCode::SpecsAdapterSkipPoll,
+            StatsigErr::SpecsDeltaMismatch(_) => StatsigErrCode::SpecsDeltaMismatch,
+            StatsigErr::DataStoreFailure(_) => StatsigErrCode::DataStoreFailure,
+
+            StatsigErr::NetworkError(e) => match e {
+                NetworkError::ShutdownError(_) => StatsigErrCode::NetworkShutdown,
+                NetworkError::DisableNetworkOn(_) => StatsigErrCode::NetworkDisabled,
+                NetworkError::SerializationError(_, _) => {
+                    StatsigErrCode::NetworkSerializationFailure
+                }
+                NetworkError::RequestFailed(_, _, _) => StatsigErrCode::NetworkRequestFailed,
+                NetworkError::RetriesExhausted(_, _, _, _) => {
+                    StatsigErrCode::NetworkRetriesExhausted
+                }
+                NetworkError::RequestNotRetryable(_, _, _) => {
+                    StatsigErrCode::NetworkRequestNotRetryable
+                }
+            },
+            StatsigErr::GrpcError(_) => StatsigErrCode::GrpcFailure,
+
+            StatsigErr::SerializationError(_) => StatsigErrCode::SerializationFailure,
+            StatsigErr::JsonParseError(_, _) => StatsigErrCode::JsonParseFailure,
+
+            StatsigErr::ZstdDictCompressionError(_) => StatsigErrCode::ZstdDictCompressionFailure,
+            StatsigErr::GzipError(_) => StatsigErrCode::GzipFailure,
+            StatsigErr::ZstdError(_) => StatsigErrCode::ZstdFailure,
+
+            StatsigErr::FileError(_) => StatsigErrCode::FileFailure,
+
+            StatsigErr::LogEventError(_) => StatsigErrCode::LogEventFailure,
+
+            StatsigErr::EvaluationError(_) => StatsigErrCode::EvaluationFailure,
+
+            StatsigErr::InitializationError(_) => StatsigErrCode::InitializationFailure,
+            StatsigErr::Timeout(_) => StatsigErrCode::Timeout,
+            StatsigErr::ShutdownFailure(_) => StatsigErrCode::ShutdownFailure,
+
+            StatsigErr::ScheduleFailure(_) => StatsigErrCode::ScheduleFailure,
+            StatsigErr::TaskShutdownFailure => StatsigErrCode::TaskShutdownFailure,
+            StatsigErr::GCIRError(_) => StatsigErrCode::ClientInitResponseFailure,
+        }
+    }
+
+    pub fn category(&self) -> StatsigErrCategory {
+        self.code().category()
+    }
+
+    pub fn context(&self) -> StatsigErrContext {
+        match self {
+            StatsigErr::NetworkError(e) => match e {
+                NetworkError::ShutdownError(url)
+                | NetworkError::DisableNetworkOn(url)
+                | NetworkError::SerializationError(url, _) => StatsigErrContext {
+                    url: Some(url.clone()),
+                    ..StatsigErrContext::default()
+                },
+                NetworkError::RequestFailed(url, status, _)
+                | NetworkError::RetriesExhausted(url, status, _, _)
+                | NetworkError::RequestNotRetryable(url, status, _) => StatsigErrContext {
+                    url: Some(url.clone()),
+                    // 0 is used internally when no response was received
+                    status_code: Some(*status).filter(|s| *s != 0),
+                    ..StatsigErrContext::default()
+                },
+            },
+            StatsigErr::SpecsAdapterSkipPoll(adapter_name) => StatsigErrContext {
+                adapter_name: Some(adapter_name.clone()),
+                ..StatsigErrContext::default()
+            },
+            StatsigErr::DataStoreFailure(_) => StatsigErrContext {
+                adapter_name: Some("DataStore".to_string()),
+                ..StatsigErrContext::default()
+            },
+            StatsigErr::GrpcError(_) => StatsigErrContext {
+                adapter_name: Some("StatsigGrpcSpecsAdapter".to_string()),
+                ..StatsigErrContext::default()
+            },
+            StatsigErr::ObservabilityClientFailure(_) => StatsigErrContext {
+                adapter_name: Some("ObservabilityClient".to_string()),
+                ..StatsigErrContext::default()
+            },
+            _ => StatsigErrContext::default(),
+        }
+    }
+
+    pub fn to_info(&self) -> StatsigErrInfo {
+        let code = self.code();
+        StatsigErrInfo {
+            code,
+            code_value: code.as_u16(),
+            category: code.category(),
+            message: self.to_string(),
+            context: self.context(),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::SpecsSource;
+use serde::Serialize;
+
+/// A point-in-time summary of an SDK instance, returned by [`crate::Statsig::health`].
+///
+/// Intended for readiness probes and debug endpoints. Gathering it takes a few short read locks
+/// and does not touch the network.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatsigHealthReport {
+    /// True once `initialize` has succeeded and specs with a non-zero lcut are loaded.
+    pub is_ready: bool,
+    pub is_initialized: bool,
+    pub specs: SpecsHealth,
+    pub id_lists: IdListsHealth,
+    pub country_lookup: LookupLoadState,
+    pub user_agent_parser: LookupLoadState,
+    pub event_logger: EventLoggerHealth,
+    pub runtime: RuntimeHealth,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SpecsHealth {
+    pub source: SpecsSource,
+    pub source_api: Option<String>,
+    pub lcut: Option<u64>,
+    pub checksum: Option<String>,
+    /// Milliseconds since `lcut`.
+    pub age_ms: Option<u64>,
+    /// When the current values were last applied to the SpecStore.
+    pub received_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IdListsHealth {
+    pub enabled: bool,
+    /// True if a sync has completed within the last two sync intervals.
+    pub is_current: bool,
+    pub list_count: usize,
+    pub last_synced_at: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LookupLoadState {
+    Disabled,
+    NotLoaded,
+    Loaded,
+}
+
+impl LookupLoadState {
+    pub(crate) fn from_flags(is_disabled: bool, is_loaded: bool) -> Self {
+        match (is_disabled, is_loaded) {
+            (true, _) => LookupLoadState::Disabled,
+            (false, true) => LookupLoadState::Loaded,
+            (false, false) => LookupLoadState::NotLoaded,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EventLoggerHealth {
+    /// Approximate number of events waiting to be flushed, including requeued batches.
+    pub queue_depth: usize,
+    /// Events dropped since startup, due to queue limits or failed flushes.
+    pub dropped_events_count: u64,
+    /// Flush failures since the last successful scheduled flush.
+    pub consecutive_flush_failures: u64,
+    pub flush_interval_ms: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RuntimeHealth {
+    pub active_task_count: usize,
+    /// Tags of the tasks currently running on the StatsigRuntime, sorted.
+    pub active_tasks: Vec<String>,
+}
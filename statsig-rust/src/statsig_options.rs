@@ -3,14 +3,17 @@ use serde::{Serialize, Serializer};
 
 use crate::data_store_interface::DataStoreTrait;
 use crate::evaluation::dynamic_value::DynamicValue;
+use crate::evaluation::user_agent_parsing::{UserAgentParserProvider, UserAgentParserType};
 use crate::event_logging_adapter::EventLoggingAdapter;
+use crate::geo_ip::geo_ip_provider_trait::GeoIpProvider;
 use crate::id_lists_adapter::IdListsAdapter;
 use crate::networking::proxy_config::ProxyConfig;
 use crate::output_logger::{LogLevel, OutputLogProvider};
 use crate::persistent_storage::persistent_storage_trait::PersistentStorage;
+use crate::user::ComputedUserAttributeProvider;
 use crate::{
-    log_d, log_w, serialize_if_not_none, ConfigCompressionMode, ObservabilityClient,
-    OverrideAdapter, SpecAdapterConfig, SpecsAdapter,
+    log_d, log_w, serialize_if_not_none, ConfigCompressionMode, ExposureDedupeStore,
+    ObservabilityClient, OverrideAdapter, SpecAdapterConfig, SpecsAdapter, StaleSpecsPolicy,
 };
 use std::collections::HashMap;
 use std::fmt;
@@ -29,9 +32,12 @@ pub struct StatsigOptions {
     pub disable_network: Option<bool>, // Disable all out-going network including get configs, log_events...
     pub disable_user_agent_parsing: Option<bool>,
 
+    pub enable_delta_specs_updates: Option<bool>, // Request SpecsResponseDelta updates from a forward proxy specs_url
     pub enable_id_lists: Option<bool>,
+    pub enable_zero_copy_specs: Option<bool>, // Borrow return values from the received specs payload instead of copying them
     pub environment: Option<String>,
     pub config_compression_mode: Option<ConfigCompressionMode>,
+    pub computed_user_attributes: Option<HashMap<String, Arc<dyn ComputedUserAttributeProvider>>>, // Derived user fields, computed when a user_field condition needs them
 
     pub event_logging_adapter: Option<Arc<dyn EventLoggingAdapter>>,
 
@@ -39,8 +45,11 @@ pub struct StatsigOptions {
     pub event_logging_flush_interval_ms: Option<u32>,
     pub event_logging_max_pending_batch_queue_size: Option<u32>,
     pub event_logging_max_queue_size: Option<u32>,
+    pub exposure_dedupe_store: Option<Arc<dyn ExposureDedupeStore>>, // Share exposure dedupe across processes
 
     pub fallback_to_statsig_api: Option<bool>,
+    pub gcir_cache_max_entries: Option<u32>, // Caches client initialize responses by the user fields the specs use. Disabled when unset or 0
+    pub geo_ip_provider: Option<Arc<dyn GeoIpProvider>>, // Resolves ip_based conditions instead of the bundled country table
     pub global_custom_fields: Option<HashMap<String, DynamicValue>>,
 
     pub id_lists_adapter: Option<Arc<dyn IdListsAdapter>>,
@@ -55,12 +64,20 @@ pub struct StatsigOptions {
     pub override_adapter: Option<Arc<dyn OverrideAdapter>>,
     pub persistent_storage: Option<Arc<dyn PersistentStorage>>,
     pub service_name: Option<String>,
+    pub shared_specs_path: Option<String>, // Share one specs sync between the processes on this host through files at this path, see StatsigSharedSpecsAdapter. Turns on enable_zero_copy_specs
+
+    pub max_specs_age_ms: Option<u64>, // Applies stale_specs_policy once the current specs are older than this
+    pub stale_specs_policy: Option<StaleSpecsPolicy>,
 
     pub spec_adapters_config: Option<Vec<SpecAdapterConfig>>, // Specs to customized spec adapter, order matters, reflecting priority of trying
     pub specs_adapter: Option<Arc<dyn SpecsAdapter>>,
     pub specs_sync_interval_ms: Option<u32>,
     pub specs_url: Option<String>,
 
+    pub user_agent_cache_max_entries: Option<u32>, // Parsed user agents kept in memory. Defaults to 1000, 0 disables
+    pub user_agent_parser_provider: Option<Arc<dyn UserAgentParserProvider>>, // Parses user agents instead of the bundled parsers
+    pub user_agent_parser_type: Option<UserAgentParserType>, // Bundled parser to use. Falls back to STATSIG_USE_EXPERIMENTAL_UAPARSER
+
     pub wait_for_country_lookup_init: Option<bool>,
     pub wait_for_user_agent_init: Option<bool>,
 
@@ -111,6 +128,36 @@ impl StatsigOptionsBuilder {
         self
     }
 
+    #[must_use]
+    pub fn shared_specs_path(mut self, shared_specs_path: Option<String>) -> Self {
+        self.inner.shared_specs_path = shared_specs_path;
+        self
+    }
+
+    #[must_use]
+    pub fn max_specs_age_ms(mut self, max_specs_age_ms: Option<u64>) -> Self {
+        self.inner.max_specs_age_ms = max_specs_age_ms;
+        self
+    }
+
+    #[must_use]
+    pub fn stale_specs_policy(mut self, stale_specs_policy: Option<StaleSpecsPolicy>) -> Self {
+        self.inner.stale_specs_policy = stale_specs_policy;
+        self
+    }
+
+    #[must_use]
+    pub fn enable_zero_copy_specs(mut self, enable_zero_copy_specs: Option<bool>) -> Self {
+        self.inner.enable_zero_copy_specs = enable_zero_copy_specs;
+        self
+    }
+
+    #[must_use]
+    pub fn enable_delta_specs_updates(mut self, enable_delta_specs_updates: Option<bool>) -> Self {
+        self.inner.enable_delta_specs_updates = enable_delta_specs_updates;
+        self
+    }
+
     // Event Logging
 
     #[must_use]
@@ -166,6 +213,15 @@ impl StatsigOptionsBuilder {
         self
     }
 
+    #[must_use]
+    pub fn exposure_dedupe_store(
+        mut self,
+        exposure_dedupe_store: Option<Arc<dyn ExposureDedupeStore>>,
+    ) -> Self {
+        self.inner.exposure_dedupe_store = exposure_dedupe_store;
+        self
+    }
+
     // ID Lists
 
     #[must_use]
@@ -194,6 +250,12 @@ impl StatsigOptionsBuilder {
 
     // Other
 
+    #[must_use]
+    pub fn gcir_cache_max_entries(mut self, gcir_cache_max_entries: Option<u32>) -> Self {
+        self.inner.gcir_cache_max_entries = gcir_cache_max_entries;
+        self
+    }
+
     #[must_use]
     pub fn proxy_config(mut self, proxy_config: Option<ProxyConfig>) -> Self {
         self.inner.proxy_config = proxy_config;
@@ -256,12 +318,54 @@ impl StatsigOptionsBuilder {
         self
     }
 
+    #[must_use]
+    pub fn user_agent_parser_type(
+        mut self,
+        user_agent_parser_type: Option<UserAgentParserType>,
+    ) -> Self {
+        self.inner.user_agent_parser_type = user_agent_parser_type;
+        self
+    }
+
+    #[must_use]
+    pub fn user_agent_parser_provider(
+        mut self,
+        user_agent_parser_provider: Option<Arc<dyn UserAgentParserProvider>>,
+    ) -> Self {
+        self.inner.user_agent_parser_provider = user_agent_parser_provider;
+        self
+    }
+
+    #[must_use]
+    pub fn user_agent_cache_max_entries(
+        mut self,
+        user_agent_cache_max_entries: Option<u32>,
+    ) -> Self {
+        self.inner.user_agent_cache_max_entries = user_agent_cache_max_entries;
+        self
+    }
+
+    #[must_use]
+    pub fn computed_user_attributes(
+        mut self,
+        computed_user_attributes: Option<HashMap<String, Arc<dyn ComputedUserAttributeProvider>>>,
+    ) -> Self {
+        self.inner.computed_user_attributes = computed_user_attributes;
+        self
+    }
+
     #[must_use]
     pub fn disable_country_lookup(mut self, disable_country_lookup: Option<bool>) -> Self {
         self.inner.disable_country_lookup = disable_country_lookup;
         self
     }
 
+    #[must_use]
+    pub fn geo_ip_provider(mut self, geo_ip_provider: Option<Arc<dyn GeoIpProvider>>) -> Self {
+        self.inner.geo_ip_provider = geo_ip_provider;
+        self
+    }
+
     #[must_use]
     pub fn service_name(mut self, service_name: Option<String>) -> Self {
         self.inner.service_name = service_name;
@@ -332,12 +436,38 @@ impl Serialize for StatsigOptions {
             "specs_sync_interval_ms",
             &self.specs_sync_interval_ms
         );
+        serialize_if_not_none!(
+            state,
+            "shared_specs_path",
+            &get_if_set(&self.shared_specs_path)
+        );
+        serialize_if_not_none!(
+            state,
+            "enable_zero_copy_specs",
+            &self.enable_zero_copy_specs
+        );
+        serialize_if_not_none!(
+            state,
+            "enable_delta_specs_updates",
+            &self.enable_delta_specs_updates
+        );
         serialize_if_not_none!(state, "init_timeout_ms", &self.init_timeout_ms);
+        serialize_if_not_none!(state, "max_specs_age_ms", &self.max_specs_age_ms);
+        serialize_if_not_none!(
+            state,
+            "stale_specs_policy",
+            &get_display_name(&self.stale_specs_policy)
+        );
 
         serialize_if_not_none!(state, "data_store", &get_if_set(&self.data_store));
 
         serialize_if_not_none!(state, "log_event_url", &self.log_event_url);
         serialize_if_not_none!(state, "disable_all_logging", &self.disable_all_logging);
+        serialize_if_not_none!(
+            state,
+            "exposure_dedupe_store",
+            &get_if_set(&self.exposure_dedupe_store)
+        );
         serialize_if_not_none!(state, "disable_network", &self.disable_network);
 
         serialize_if_not_none!(state, "id_lists_url", &self.id_lists_url);
@@ -347,6 +477,21 @@ impl Serialize for StatsigOptions {
             "wait_for_user_agent_init",
             &self.wait_for_user_agent_init
         );
+        serialize_if_not_none!(
+            state,
+            "user_agent_parser_type",
+            &get_display_name(&self.user_agent_parser_type)
+        );
+        serialize_if_not_none!(
+            state,
+            "user_agent_parser_provider",
+            &get_if_set(&self.user_agent_parser_provider)
+        );
+        serialize_if_not_none!(
+            state,
+            "user_agent_cache_max_entries",
+            &self.user_agent_cache_max_entries
+        );
         serialize_if_not_none!(
             state,
             "wait_for_country_lookup_init",
@@ -373,8 +518,19 @@ impl Serialize for StatsigOptions {
             "override_adapter",
             &get_if_set(&self.override_adapter)
         );
+        serialize_if_not_none!(
+            state,
+            "gcir_cache_max_entries",
+            &self.gcir_cache_max_entries
+        );
+        serialize_if_not_none!(state, "geo_ip_provider", &get_if_set(&self.geo_ip_provider));
         serialize_if_not_none!(state, "service_name", &get_if_set(&self.service_name));
         serialize_if_not_none!(state, "global_custom_fields", &self.global_custom_fields);
+        serialize_if_not_none!(
+            state,
+            "computed_user_attributes",
+            &get_if_set(&self.computed_user_attributes)
+        );
 
         state.end()
     }
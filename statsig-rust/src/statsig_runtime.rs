@@ -68,6 +68,20 @@ impl StatsigRuntime {
         }
     }
 
+    pub fn get_active_task_tags(&self) -> Vec<String> {
+        match self.spawned_tasks.try_lock_for(Duration::from_secs(5)) {
+            Some(lock) => {
+                let mut tags: Vec<String> = lock.keys().map(|k| k.tag.clone()).collect();
+                tags.sort();
+                tags
+            }
+            None => {
+                log_e!(TAG, "Failed to lock spawned tasks for get_active_task_tags");
+                vec![]
+            }
+        }
+    }
+
     pub fn shutdown(&self) {
         self.shutdown_notify.notify_waiters();
 
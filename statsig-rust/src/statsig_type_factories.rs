@@ -6,7 +6,7 @@ use crate::evaluation::evaluation_types::{
 use crate::event_logging::event_logger::EventLogger;
 use crate::event_logging::exposable_string::{self, ExposableString};
 use crate::statsig_types::{DynamicConfig, Experiment, FeatureGate, Layer};
-use crate::user::StatsigUserLoggable;
+use crate::user::StatsigUserInternal;
 use std::sync::Weak;
 
 pub fn make_feature_gate(
@@ -102,7 +102,7 @@ pub fn make_experiment(
 
 #[allow(clippy::too_many_arguments)]
 pub fn make_layer(
-    user: StatsigUserLoggable,
+    user: &StatsigUserInternal,
     name: &str,
     evaluation: Option<LayerEvaluation>,
     details: EvaluationDetails,
@@ -147,7 +147,8 @@ pub fn make_layer(
         is_experiment_active,
         __value: value.get_json().unwrap_or_default(),
         __evaluation: evaluation,
-        __user: user,
+        __user: user.to_loggable(),
+        __user_values_hash: user.get_prepared_user_values_hash(),
         __event_logger_ptr: event_logger_ptr,
         __disable_exposure: disable_exposure,
         __version: version,
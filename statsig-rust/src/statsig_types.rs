@@ -92,6 +92,9 @@ pub struct Layer {
 
     #[serde(skip_serializing, skip_deserializing)]
     pub __event_logger_ptr: Option<Weak<EventLogger>>,
+
+    #[serde(skip_serializing, skip_deserializing)]
+    pub(crate) __user_values_hash: Option<u64>, // Set for prepared users, see PreparedUser
 }
 
 impl Layer {
@@ -0,0 +1,19 @@
+use crate::{DynamicValue, StatsigUser};
+
+/// Derives a user field (for example `account_age_days` or `is_internal_email`) from the rest of
+/// the user, registered by name in `StatsigOptions::computed_user_attributes`.
+///
+/// Only consulted when a `user_field` condition references the name and the user does not set it
+/// directly. Each attribute is computed at most once per evaluation.
+pub trait ComputedUserAttributeProvider: Send + Sync {
+    fn compute(&self, user: &StatsigUser) -> Option<DynamicValue>;
+}
+
+impl<F> ComputedUserAttributeProvider for F
+where
+    F: Fn(&StatsigUser) -> Option<DynamicValue> + Send + Sync,
+{
+    fn compute(&self, user: &StatsigUser) -> Option<DynamicValue> {
+        self(user)
+    }
+}
@@ -1,4 +1,7 @@
+pub mod computed_user_attribute;
 pub mod into_optional;
+pub mod prepared_user;
+pub mod scoped_custom_fields;
 pub mod statsig_user;
 pub mod statsig_user_builder;
 pub mod statsig_user_internal;
@@ -6,6 +9,9 @@ pub mod statsig_user_loggable;
 pub mod unit_id;
 pub mod user_data;
 
+pub use computed_user_attribute::ComputedUserAttributeProvider;
+pub use prepared_user::PreparedUser;
+pub use scoped_custom_fields::ScopedCustomFields;
 pub use statsig_user::StatsigUser;
 pub use statsig_user_builder::StatsigUserBuilder;
 pub use statsig_user_internal::StatsigUserInternal;
@@ -0,0 +1,174 @@
+use super::user_data::UserData;
+use super::{StatsigUser, StatsigUserInternal};
+use crate::evaluation::dynamic_string::DynamicString;
+use crate::evaluation::user_agent_parsing::ParsedUserAgent;
+use crate::{DynamicValue, Statsig, StatsigOptions};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::{Arc, OnceLock, Weak};
+
+// get_user_value reads these through user.data rather than a map, see StatsigUserInternal
+const BUILT_IN_FIELDS: [&str; 10] = [
+    "userid",
+    "email",
+    "ip",
+    "country",
+    "locale",
+    "appversion",
+    "useragent",
+    "user_id",
+    "app_version",
+    "user_agent",
+];
+
+/// A user prepared by [`crate::Statsig::prepare_user`] for many evaluations, for example every
+/// gate and experiment checked while handling one request.
+///
+/// Derefs to [`StatsigUser`], so it can be passed to every evaluation API. Values that are costly
+/// to derive are computed once and reused: the exposure dedupe hash, unit IDs and field lookups up
+/// front, the parsed user agent and IP lookups on first use.
+///
+/// Apart from the dedupe hash, these values depend on the instance's options (global custom fields,
+/// user agent parser, geo-IP provider), so they are only used by instances sharing the preparing
+/// instance's `StatsigOptions`. Other instances evaluate the user as if it was not prepared.
+/// They are also tied to the `UserData` they were derived from: a clone of the inner user whose
+/// data is changed, through a setter or by assigning `data`, is evaluated as if it was not prepared.
+#[derive(Clone)]
+pub struct PreparedUser {
+    user: StatsigUser,
+}
+
+impl PreparedUser {
+    pub(crate) fn new(user: &StatsigUser, statsig: &Statsig) -> Self {
+        let internal = StatsigUserInternal::new(user, Some(statsig));
+        let values = PreparedUserValues {
+            options: Arc::downgrade(&statsig.options),
+            data: Arc::downgrade(&user.data),
+            user_values_hash: user.data.create_user_values_hash(),
+            user_values: resolve_user_values(&internal),
+            unit_ids: resolve_unit_ids(&internal),
+            parsed_user_agent: OnceLock::new(),
+            ip_values: Mutex::new(HashMap::new()),
+        };
+
+        Self {
+            user: StatsigUser {
+                data: user.data.clone(),
+                scoped_custom_fields: user.scoped_custom_fields.clone(),
+                prepared_values: Some(Arc::new(values)),
+            },
+        }
+    }
+
+    pub fn user(&self) -> &StatsigUser {
+        &self.user
+    }
+}
+
+impl Deref for PreparedUser {
+    type Target = StatsigUser;
+
+    fn deref(&self) -> &Self::Target {
+        &self.user
+    }
+}
+
+pub struct PreparedUserValues {
+    // only compared by address, a weak reference keeps it from being reused
+    options: Weak<StatsigOptions>,
+    // same as options, compared to the user's data so replacing it drops these values
+    data: Weak<UserData>,
+    pub(crate) user_values_hash: u64,
+    // field name -> StatsigUserInternal::get_user_value, only found values
+    user_values: HashMap<String, DynamicValue>,
+    // id type -> StatsigUserInternal::get_unit_id, only found values
+    unit_ids: HashMap<String, DynamicValue>,
+    pub(crate) parsed_user_agent: OnceLock<Arc<ParsedUserAgent>>,
+    // ip_based field -> value, only successful lookups
+    pub(crate) ip_values: Mutex<HashMap<String, DynamicValue>>,
+}
+
+impl PreparedUserValues {
+    pub(crate) fn is_for(&self, statsig: &Statsig, user: &StatsigUser) -> bool {
+        std::ptr::eq(self.options.as_ptr(), Arc::as_ptr(&statsig.options))
+            && std::ptr::eq(self.data.as_ptr(), Arc::as_ptr(&user.data))
+    }
+
+    /// None if `field` was not resolved up front, which only happens for nested field paths.
+    pub(crate) fn get_user_value(&self, field: &DynamicString) -> Option<Option<&DynamicValue>> {
+        if let Some(found) = self.user_values.get(&field.value) {
+            return Some(Some(found));
+        }
+
+        if field.value.starts_with('/') || field.value.contains('.') {
+            return None;
+        }
+
+        Some(self.user_values.get(&field.lowercased_value))
+    }
+
+    pub(crate) fn get_unit_id(&self, id_type: &DynamicString) -> Option<&DynamicValue> {
+        self.unit_ids
+            .get(&id_type.value)
+            .or_else(|| self.unit_ids.get(&id_type.lowercased_value))
+    }
+}
+
+// A lookup by name only differs from one by its lowercased form when the name is itself a key, so
+// resolving every key and every built-in field covers all names. See PreparedUserValues::get_user_value
+fn resolve_user_values(user: &StatsigUserInternal) -> HashMap<String, DynamicValue> {
+    let mut names: Vec<&str> = BUILT_IN_FIELDS.to_vec();
+    let data = &user.user_ref.data;
+
+    for map in [&data.custom, &data.private_attributes]
+        .into_iter()
+        .flatten()
+    {
+        names.extend(map.keys().map(String::as_str));
+    }
+
+    if let Some(scoped) = &user.user_ref.scoped_custom_fields {
+        names.extend(scoped.fields().keys().map(String::as_str));
+    }
+
+    let global_names: Vec<String> = user
+        .statsig_instance
+        .map(|statsig| {
+            statsig.use_global_custom_fields(|fields| {
+                fields
+                    .map(|fields| fields.keys().cloned().collect())
+                    .unwrap_or_default()
+            })
+        })
+        .unwrap_or_default();
+    names.extend(global_names.iter().map(String::as_str));
+
+    resolve_each(names, |name| user.get_user_value(&Some(name)))
+}
+
+fn resolve_unit_ids(user: &StatsigUserInternal) -> HashMap<String, DynamicValue> {
+    let mut names = vec!["userid"];
+    if let Some(custom_ids) = &user.user_ref.data.custom_ids {
+        names.extend(custom_ids.keys().map(String::as_str));
+    }
+
+    resolve_each(names, |id_type| user.get_unit_id(&id_type))
+}
+
+fn resolve_each<'a>(
+    names: Vec<&str>,
+    resolve: impl Fn(DynamicString) -> Option<&'a DynamicValue>,
+) -> HashMap<String, DynamicValue> {
+    let mut resolved = HashMap::with_capacity(names.len());
+    for name in names {
+        if resolved.contains_key(name) {
+            continue;
+        }
+
+        if let Some(value) = resolve(DynamicString::from(name.to_string())) {
+            resolved.insert(name.to_string(), value.clone());
+        }
+    }
+    resolved
+}
@@ -0,0 +1,40 @@
+use crate::DynamicValue;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Custom fields shared by many users, like the plan or region of the tenant a request belongs to.
+///
+/// Cloning is cheap, so build one per tenant or request and attach it to each user with
+/// [`crate::StatsigUser::with_scoped_custom_fields`]. Conditions check the user's own `custom`
+/// fields first, then these, then `StatsigOptions::global_custom_fields`. Logged users include them
+/// in `custom` the same way.
+#[derive(Clone, Default, Debug)]
+pub struct ScopedCustomFields {
+    fields: Arc<HashMap<String, DynamicValue>>,
+}
+
+impl ScopedCustomFields {
+    #[must_use]
+    pub fn new<K, V>(fields: HashMap<K, V>) -> Self
+    where
+        K: Into<String>,
+        V: Into<DynamicValue>,
+    {
+        Self {
+            fields: Arc::new(
+                fields
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v.into()))
+                    .collect(),
+            ),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&DynamicValue> {
+        self.fields.get(key)
+    }
+
+    pub fn fields(&self) -> &HashMap<String, DynamicValue> {
+        &self.fields
+    }
+}
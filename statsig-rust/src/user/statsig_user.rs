@@ -2,11 +2,17 @@ use crate::evaluation::dynamic_value::DynamicValue;
 use crate::{dyn_value, evaluation::dynamic_string::DynamicString};
 use std::{collections::HashMap, sync::Arc};
 
-use super::{into_optional::IntoOptional, unit_id::UnitID, user_data::UserData};
+use super::{
+    into_optional::IntoOptional, prepared_user::PreparedUserValues,
+    scoped_custom_fields::ScopedCustomFields, unit_id::UnitID, user_data::UserData,
+};
 
-#[derive(Clone)]
+/// Build with `StatsigUser::new`, the `with_*` constructors or `StatsigUserBuilder`.
+#[derive(Clone, Default)]
 pub struct StatsigUser {
     pub data: Arc<UserData>,
+    pub(crate) scoped_custom_fields: Option<ScopedCustomFields>, // See with_scoped_custom_fields
+    pub(crate) prepared_values: Option<Arc<PreparedUserValues>>, // Set by Statsig::prepare_user, see PreparedUser
 }
 
 impl StatsigUser {
@@ -36,9 +42,23 @@ impl StatsigUser {
         })
     }
 
-    pub(crate) fn new(inner: UserData) -> Self {
+    #[must_use]
+    pub fn new(inner: UserData) -> Self {
         Self {
             data: Arc::new(inner),
+            scoped_custom_fields: None,
+            prepared_values: None,
+        }
+    }
+
+    /// Returns a copy of this user with `scoped_custom_fields` attached. Both are reference
+    /// counted, so no fields are copied. Prepare the returned user, not the other way around.
+    #[must_use]
+    pub fn with_scoped_custom_fields(&self, scoped_custom_fields: &ScopedCustomFields) -> Self {
+        Self {
+            data: self.data.clone(),
+            scoped_custom_fields: Some(scoped_custom_fields.clone()),
+            prepared_values: None,
         }
     }
 }
@@ -57,6 +77,7 @@ macro_rules! string_field_accessor {
         }
 
         pub fn $setter_name(&mut self, value: impl IntoOptional<String>) {
+            self.prepared_values = None;
             let value = value.into_optional();
             let mut_data = Arc::make_mut(&mut self.data);
             match value {
@@ -80,6 +101,7 @@ macro_rules! map_field_accessor {
             K: Into<String>,
             V: Into<DynamicValue>,
         {
+            self.prepared_values = None;
             let mut_data = Arc::make_mut(&mut self.data);
             let value = match value.into_optional() {
                 Some(value) => value,
@@ -100,6 +122,16 @@ macro_rules! map_field_accessor {
 }
 
 impl StatsigUser {
+    pub fn get_user_data(&self) -> &UserData {
+        &self.data
+    }
+
+    /// Drops any values precomputed by `Statsig::prepare_user`, as with every other setter.
+    pub fn get_user_data_mut(&mut self) -> &mut UserData {
+        self.prepared_values = None;
+        Arc::make_mut(&mut self.data)
+    }
+
     pub fn get_user_id(&self) -> Option<&str> {
         self.data
             .user_id
@@ -111,6 +143,7 @@ impl StatsigUser {
 
     pub fn set_user_id(&mut self, user_id: impl Into<UnitID>) {
         let unit_id = user_id.into();
+        self.prepared_values = None;
         let mut_data = Arc::make_mut(&mut self.data);
         mut_data.user_id = Some(unit_id.into());
     }
@@ -137,6 +170,7 @@ impl StatsigUser {
             .map(|(k, v)| (k.into(), v.into().into()))
             .collect();
 
+        self.prepared_values = None;
         let mut_data = Arc::make_mut(&mut self.data);
         mut_data.custom_ids = Some(custom_ids);
     }
@@ -163,6 +197,15 @@ impl StatsigUser {
     string_field_accessor!(self, get_app_version, set_app_version, app_version);
 
     map_field_accessor!(self, get_custom, set_custom, custom);
+
+    pub fn get_scoped_custom_fields(&self) -> Option<&ScopedCustomFields> {
+        self.scoped_custom_fields.as_ref()
+    }
+
+    pub fn set_scoped_custom_fields(&mut self, value: Option<ScopedCustomFields>) {
+        self.prepared_values = None;
+        self.scoped_custom_fields = value;
+    }
     map_field_accessor!(
         self,
         get_private_attributes,
@@ -1,3 +1,4 @@
+use super::scoped_custom_fields::ScopedCustomFields;
 use super::unit_id::UnitID;
 use super::user_data::UserData;
 use super::StatsigUser;
@@ -18,6 +19,7 @@ pub struct StatsigUserBuilder {
 
     pub custom: Option<HashMap<String, DynamicValue>>,
     pub private_attributes: Option<HashMap<String, DynamicValue>>,
+    pub scoped_custom_fields: Option<ScopedCustomFields>,
 }
 
 impl StatsigUserBuilder {
@@ -50,6 +52,7 @@ impl StatsigUserBuilder {
             custom: None,
             private_attributes: None,
             custom_ids: None,
+            scoped_custom_fields: None,
         }
     }
 
@@ -153,6 +156,14 @@ impl StatsigUserBuilder {
         self
     }
 
+    pub fn scoped_custom_fields(
+        mut self,
+        scoped_custom_fields: Option<ScopedCustomFields>,
+    ) -> Self {
+        self.scoped_custom_fields = scoped_custom_fields;
+        self
+    }
+
     pub fn build(self) -> StatsigUser {
         let data = UserData {
             user_id: self.user_id.map(|u| u.into()),
@@ -169,7 +180,10 @@ impl StatsigUserBuilder {
                 .map(|m| m.into_iter().map(|(k, v)| (k, v.into())).collect()),
         };
 
-        StatsigUser::new(data)
+        StatsigUser {
+            scoped_custom_fields: self.scoped_custom_fields,
+            ..StatsigUser::new(data)
+        }
     }
 }
 
@@ -1,8 +1,11 @@
-use super::StatsigUserLoggable;
+use super::prepared_user::PreparedUserValues;
+use super::{ComputedUserAttributeProvider, StatsigUserLoggable};
 use crate::evaluation::dynamic_value::DynamicValue;
 use crate::hashing::djb2_number;
 use crate::StatsigUser;
 use crate::{evaluation::dynamic_string::DynamicString, Statsig};
+use std::borrow::Cow;
+use std::sync::Arc;
 
 pub type FullUserKey = (
     u64,      // app_version
@@ -33,6 +36,10 @@ impl<'statsig, 'user> StatsigUserInternal<'statsig, 'user> {
     }
 
     pub fn get_unit_id(&self, id_type: &DynamicString) -> Option<&DynamicValue> {
+        if let Some(prepared) = self.get_prepared_values() {
+            return prepared.get_unit_id(id_type);
+        }
+
         if id_type.lowercased_value.eq("userid") {
             return self.user_ref.data.user_id.as_ref();
         }
@@ -49,6 +56,13 @@ impl<'statsig, 'user> StatsigUserInternal<'statsig, 'user> {
     pub fn get_user_value(&self, field: &Option<DynamicString>) -> Option<&DynamicValue> {
         let field = field.as_ref()?;
 
+        if let Some(found) = self
+            .get_prepared_values()
+            .and_then(|prepared| prepared.get_user_value(field))
+        {
+            return found;
+        }
+
         let lowered_field = &field.lowercased_value;
 
         let str_value = match lowered_field as &str {
@@ -79,6 +93,15 @@ impl<'statsig, 'user> StatsigUserInternal<'statsig, 'user> {
             }
         }
 
+        if let Some(scoped) = &self.user_ref.scoped_custom_fields {
+            if let Some(found) = scoped.get(&field.value) {
+                return Some(found);
+            }
+            if let Some(lowered_found) = scoped.get(lowered_field) {
+                return Some(lowered_found);
+            }
+        }
+
         if let Some(instance) = &self.statsig_instance {
             if let Some(val) = instance.get_value_from_global_custom_fields(&field.value) {
                 return Some(val);
@@ -110,9 +133,80 @@ impl<'statsig, 'user> StatsigUserInternal<'statsig, 'user> {
             return str_value_alt.as_ref();
         }
 
+        if let Some(found) = self.get_nested_user_value(&field.value) {
+            return Some(found);
+        }
+
+        if field.lowercased_value != field.value {
+            return self.get_nested_user_value(lowered_field);
+        }
+
         None
     }
 
+    /// Resolves dotted (`org.plan.seats`) or JSON pointer (`/org/plan/seats`) paths into the
+    /// objects and arrays of `custom`, scoped and global custom fields and `private_attributes`, in
+    /// that order.
+    /// Keys that exist verbatim at the top level take precedence (see `get_user_value`).
+    fn get_nested_user_value(&self, path: &str) -> Option<&DynamicValue> {
+        let segments = parse_field_path(path)?;
+        let (root, rest) = segments.split_first()?;
+
+        if let Some(found) = self
+            .user_ref
+            .data
+            .custom
+            .as_ref()
+            .and_then(|custom| custom.get(root.as_ref()))
+            .and_then(|value| resolve_field_path(value, rest))
+        {
+            return Some(found);
+        }
+
+        if let Some(found) = self
+            .user_ref
+            .scoped_custom_fields
+            .as_ref()
+            .and_then(|scoped| scoped.get(root))
+            .and_then(|value| resolve_field_path(value, rest))
+        {
+            return Some(found);
+        }
+
+        if let Some(found) = self
+            .statsig_instance
+            .and_then(|instance| instance.get_value_from_global_custom_fields(root))
+            .and_then(|value| resolve_field_path(value, rest))
+        {
+            return Some(found);
+        }
+
+        self.user_ref
+            .data
+            .private_attributes
+            .as_ref()
+            .and_then(|private_attributes| private_attributes.get(root.as_ref()))
+            .and_then(|value| resolve_field_path(value, rest))
+    }
+
+    /// The provider registered in `StatsigOptions::computed_user_attributes` for `field`, if any.
+    /// See `EvaluatorContext::get_computed_user_value` for the memoized lookup.
+    pub fn get_computed_user_attribute(
+        &self,
+        field: &Option<DynamicString>,
+    ) -> Option<&Arc<dyn ComputedUserAttributeProvider>> {
+        let field = field.as_ref()?;
+        let providers = self
+            .statsig_instance?
+            .options
+            .computed_user_attributes
+            .as_ref()?;
+
+        providers
+            .get(&field.value)
+            .or_else(|| providers.get(&field.lowercased_value))
+    }
+
     pub fn get_value_from_environment(
         &self,
         field: &Option<DynamicString>,
@@ -127,6 +221,28 @@ impl<'statsig, 'user> StatsigUserInternal<'statsig, 'user> {
             .get_from_statsig_env(&field.lowercased_value)
     }
 
+    /// See `UserData::create_user_values_hash`, precomputed for prepared users.
+    pub fn get_user_values_hash(&self) -> u64 {
+        match self.get_prepared_user_values_hash() {
+            Some(hash) => hash,
+            None => self.user_ref.data.create_user_values_hash(),
+        }
+    }
+
+    /// The values precomputed by `Statsig::prepare_user`, if they were derived for this instance
+    /// and this user's data.
+    pub fn get_prepared_values(&self) -> Option<&PreparedUserValues> {
+        let prepared = self.user_ref.prepared_values.as_deref()?;
+        prepared
+            .is_for(self.statsig_instance?, self.user_ref)
+            .then_some(prepared)
+    }
+
+    pub fn get_prepared_user_values_hash(&self) -> Option<u64> {
+        self.get_prepared_values()
+            .map(|prepared| prepared.user_values_hash)
+    }
+
     pub fn to_loggable(&self) -> StatsigUserLoggable {
         let (environment, global_custom) = match self.statsig_instance {
             Some(statsig) => (
@@ -136,6 +252,16 @@ impl<'statsig, 'user> StatsigUserInternal<'statsig, 'user> {
             None => (None, None),
         };
 
+        // scoped fields are logged like global ones, taking precedence over them
+        let global_custom = match &self.user_ref.scoped_custom_fields {
+            Some(scoped) => {
+                let mut merged = global_custom.unwrap_or_default();
+                merged.extend(scoped.fields().iter().map(|(k, v)| (k.clone(), v.clone())));
+                Some(merged)
+            }
+            None => global_custom,
+        };
+
         StatsigUserLoggable::new(&self.user_ref.data, environment, global_custom)
     }
 
@@ -161,3 +287,38 @@ impl<'statsig, 'user> StatsigUserInternal<'statsig, 'user> {
         Some(val.to_string())
     }
 }
+
+/// None if `path` is a plain key rather than a dotted path or JSON pointer.
+fn parse_field_path(path: &str) -> Option<Vec<Cow<'_, str>>> {
+    if let Some(pointer) = path.strip_prefix('/') {
+        return Some(
+            pointer
+                .split('/')
+                .map(|segment| match segment.contains('~') {
+                    true => Cow::Owned(segment.replace("~1", "/").replace("~0", "~")),
+                    false => Cow::Borrowed(segment),
+                })
+                .collect(),
+        );
+    }
+
+    if !path.contains('.') {
+        return None;
+    }
+
+    Some(path.split('.').map(Cow::Borrowed).collect())
+}
+
+fn resolve_field_path<'a>(
+    value: &'a DynamicValue,
+    segments: &[Cow<'_, str>],
+) -> Option<&'a DynamicValue> {
+    segments.iter().try_fold(value, |current, segment| {
+        if let Some(object) = &current.object_value {
+            return object.get(segment.as_ref());
+        }
+
+        let index = segment.parse::<usize>().ok()?;
+        current.array_value.as_ref()?.get(index)
+    })
+}
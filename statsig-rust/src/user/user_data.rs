@@ -1,5 +1,7 @@
 use crate::{evaluation::dynamic_value::DynamicValue, hashing};
+use ahash::RandomState;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use serde_with::skip_serializing_none;
 use std::collections::HashMap;
 
@@ -32,6 +34,33 @@ impl UserData {
         hashing::hash_one(hashes)
     }
 
+    /// Hashes the same values as `create_user_values_hash` with `hasher`, independent of map
+    /// iteration order, so every process using the same seeds gets the same result.
+    pub fn create_user_values_hash_with(&self, hasher: &RandomState) -> u64 {
+        let field_hash =
+            |field: &Option<DynamicValue>| field.as_ref().map_or(0, |v| hash_value_with(hasher, v));
+        let map_hash = |field: &Option<HashMap<String, DynamicValue>>| {
+            field.as_ref().map_or(0, |map| {
+                map.iter().fold(0u64, |acc, (key, value)| {
+                    acc.wrapping_add(hasher.hash_one((key, hash_value_with(hasher, value))))
+                })
+            })
+        };
+
+        hasher.hash_one([
+            field_hash(&self.user_id),
+            map_hash(&self.custom_ids),
+            field_hash(&self.app_version),
+            field_hash(&self.country),
+            field_hash(&self.email),
+            field_hash(&self.ip),
+            field_hash(&self.locale),
+            field_hash(&self.user_agent),
+            map_hash(&self.custom),
+            map_hash(&self.private_attributes),
+        ])
+    }
+
     fn get_all_user_hashes(&self) -> Vec<u64> {
         let mut hashes = Vec::new();
         push_string_field_hashes(&mut hashes, &self.user_id);
@@ -55,6 +84,13 @@ impl UserData {
     }
 }
 
+fn hash_value_with(hasher: &RandomState, value: &DynamicValue) -> u64 {
+    match &value.json_value {
+        JsonValue::String(s) => hasher.hash_one(s.as_str()),
+        other => hasher.hash_one(other.to_string()),
+    }
+}
+
 fn push_string_field_hashes(hashes: &mut Vec<u64>, field: &Option<DynamicValue>) {
     if let Some(field) = field {
         hashes.push(field.hash_value);
@@ -0,0 +1,132 @@
+mod utils;
+
+use statsig_rust::{
+    dyn_value, ComputedUserAttributeProvider, DynamicValue, Statsig, StatsigOptions, StatsigUser,
+    StatsigUserBuilder,
+};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+// "level" is read by test_custom_gt (> 8), test_custom_lt (< 10) and test_numeric (> 8)
+#[derive(Default)]
+struct LevelFromEmail {
+    calls: AtomicUsize,
+}
+
+impl ComputedUserAttributeProvider for LevelFromEmail {
+    fn compute(&self, user: &StatsigUser) -> Option<DynamicValue> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let email = user.get_email()?;
+        Some(dyn_value!(if email.ends_with("@statsig.com") {
+            9
+        } else {
+            1
+        }))
+    }
+}
+
+async fn setup(provider: Arc<LevelFromEmail>) -> Statsig {
+    let computed_user_attributes = HashMap::from([(
+        "level".to_string(),
+        provider as Arc<dyn ComputedUserAttributeProvider>,
+    )]);
+
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            computed_user_attributes: Some(computed_user_attributes),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn user_with_email(email: &str) -> StatsigUser {
+    StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .email(Some(email.to_string()))
+        .build()
+}
+
+#[tokio::test]
+async fn test_computed_attribute_in_user_field_condition() {
+    let provider = Arc::new(LevelFromEmail::default());
+    let statsig = setup(provider.clone()).await;
+
+    assert!(statsig.check_gate(&user_with_email("dan@statsig.com"), "test_custom_gt"));
+    assert!(!statsig.check_gate(&user_with_email("dan@example.com"), "test_custom_gt"));
+}
+
+#[tokio::test]
+async fn test_user_value_takes_precedence() {
+    let provider = Arc::new(LevelFromEmail::default());
+    let statsig = setup(provider.clone()).await;
+
+    let user = StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .email(Some("dan@example.com".to_string()))
+        .custom(Some(HashMap::from([("level".to_string(), dyn_value!(9))])))
+        .build();
+
+    assert!(statsig.check_gate(&user, "test_custom_gt"));
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_not_computed_when_unused() {
+    let provider = Arc::new(LevelFromEmail::default());
+    let statsig = setup(provider.clone()).await;
+
+    statsig.check_gate(&user_with_email("dan@statsig.com"), "test_email");
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn test_computed_once_per_evaluation() {
+    let provider = Arc::new(LevelFromEmail::default());
+    let statsig = setup(provider.clone()).await;
+
+    // every spec is evaluated with a single context
+    let _ = statsig.get_client_init_response(&user_with_email("dan@statsig.com"));
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+
+    let user = user_with_email("dan@statsig.com");
+    statsig.check_gate(&user, "test_custom_gt");
+    statsig.check_gate(&user, "test_custom_lt");
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_closure_provider() {
+    let is_internal_email: Arc<dyn ComputedUserAttributeProvider> =
+        Arc::new(|user: &StatsigUser| {
+            user.get_email()
+                .map(|email| dyn_value!(email.ends_with("@statsig.com")))
+        });
+
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            computed_user_attributes: Some(HashMap::from([(
+                "newUser".to_string(),
+                is_internal_email,
+            )])),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+
+    // test_custom passes unless newUser is "true"
+    assert!(!statsig.check_gate(&user_with_email("dan@statsig.com"), "test_custom"));
+    assert!(statsig.check_gate(&user_with_email("dan@example.com"), "test_custom"));
+}
@@ -0,0 +1,64 @@
+mod utils;
+
+use statsig_rust::{Statsig, StatsigOptions, StatsigUser, StatsigUserBuilder};
+use std::sync::Arc;
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+async fn setup() -> Statsig {
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            wait_for_country_lookup_init: Some(true),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn user_with_ip(ip: &str) -> StatsigUser {
+    StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .ip(Some(ip.to_string()))
+        .build()
+}
+
+#[tokio::test]
+async fn test_ipv4_lookup() {
+    let statsig = setup().await;
+
+    assert!(statsig.check_gate(&user_with_ip("8.8.8.8"), "test_country"));
+    assert!(!statsig.check_gate(&user_with_ip("not.an.ip.address"), "test_country"));
+}
+
+#[tokio::test]
+async fn test_ipv4_mapped_ipv6_lookup() {
+    let statsig = setup().await;
+
+    assert!(statsig.check_gate(&user_with_ip("::ffff:8.8.8.8"), "test_country"));
+    assert!(statsig.check_gate(&user_with_ip("::FFFF:808:808"), "test_country"));
+    assert!(statsig.check_gate(&user_with_ip("[::ffff:8.8.8.8]"), "test_country"));
+}
+
+#[tokio::test]
+async fn test_ipv6_lookup() {
+    let statsig = setup().await;
+
+    // US and CA pass the gate
+    assert!(statsig.check_gate(&user_with_ip("2001:4860:4860::8888"), "test_country"));
+    assert!(statsig.check_gate(&user_with_ip("[2001:4860:4860::8844]"), "test_country"));
+    assert!(!statsig.check_gate(&user_with_ip("2001:da8::1"), "test_country"));
+    assert!(!statsig.check_gate(&user_with_ip("2a02:2e0::1"), "test_country"));
+}
+
+#[tokio::test]
+async fn test_invalid_ipv6_lookup() {
+    let statsig = setup().await;
+
+    assert!(!statsig.check_gate(&user_with_ip("2001:db8::zz"), "test_country"));
+    assert!(!statsig.check_gate(&user_with_ip("::1"), "test_country"));
+}
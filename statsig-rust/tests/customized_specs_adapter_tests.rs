@@ -152,6 +152,7 @@ pub mod specs_adapter_tests {
                 client_cert_path: None,
                 client_key_path: None,
                 domain_name: None,
+                grpc_stream_config: None,
             },
             SpecAdapterConfig {
                 adapter_type: SpecsAdapterType::NetworkGrpcWebsocket,
@@ -162,6 +163,7 @@ pub mod specs_adapter_tests {
                 client_cert_path: None,
                 client_key_path: None,
                 domain_name: None,
+                grpc_stream_config: None,
             },
         ];
         let mut options = StatsigOptions::new();
@@ -0,0 +1,294 @@
+mod utils;
+
+use serde_json::{json, Value};
+use statsig_rust::{
+    SpecStore, SpecsSource, SpecsUpdate, Statsig, StatsigErr, StatsigOptions, StatsigRuntime,
+    StatsigUser,
+};
+use std::sync::Arc;
+use utils::helpers::load_contents;
+use utils::mock_scrapi::{Endpoint, EndpointStub, Method, MockScrapi};
+
+const SDK_KEY: &str = "secret-key";
+const DCS_TIME: u64 = 1729873603830;
+
+fn updated_custom_config() -> Value {
+    let dcs: Value = serde_json::from_str(&load_contents("eval_proj_dcs.json")).unwrap();
+    let mut config = dcs["dynamic_configs"]["test_custom_config"].clone();
+    config["rules"][0]["returnValue"]["header_text"] = json!("delta update");
+    config
+}
+
+fn make_delta(base_time: u64) -> String {
+    json!({
+        "is_delta": true,
+        "has_updates": true,
+        "base_time": base_time,
+        "time": DCS_TIME + 1,
+        "dynamic_configs": { "test_custom_config": updated_custom_config() },
+        "deleted_feature_gates": ["test_public"],
+    })
+    .to_string()
+}
+
+fn make_delta_with(base_time: u64, extra: Value) -> String {
+    let mut delta: Value = serde_json::from_str(&make_delta(base_time)).unwrap();
+    for (key, value) in extra.as_object().unwrap() {
+        delta[key] = value.clone();
+    }
+    delta.to_string()
+}
+
+fn make_update(data: String) -> SpecsUpdate {
+    SpecsUpdate {
+        data: data.into_bytes(),
+        source: SpecsSource::Network,
+        received_at: 0,
+        source_api: None,
+    }
+}
+
+fn create_spec_store(sdk_key: &str) -> SpecStore {
+    let spec_store = SpecStore::new(
+        sdk_key,
+        sdk_key.to_string(),
+        StatsigRuntime::get_runtime(),
+        None,
+    );
+    spec_store
+        .set_values(make_update(load_contents("eval_proj_dcs.json")))
+        .unwrap();
+    spec_store
+}
+
+async fn setup(scrapi: &MockScrapi) -> Statsig {
+    let statsig = Statsig::new(
+        SDK_KEY,
+        Some(Arc::new(StatsigOptions {
+            specs_url: Some(scrapi.url_for_endpoint(Endpoint::DownloadConfigSpecs)),
+            log_event_url: Some(scrapi.url_for_endpoint(Endpoint::LogEvent)),
+            specs_sync_interval_ms: Some(1000),
+            enable_delta_specs_updates: Some(true),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn get_header_text(statsig: &Statsig) -> String {
+    let user = StatsigUser::with_user_id("a-user");
+    let config = statsig.get_dynamic_config(&user, "test_custom_config");
+    config.get_string("header_text", String::new())
+}
+
+#[test]
+fn test_delta_replaces_only_changed_specs() {
+    let spec_store = create_spec_store("secret-key-delta-changed");
+    let (gate_count, untouched) = {
+        let data = spec_store.data.read();
+        let untouched = data
+            .values()
+            .feature_gates
+            .get("test_disabled_gate")
+            .unwrap();
+        (
+            data.values().feature_gates.specs.len(),
+            untouched.spec.clone(),
+        )
+    };
+
+    spec_store
+        .set_values(make_update(make_delta(DCS_TIME)))
+        .unwrap();
+
+    let data = spec_store.data.read();
+    assert_eq!(data.values().time, DCS_TIME + 1);
+    assert_eq!(data.values().feature_gates.specs.len(), gate_count - 1);
+    assert!(data.values().feature_gates.get("test_public").is_none());
+
+    let after = data
+        .values()
+        .feature_gates
+        .get("test_disabled_gate")
+        .unwrap();
+    assert!(Arc::ptr_eq(&after.spec, &untouched));
+
+    let config = data
+        .values()
+        .dynamic_configs
+        .get("test_custom_config")
+        .unwrap();
+    assert_eq!(
+        config.spec.rules[0].return_value.get_json().unwrap()["header_text"],
+        json!("delta update")
+    );
+}
+
+#[test]
+fn test_delta_with_other_base_is_rejected() {
+    let spec_store = create_spec_store("secret-key-delta-mismatch");
+
+    let result = spec_store.set_values(make_update(make_delta(DCS_TIME - 1)));
+
+    assert!(matches!(result, Err(StatsigErr::SpecsDeltaMismatch(_))));
+    let data = spec_store.data.read();
+    assert_eq!(data.values().time, DCS_TIME);
+    assert!(data.values().feature_gates.get("test_public").is_some());
+}
+
+#[test]
+fn test_delta_replaces_top_level_sections() {
+    let spec_store = create_spec_store("secret-key-delta-sections");
+
+    spec_store
+        .set_values(make_update(make_delta_with(
+            DCS_TIME,
+            json!({
+                "app_id": "delta-app",
+                "id_lists": { "delta_list": true },
+                "cmab_configs": {},
+                "session_replay_info": { "sampling_rate": 0.5 },
+            }),
+        )))
+        .unwrap();
+
+    let data = spec_store.data.read();
+    assert_eq!(
+        data.values()
+            .app_id
+            .as_ref()
+            .and_then(|v| v.string_value.as_ref())
+            .map(|v| v.value.as_str()),
+        Some("delta-app")
+    );
+    assert_eq!(
+        data.values().id_lists.as_ref().unwrap().get("delta_list"),
+        Some(&true)
+    );
+    assert!(data.values().cmab_configs.as_ref().unwrap().is_empty());
+    assert_eq!(
+        data.values()
+            .session_replay_info
+            .as_ref()
+            .unwrap()
+            .sampling_rate,
+        Some(0.5)
+    );
+}
+
+#[test]
+fn test_delta_with_matching_specs_checksum_is_applied() {
+    let expected = create_spec_store("secret-key-delta-checksum-expected");
+    expected
+        .set_values(make_update(make_delta(DCS_TIME)))
+        .unwrap();
+    let specs_checksum = expected.data.read().values().get_specs_checksum();
+
+    let spec_store = create_spec_store("secret-key-delta-checksum-match");
+    spec_store
+        .set_values(make_update(make_delta_with(
+            DCS_TIME,
+            json!({ "specs_checksum": specs_checksum }),
+        )))
+        .unwrap();
+
+    let data = spec_store.data.read();
+    assert_eq!(data.values().time, DCS_TIME + 1);
+    assert_eq!(data.values().get_specs_checksum(), specs_checksum);
+}
+
+#[test]
+fn test_delta_with_other_specs_checksum_is_rejected() {
+    let spec_store = create_spec_store("secret-key-delta-checksum-mismatch");
+    let before = spec_store.data.read().values().get_specs_checksum();
+
+    let result = spec_store.set_values(make_update(make_delta_with(
+        DCS_TIME,
+        json!({ "specs_checksum": before }),
+    )));
+
+    assert!(matches!(result, Err(StatsigErr::SpecsDeltaMismatch(_))));
+    let data = spec_store.data.read();
+    assert_eq!(data.values().time, DCS_TIME);
+    assert!(data.values().feature_gates.get("test_public").is_some());
+}
+
+#[tokio::test]
+async fn test_delta_is_applied_on_background_sync() {
+    let scrapi = MockScrapi::new().await;
+    scrapi
+        .stub(EndpointStub {
+            method: Method::GET,
+            response: load_contents("eval_proj_dcs.json"),
+            ..EndpointStub::with_endpoint(Endpoint::DownloadConfigSpecs)
+        })
+        .await;
+
+    let statsig = setup(&scrapi).await;
+    let user = StatsigUser::with_user_id("a-user");
+    assert!(statsig.check_gate(&user, "test_public"));
+    assert_eq!(get_header_text(&statsig), "old user test");
+
+    scrapi.reset().await;
+    scrapi
+        .stub(EndpointStub {
+            method: Method::GET,
+            response: make_delta(DCS_TIME),
+            ..EndpointStub::with_endpoint(Endpoint::DownloadConfigSpecs)
+        })
+        .await;
+
+    assert_eventually_eq!(|| get_header_text(&statsig), "delta update");
+    assert!(!statsig.check_gate(&user, "test_public"));
+
+    let requests = scrapi.get_requests_for_endpoint(Endpoint::DownloadConfigSpecs);
+    let query = requests
+        .last()
+        .unwrap()
+        .url
+        .query()
+        .unwrap_or_default()
+        .to_string();
+    assert!(query.contains("accept_deltas=true"), "{query}");
+}
+
+#[tokio::test]
+async fn test_full_specs_are_fetched_when_delta_does_not_apply() {
+    let scrapi = MockScrapi::new().await;
+    scrapi
+        .stub_without_query_param(
+            EndpointStub {
+                method: Method::GET,
+                response: load_contents("eval_proj_dcs.json"),
+                ..EndpointStub::with_endpoint(Endpoint::DownloadConfigSpecs)
+            },
+            "sinceTime",
+        )
+        .await;
+    scrapi
+        .stub(EndpointStub {
+            method: Method::GET,
+            response: make_delta(1),
+            ..EndpointStub::with_endpoint(Endpoint::DownloadConfigSpecs)
+        })
+        .await;
+
+    let statsig = setup(&scrapi).await;
+
+    let user = StatsigUser::with_user_id("a-user");
+    assert!(statsig.check_gate(&user, "test_public"));
+    assert_eq!(get_header_text(&statsig), "old user test");
+
+    let requests = scrapi.get_requests_for_endpoint(Endpoint::DownloadConfigSpecs);
+    assert!(requests[0]
+        .url
+        .query()
+        .unwrap_or_default()
+        .contains("sinceTime=0"));
+    assert!(!requests[1]
+        .url
+        .query()
+        .unwrap_or_default()
+        .contains("sinceTime"));
+}
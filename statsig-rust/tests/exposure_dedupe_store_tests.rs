@@ -0,0 +1,158 @@
+mod utils;
+
+use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+use statsig_rust::{
+    event_logging::exposure_sampling::ExposureSamplingKey, ExposureDedupeStore,
+    InMemoryExposureDedupeStore, SharedMemoryExposureDedupeStore, Statsig, StatsigOptions,
+    StatsigUser,
+};
+use std::sync::Arc;
+
+fn make_key(spec_name_hash: u64, user_values_hash: u64) -> ExposureSamplingKey {
+    ExposureSamplingKey {
+        spec_name_hash,
+        rule_id_hash: 2,
+        user_values_hash,
+        additional_hash: 0,
+    }
+}
+
+fn make_temp_path(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!(
+        "statsig_dedupe_{}_{}_{}",
+        name,
+        std::process::id(),
+        uuid::Uuid::new_v4()
+    ));
+    path.to_string_lossy().to_string()
+}
+
+#[test]
+fn test_in_memory_store_dedupes() {
+    let store = InMemoryExposureDedupeStore::new();
+
+    assert!(!store.check_and_insert(&make_key(1, 1)));
+    assert!(store.check_and_insert(&make_key(1, 1)));
+    assert!(!store.check_and_insert(&make_key(1, 2)));
+}
+
+#[test]
+fn test_shared_memory_store_dedupes_across_instances() {
+    let path = make_temp_path("across");
+    let store_a = SharedMemoryExposureDedupeStore::new(&path, 1024).unwrap();
+    let store_b = SharedMemoryExposureDedupeStore::new(&path, 1024).unwrap();
+
+    assert!(!store_a.check_and_insert(&make_key(1, 1)));
+    assert!(store_b.check_and_insert(&make_key(1, 1)));
+    assert!(!store_b.check_and_insert(&make_key(2, 1)));
+    assert!(store_a.check_and_insert(&make_key(2, 1)));
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_shared_memory_store_keeps_existing_capacity() {
+    let path = make_temp_path("capacity");
+    let store_a = SharedMemoryExposureDedupeStore::new(&path, 64).unwrap();
+    assert!(!store_a.check_and_insert(&make_key(3, 3)));
+
+    let store_b = SharedMemoryExposureDedupeStore::new(&path, 4096).unwrap();
+    assert!(store_b.check_and_insert(&make_key(3, 3)));
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_shared_memory_store_does_not_dedupe_when_full() {
+    let path = make_temp_path("full");
+    let store = SharedMemoryExposureDedupeStore::new(&path, 4).unwrap();
+
+    for i in 0..4 {
+        assert!(!store.check_and_insert(&make_key(i, i)));
+    }
+
+    let overflow = make_key(100, 100);
+    assert!(!store.check_and_insert(&overflow));
+    assert!(!store.check_and_insert(&overflow));
+
+    let _ = std::fs::remove_file(path);
+}
+
+#[test]
+fn test_shared_memory_store_rejects_zero_capacity() {
+    let path = make_temp_path("zero");
+    assert!(SharedMemoryExposureDedupeStore::new(&path, 0).is_err());
+}
+
+#[test]
+fn test_shared_memory_store_rejects_invalid_existing_capacity() {
+    let write_header = |path: &str, capacity: u64| {
+        let mut header = vec![0u8; 64];
+        header[0..8].copy_from_slice(&0x5354_4744_4455_5031u64.to_le_bytes());
+        header[8..16].copy_from_slice(&capacity.to_le_bytes());
+        std::fs::write(path, header).unwrap();
+    };
+
+    let zero = make_temp_path("existing-zero");
+    write_header(&zero, 0);
+    assert!(SharedMemoryExposureDedupeStore::new(&zero, 64).is_err());
+
+    let oversized = make_temp_path("existing-oversized");
+    write_header(&oversized, u64::MAX);
+    assert!(SharedMemoryExposureDedupeStore::new(&oversized, 64).is_err());
+
+    let _ = std::fs::remove_file(zero);
+    let _ = std::fs::remove_file(oversized);
+}
+
+#[tokio::test]
+async fn test_exposures_deduped_across_statsig_instances() {
+    let path = make_temp_path("statsig");
+    let user = StatsigUser::with_user_id("a_user_id");
+
+    let logging_adapter_a = Arc::new(MockEventLoggingAdapter::new());
+    let statsig_a = create_statsig(&path, &logging_adapter_a);
+    statsig_a.initialize().await.unwrap();
+
+    let logging_adapter_b = Arc::new(MockEventLoggingAdapter::new());
+    let statsig_b = create_statsig(&path, &logging_adapter_b);
+    statsig_b.initialize().await.unwrap();
+
+    let _ = statsig_a.check_gate(&user, "test_public");
+    let _ = statsig_b.check_gate(&user, "test_public");
+
+    statsig_a.flush_events().await;
+    statsig_b.flush_events().await;
+
+    assert_eq!(count_exposures(&logging_adapter_a), 1);
+    assert_eq!(count_exposures(&logging_adapter_b), 0);
+
+    let _ = std::fs::remove_file(path);
+}
+
+fn create_statsig(path: &str, logging_adapter: &Arc<MockEventLoggingAdapter>) -> Statsig {
+    let store = SharedMemoryExposureDedupeStore::new(path, 1024).unwrap();
+
+    Statsig::new(
+        "secret-shhh",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(logging_adapter.clone()),
+            exposure_dedupe_store: Some(Arc::new(store)),
+            ..StatsigOptions::new()
+        })),
+    )
+}
+
+fn count_exposures(logging_adapter: &MockEventLoggingAdapter) -> usize {
+    let payloads = logging_adapter.logged_payloads.lock().unwrap();
+    payloads
+        .iter()
+        .filter_map(|p| p.events.as_array())
+        .flatten()
+        .filter(|e| e["eventName"] == "statsig::gate_exposure")
+        .count()
+}
@@ -0,0 +1,153 @@
+mod utils;
+
+#[cfg(all(test, feature = "with_grpc"))]
+pub mod forward_proxy_server_tests {
+    use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+    use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+    use sigstat_grpc::statsig_grpc_client::StatsigGrpcClient;
+    use sigstat_grpc::statsig_grpc_server::StatsigGrpcServer;
+    use statsig_rust::{
+        SpecAdapterConfig, SpecsAdapterType, SpecsSource, Statsig, StatsigOptions, StatsigUser,
+    };
+    use std::net::{SocketAddr, TcpListener};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::Notify;
+
+    fn free_address() -> SocketAddr {
+        TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+    }
+
+    fn grpc_client(sdk_key: &str, address: SocketAddr) -> StatsigGrpcClient {
+        StatsigGrpcClient::new(
+            sdk_key,
+            &format!("http://{address}"),
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+    }
+
+    async fn spawn_server(sdk_key: &str) -> (Arc<StatsigGrpcServer>, SocketAddr, Arc<Notify>) {
+        let server = StatsigGrpcServer::new(sdk_key);
+        let address = free_address();
+        let shutdown = Arc::new(Notify::new());
+
+        let shutdown_clone = shutdown.clone();
+        tokio::spawn(server.clone().serve_with_shutdown(address, async move {
+            shutdown_clone.notified().await;
+        }));
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        (server, address, shutdown)
+    }
+
+    #[tokio::test]
+    async fn test_grpc_adapter_initializes_from_statsig_server() {
+        let sdk_key = "secret-forward-proxy-server";
+        let address = free_address();
+
+        let upstream = Statsig::new(
+            sdk_key,
+            Some(Arc::new(StatsigOptions {
+                specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                    "tests/data/eval_proj_dcs.json",
+                ))),
+                event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+                ..StatsigOptions::new()
+            })),
+        );
+        upstream.initialize().await.unwrap();
+        upstream.start_forward_proxy_server(address).unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+
+        let downstream = Statsig::new(
+            sdk_key,
+            Some(Arc::new(StatsigOptions {
+                spec_adapters_config: Some(vec![SpecAdapterConfig {
+                    adapter_type: SpecsAdapterType::NetworkGrpcWebsocket,
+                    specs_url: Some(format!("http://{address}")),
+                    init_timeout_ms: 3000,
+                    authentication_mode: None,
+                    ca_cert_path: None,
+                    client_cert_path: None,
+                    client_key_path: None,
+                    domain_name: None,
+                    grpc_stream_config: None,
+                }]),
+                event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+                ..StatsigOptions::new()
+            })),
+        );
+        let details = downstream.initialize_with_details().await.unwrap();
+        assert_eq!(details.source, SpecsSource::Adapter("GRPC".to_string()));
+
+        for user_id in ["a_user", "b_user", "c_user"] {
+            let user = StatsigUser::with_user_id(user_id);
+            for gate in ["test_public", "test_50_50", "test_nested_gate_condition"] {
+                assert_eq!(
+                    upstream.check_gate(&user, gate),
+                    downstream.check_gate(&user, gate)
+                );
+            }
+        }
+
+        downstream.shutdown().await.unwrap();
+        upstream.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_streams_newer_specs_only() {
+        let (server, address, shutdown) = spawn_server("secret-key").await;
+        server.publish_specs("first".to_string(), 100);
+
+        let client = grpc_client("secret-key", address);
+        let mut stream = client.get_specs_stream(None, None).await.unwrap();
+        let first = stream.message().await.unwrap().unwrap();
+        assert_eq!(first.spec, "first");
+        assert_eq!(first.zstd_dict_id, None);
+
+        server.publish_specs("stale".to_string(), 50);
+        server.publish_specs("second".to_string(), 200);
+        let second = stream.message().await.unwrap().unwrap();
+        assert_eq!(second.spec, "second");
+        assert_eq!(second.last_updated, 200);
+
+        shutdown.notify_one();
+    }
+
+    #[tokio::test]
+    async fn test_stream_skips_specs_the_client_already_has() {
+        let (server, address, shutdown) = spawn_server("secret-key").await;
+        server.publish_specs("current".to_string(), 100);
+
+        let client = grpc_client("secret-key", address);
+        let mut stream = client.get_specs_stream(Some(100), None).await.unwrap();
+
+        server.publish_specs("next".to_string(), 101);
+        let update = stream.message().await.unwrap().unwrap();
+        assert_eq!(update.spec, "next");
+
+        shutdown.notify_one();
+    }
+
+    #[tokio::test]
+    async fn test_rejects_other_sdk_keys() {
+        let (server, address, shutdown) = spawn_server("secret-key").await;
+        server.publish_specs("specs".to_string(), 100);
+
+        let client = grpc_client("secret-other-key", address);
+        assert!(client.get_specs(None, None).await.is_err());
+        assert!(client.get_specs_stream(None, None).await.is_err());
+
+        let client = grpc_client("secret-key", address);
+        assert_eq!(client.get_specs(None, None).await.unwrap().spec, "specs");
+
+        shutdown.notify_one();
+    }
+}
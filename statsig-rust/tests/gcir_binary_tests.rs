@@ -0,0 +1,121 @@
+mod utils;
+
+use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+use assert_json_diff::assert_json_eq;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use serde_json::{json, Value};
+use statsig_rust::{
+    ClientInitResponseOptions, GCIRBinaryCodec, GCIRResponseFormat, HashAlgorithm, Statsig,
+    StatsigOptions, StatsigUser,
+};
+use std::sync::Arc;
+
+async fn setup() -> Statsig {
+    let mut options = StatsigOptions::new();
+    options.specs_adapter = Some(Arc::new(MockSpecsAdapter::with_data(
+        "tests/data/eval_proj_dcs.json",
+    )));
+    options.event_logging_adapter = Some(Arc::new(MockEventLoggingAdapter::new()));
+
+    let statsig = Statsig::new("secret-key", Some(Arc::new(options)));
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn options(response_format: GCIRResponseFormat) -> ClientInitResponseOptions {
+    ClientInitResponseOptions {
+        hash_algorithm: Some(HashAlgorithm::None),
+        response_format: Some(response_format),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_binary_decodes_to_v2_json() {
+    let statsig = setup().await;
+    let user = StatsigUser::with_user_id("a_user");
+
+    let v2 = statsig.get_client_init_response_with_options_as_string(
+        &user,
+        &options(GCIRResponseFormat::InitializeWithSecondaryExposureMapping),
+    );
+    let binary = statsig.get_client_init_response_with_options_as_bytes(
+        &user,
+        &options(GCIRResponseFormat::InitializeCompactBinary),
+    );
+
+    let expected: Value = serde_json::from_str(&v2).unwrap();
+    let decoded = GCIRBinaryCodec::decode(&binary).unwrap();
+
+    assert_json_eq!(decoded, expected);
+    assert!(binary.len() < v2.len());
+}
+
+#[tokio::test]
+async fn test_binary_as_string_is_base64() {
+    let statsig = setup().await;
+    let user = StatsigUser::with_user_id("a_user");
+    let options = options(GCIRResponseFormat::InitializeCompactBinary);
+
+    let as_string = statsig.get_client_init_response_with_options_as_string(&user, &options);
+    let as_bytes = statsig.get_client_init_response_with_options_as_bytes(&user, &options);
+
+    let decoded_string =
+        GCIRBinaryCodec::decode(&BASE64_STANDARD.decode(as_string).unwrap()).unwrap();
+    let decoded_bytes = GCIRBinaryCodec::decode(&as_bytes).unwrap();
+
+    assert_json_eq!(decoded_string, decoded_bytes);
+}
+
+#[test]
+fn test_binary_format_from_string() {
+    assert!(matches!(
+        GCIRResponseFormat::from_string("binary"),
+        Some(GCIRResponseFormat::InitializeCompactBinary)
+    ));
+}
+
+#[test]
+fn test_round_trips_json_values() {
+    let value = json!({
+        "bool": true,
+        "null": null,
+        "negative": -12345,
+        "large": u64::MAX,
+        "float": 1.5,
+        "nested": [{"name": "a_gate"}, {"name": "a_gate"}, []],
+        "repeated": ["a_gate", "a_gate", "another_gate", "a_gate"],
+        "unicode": "héllo wörld",
+    });
+
+    let bytes = GCIRBinaryCodec::encode(&value).unwrap();
+
+    assert_eq!(GCIRBinaryCodec::decode(&bytes).unwrap(), value);
+}
+
+#[test]
+fn test_repeated_strings_are_interned() {
+    let long_name = "a_very_long_gate_name_used_in_many_secondary_exposures";
+    let once = GCIRBinaryCodec::encode(&vec![long_name]).unwrap();
+    let many = GCIRBinaryCodec::encode(&vec![long_name; 100]).unwrap();
+
+    assert!(many.len() < once.len() + 100 * 3);
+}
+
+#[test]
+fn test_rejects_invalid_input() {
+    let bytes = GCIRBinaryCodec::encode(&json!({"key": "value"})).unwrap();
+
+    assert!(GCIRBinaryCodec::decode(b"{\"key\": \"value\"}").is_err());
+    assert!(GCIRBinaryCodec::decode(&bytes[..bytes.len() - 1]).is_err());
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(GCIRBinaryCodec::decode(&trailing).is_err());
+
+    let mut bad_version = bytes.clone();
+    bad_version[4] = 99;
+    assert!(GCIRBinaryCodec::decode(&bad_version).is_err());
+}
@@ -0,0 +1,327 @@
+mod utils;
+
+use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use crate::utils::mock_observability_client::MockObservabilityClient;
+use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+use serde_json::Value;
+use statsig_rust::{
+    dyn_value, ClientInitResponseOptions, ComputedUserAttributeProvider, DynamicValue,
+    GCIRResponseFormat, HashAlgorithm, ObservabilityClient, Statsig, StatsigOptions, StatsigUser,
+    StatsigUserBuilder,
+};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+
+const HIT: &str = "statsig.sdk.gcir_cache_hit";
+const MISS: &str = "statsig.sdk.gcir_cache_miss";
+
+struct TestContext {
+    statsig: Statsig,
+    specs_adapter: Arc<MockSpecsAdapter>,
+    observability_client: Arc<MockObservabilityClient>,
+}
+
+async fn setup(sdk_key: &str, gcir_cache_max_entries: Option<u32>) -> TestContext {
+    let options = StatsigOptions {
+        gcir_cache_max_entries,
+        ..StatsigOptions::new()
+    };
+    setup_with_options(sdk_key, options).await
+}
+
+async fn setup_with_options(sdk_key: &str, options: StatsigOptions) -> TestContext {
+    let specs_adapter = Arc::new(MockSpecsAdapter::with_data(
+        "tests/data/dcs_with_fields_used.json",
+    ));
+    let observability_client = Arc::new(MockObservabilityClient::new());
+    let weak_client = Arc::downgrade(&observability_client) as Weak<dyn ObservabilityClient>;
+
+    let statsig = Statsig::new(
+        sdk_key,
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(specs_adapter.clone()),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            observability_client: Some(weak_client),
+            ..options
+        })),
+    );
+    statsig.initialize().await.unwrap();
+
+    TestContext {
+        statsig,
+        specs_adapter,
+        observability_client,
+    }
+}
+
+fn options_for_gates(gates: &[&str]) -> ClientInitResponseOptions {
+    ClientInitResponseOptions {
+        hash_algorithm: Some(HashAlgorithm::None),
+        feature_gate_filter: Some(gates.iter().map(|g| (*g).to_string()).collect()),
+        experiment_filter: Some(HashSet::new()),
+        dynamic_config_filter: Some(HashSet::from(["country_config".to_string()])),
+        layer_filter: Some(HashSet::new()),
+        ..Default::default()
+    }
+}
+
+fn user_with_email(user_id: &str, email: &str) -> StatsigUser {
+    StatsigUserBuilder::new_with_user_id(user_id.to_string())
+        .email(Some(email.to_string()))
+        .build()
+}
+
+fn get_response(
+    statsig: &Statsig,
+    user: &StatsigUser,
+    options: &ClientInitResponseOptions,
+) -> Value {
+    let response = statsig.get_client_init_response_with_options_as_string(user, options);
+    serde_json::from_str(&response).unwrap()
+}
+
+fn count_metric(client: &MockObservabilityClient, name: &str, reason: Option<&str>) -> usize {
+    client
+        .increment_calls
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|(metric_name, _, tags)| {
+            metric_name == name
+                && reason.is_none_or(|r| {
+                    tags.as_ref()
+                        .and_then(|t| t.get("reason"))
+                        .is_some_and(|v| v == r)
+                })
+        })
+        .count()
+}
+
+#[tokio::test]
+async fn test_cache_hit_when_only_unused_fields_differ() {
+    let ctx = setup("secret-gcir-cache-hit", Some(100)).await;
+    let options = options_for_gates(&["public_gate", "email_gate"]);
+
+    let user_a = StatsigUserBuilder::new_with_user_id("user_a".to_string())
+        .email(Some("a@statsig.io".to_string()))
+        .locale(Some("en_US".to_string()))
+        .build();
+    let user_b = StatsigUserBuilder::new_with_user_id("user_b".to_string())
+        .email(Some("a@statsig.io".to_string()))
+        .locale(Some("fr_FR".to_string()))
+        .build();
+
+    let response_a = get_response(&ctx.statsig, &user_a, &options);
+    let response_b = get_response(&ctx.statsig, &user_b, &options);
+
+    assert_eq!(response_a["feature_gates"], response_b["feature_gates"]);
+    assert_eq!(response_a["dynamic_configs"], response_b["dynamic_configs"]);
+    assert_eq!(response_b["user"]["userID"], "user_b");
+    assert_eq!(response_b["evaluated_keys"]["userID"], "user_b");
+
+    let client = &ctx.observability_client;
+    assert_eventually!(|| count_metric(client, HIT, None) == 1);
+    assert_eq!(count_metric(client, MISS, Some("not_found")), 1);
+}
+
+#[tokio::test]
+async fn test_cache_miss_when_used_fields_differ() {
+    let ctx = setup("secret-gcir-cache-used-fields", Some(100)).await;
+    let options = options_for_gates(&["email_gate"]);
+
+    let statsig_user = user_with_email("user", "a@statsig.io");
+    let other_user = user_with_email("user", "a@example.com");
+
+    let statsig_response = get_response(&ctx.statsig, &statsig_user, &options);
+    let other_response = get_response(&ctx.statsig, &other_user, &options);
+
+    assert_eq!(
+        statsig_response["feature_gates"]["email_gate"]["value"],
+        true
+    );
+    assert_eq!(
+        other_response["feature_gates"]["email_gate"]["value"],
+        false
+    );
+
+    let client = &ctx.observability_client;
+    assert_eventually!(|| count_metric(client, MISS, Some("not_found")) == 2);
+    assert_eq!(count_metric(client, HIT, None), 0);
+}
+
+#[tokio::test]
+async fn test_cache_key_includes_nested_gate_fields() {
+    let ctx = setup("secret-gcir-cache-nested", Some(100)).await;
+    let options = options_for_gates(&["nested_email_gate"]);
+
+    let statsig_response = get_response(
+        &ctx.statsig,
+        &user_with_email("a", "a@statsig.io"),
+        &options,
+    );
+    let other_response = get_response(
+        &ctx.statsig,
+        &user_with_email("a", "a@example.com"),
+        &options,
+    );
+
+    assert_eq!(
+        statsig_response["feature_gates"]["nested_email_gate"]["value"],
+        true
+    );
+    assert_eq!(
+        other_response["feature_gates"]["nested_email_gate"]["value"],
+        false
+    );
+}
+
+#[tokio::test]
+async fn test_cache_key_includes_unit_id_for_partial_rollouts() {
+    let ctx = setup("secret-gcir-cache-partial", Some(100)).await;
+    let options = options_for_gates(&["partial_rollout_gate"]);
+
+    let _ = get_response(&ctx.statsig, &StatsigUser::with_user_id("a"), &options);
+    let _ = get_response(&ctx.statsig, &StatsigUser::with_user_id("b"), &options);
+    let _ = get_response(&ctx.statsig, &StatsigUser::with_user_id("a"), &options);
+
+    let client = &ctx.observability_client;
+    assert_eventually!(|| count_metric(client, HIT, None) == 1);
+    assert_eq!(count_metric(client, MISS, Some("not_found")), 2);
+}
+
+#[tokio::test]
+async fn test_uncacheable_specs_are_not_cached() {
+    let ctx = setup("secret-gcir-cache-uncacheable", Some(100)).await;
+    let user = StatsigUser::with_user_id("a");
+
+    for gate in ["time_gate", "gate_without_fields_used"] {
+        let options = options_for_gates(&[gate]);
+        let _ = get_response(&ctx.statsig, &user, &options);
+        let _ = get_response(&ctx.statsig, &user, &options);
+    }
+
+    let local_overrides = ClientInitResponseOptions {
+        include_local_overrides: Some(true),
+        ..options_for_gates(&["public_gate"])
+    };
+    let _ = get_response(&ctx.statsig, &user, &local_overrides);
+
+    let client = &ctx.observability_client;
+    assert_eventually!(|| count_metric(client, MISS, Some("uncacheable")) == 5);
+    assert_eq!(count_metric(client, HIT, None), 0);
+}
+
+#[tokio::test]
+async fn test_cache_invalidated_when_specs_update() {
+    let ctx = setup("secret-gcir-cache-invalidate", Some(100)).await;
+    let options = options_for_gates(&["public_gate"]);
+    let user = StatsigUser::with_user_id("a");
+
+    let _ = get_response(&ctx.statsig, &user, &options);
+    let _ = get_response(&ctx.statsig, &user, &options);
+
+    ctx.specs_adapter.resync().await;
+
+    let _ = get_response(&ctx.statsig, &user, &options);
+
+    let client = &ctx.observability_client;
+    assert_eventually!(|| count_metric(client, MISS, Some("not_found")) == 2);
+    assert_eq!(count_metric(client, HIT, None), 1);
+}
+
+#[tokio::test]
+async fn test_formats_are_cached_separately() {
+    let ctx = setup("secret-gcir-cache-formats", Some(100)).await;
+    let user = StatsigUser::with_user_id("a");
+
+    let v1 = options_for_gates(&["public_gate"]);
+    let v2 = ClientInitResponseOptions {
+        response_format: Some(GCIRResponseFormat::InitializeWithSecondaryExposureMapping),
+        ..options_for_gates(&["public_gate"])
+    };
+
+    let v1_response = get_response(&ctx.statsig, &user, &v1);
+    let v2_response = get_response(&ctx.statsig, &user, &v2);
+    let v2_cached_response = get_response(&ctx.statsig, &user, &v2);
+
+    assert!(v1_response.get("exposures").is_none());
+    assert_eq!(
+        v2_response["feature_gates"],
+        v2_cached_response["feature_gates"]
+    );
+    assert_eq!(v2_response["exposures"], v2_cached_response["exposures"]);
+
+    let client = &ctx.observability_client;
+    assert_eventually!(|| count_metric(client, HIT, None) == 1);
+    assert_eq!(count_metric(client, MISS, Some("not_found")), 2);
+}
+
+#[tokio::test]
+async fn test_cache_disabled_by_default() {
+    let ctx = setup("secret-gcir-cache-disabled", None).await;
+    let options = options_for_gates(&["public_gate"]);
+    let user = StatsigUser::with_user_id("a");
+
+    let _ = get_response(&ctx.statsig, &user, &options);
+    let _ = get_response(&ctx.statsig, &user, &options);
+
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+    let client = &ctx.observability_client;
+    assert_eq!(count_metric(client, HIT, None), 0);
+    assert_eq!(count_metric(client, MISS, None), 0);
+}
+
+#[tokio::test]
+async fn test_least_recently_used_entry_is_evicted_when_full() {
+    let ctx = setup("secret-gcir-cache-lru", Some(2)).await;
+    let options = options_for_gates(&["partial_rollout_gate"]);
+
+    for user_id in ["a", "b", "a", "c", "a", "b"] {
+        let _ = get_response(&ctx.statsig, &StatsigUser::with_user_id(user_id), &options);
+    }
+
+    // "a" is used again before "c" is added, so "b" is the one evicted
+    let client = &ctx.observability_client;
+    assert_eventually!(|| count_metric(client, HIT, None) == 2);
+    assert_eq!(count_metric(client, MISS, Some("not_found")), 4);
+}
+
+#[derive(Default)]
+struct CountingEmailProvider {
+    calls: AtomicUsize,
+}
+
+impl ComputedUserAttributeProvider for CountingEmailProvider {
+    fn compute(&self, _user: &StatsigUser) -> Option<DynamicValue> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        Some(dyn_value!("computed@statsig.io"))
+    }
+}
+
+#[tokio::test]
+async fn test_computed_values_are_computed_once_per_request() {
+    let provider = Arc::new(CountingEmailProvider::default());
+    let ctx = setup_with_options(
+        "secret-gcir-cache-computed",
+        StatsigOptions {
+            gcir_cache_max_entries: Some(100),
+            computed_user_attributes: Some(HashMap::from([(
+                "email".to_string(),
+                provider.clone() as Arc<dyn ComputedUserAttributeProvider>,
+            )])),
+            ..StatsigOptions::new()
+        },
+    )
+    .await;
+    let options = options_for_gates(&["email_gate"]);
+    let user = StatsigUser::with_user_id("a");
+
+    let response = get_response(&ctx.statsig, &user, &options);
+    assert_eq!(response["feature_gates"]["email_gate"]["value"], true);
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+
+    let _ = get_response(&ctx.statsig, &user, &options);
+    assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+}
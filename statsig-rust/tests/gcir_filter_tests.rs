@@ -0,0 +1,179 @@
+mod utils;
+
+use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+use serde_json::Value;
+use statsig_rust::{
+    ClientInitResponseOptions, HashAlgorithm, Statsig, StatsigOptions, StatsigUser,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+async fn setup(sdk_key: &str) -> Statsig {
+    let statsig = Statsig::new(
+        sdk_key,
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/dcs_with_target_app_ids.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn get_response(statsig: &Statsig, options: ClientInitResponseOptions) -> Value {
+    let options = ClientInitResponseOptions {
+        hash_algorithm: Some(HashAlgorithm::None),
+        ..options
+    };
+    let user = StatsigUser::with_user_id("a_user");
+    let response = statsig.get_client_init_response_with_options_as_string(&user, &options);
+    serde_json::from_str(&response).unwrap()
+}
+
+fn set(values: &[&str]) -> Option<HashSet<String>> {
+    Some(values.iter().map(|v| (*v).to_string()).collect())
+}
+
+fn names(response: &Value, section: &str) -> HashSet<String> {
+    response[section]
+        .as_object()
+        .map(|o| o.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[tokio::test]
+async fn test_entity_filters_accept_globs() {
+    let statsig = setup("secret-gcir-filter-globs").await;
+
+    let response = get_response(
+        &statsig,
+        ClientInitResponseOptions {
+            feature_gate_filter: set(&["checkout_*", "mobile_gat?"]),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        names(&response, "feature_gates"),
+        set(&["checkout_gate", "checkout_button_gate", "mobile_gate"]).unwrap()
+    );
+    assert_eq!(
+        names(&response, "dynamic_configs"),
+        set(&["checkout_config"]).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_exact_names_still_match() {
+    let statsig = setup("secret-gcir-filter-exact").await;
+
+    let response = get_response(
+        &statsig,
+        ClientInitResponseOptions {
+            feature_gate_filter: set(&["internal_gate"]),
+            param_store_filter: set(&["mobile_store"]),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        names(&response, "feature_gates"),
+        set(&["internal_gate"]).unwrap()
+    );
+    assert_eq!(
+        names(&response, "param_stores"),
+        set(&["mobile_store"]).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_exclude_filter_applies_to_all_entities() {
+    let statsig = setup("secret-gcir-filter-exclude").await;
+
+    let response = get_response(
+        &statsig,
+        ClientInitResponseOptions {
+            feature_gate_filter: set(&["checkout_*"]),
+            exclude_filter: Some(vec!["*_button_*".to_string(), "*_config".to_string()]),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        names(&response, "feature_gates"),
+        set(&["checkout_gate"]).unwrap()
+    );
+    assert!(names(&response, "dynamic_configs").is_empty());
+}
+
+#[tokio::test]
+async fn test_entity_type_filter() {
+    let statsig = setup("secret-gcir-filter-entity-type").await;
+
+    let response = get_response(
+        &statsig,
+        ClientInitResponseOptions {
+            entity_type_filter: set(&["dynamic_config", "param_store"]),
+            ..Default::default()
+        },
+    );
+
+    assert!(names(&response, "feature_gates").is_empty());
+    assert_eq!(
+        names(&response, "dynamic_configs"),
+        set(&["checkout_config"]).unwrap()
+    );
+    assert_eq!(
+        names(&response, "param_stores"),
+        set(&["checkout_store", "mobile_store"]).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_target_app_id_filter() {
+    let statsig = setup("secret-gcir-filter-target-app").await;
+
+    let response = get_response(
+        &statsig,
+        ClientInitResponseOptions {
+            target_app_id_filter: set(&["app_mobile"]),
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(
+        names(&response, "feature_gates"),
+        set(&["checkout_button_gate", "mobile_gate"]).unwrap()
+    );
+    assert!(names(&response, "dynamic_configs").is_empty());
+    assert_eq!(
+        names(&response, "param_stores"),
+        set(&["mobile_store"]).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_filters_deserialize_from_json() {
+    let statsig = setup("secret-gcir-filter-json").await;
+
+    let options: ClientInitResponseOptions = serde_json::from_str(
+        r#"{
+            "feature_gate_filter": ["checkout_*"],
+            "exclude_filter": ["checkout_button_*"],
+            "entity_type_filter": ["feature_gate"]
+        }"#,
+    )
+    .unwrap();
+    let response = get_response(&statsig, options);
+
+    assert_eq!(
+        names(&response, "feature_gates"),
+        set(&["checkout_gate"]).unwrap()
+    );
+    assert!(names(&response, "dynamic_configs").is_empty());
+    assert!(names(&response, "param_stores").is_empty());
+}
@@ -0,0 +1,154 @@
+mod utils;
+
+use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use serde_json::{json, Value};
+use statsig_rust::{
+    ClientInitResponseOptions, GCIRBinaryCodec, GCIRResponseFormat, GCIRSigner, GCIRSigningKey,
+    GCIRVerificationKey, HashAlgorithm, Statsig, StatsigOptions, StatsigUser,
+};
+use std::sync::Arc;
+
+async fn setup() -> Statsig {
+    let statsig = Statsig::new(
+        "secret-gcir-signing",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn ed25519_key() -> GCIRSigningKey {
+    GCIRSigningKey::Ed25519(BASE64_STANDARD.encode([7u8; 32]))
+}
+
+fn signed_options(signing_key: GCIRSigningKey) -> ClientInitResponseOptions {
+    ClientInitResponseOptions {
+        hash_algorithm: Some(HashAlgorithm::None),
+        signing_key: Some(signing_key),
+        ..Default::default()
+    }
+}
+
+fn get_response(statsig: &Statsig, options: &ClientInitResponseOptions) -> String {
+    let user = StatsigUser::with_user_id("a_user");
+    statsig.get_client_init_response_with_options_as_string(&user, options)
+}
+
+#[tokio::test]
+async fn test_ed25519_signed_response_verifies() {
+    let statsig = setup().await;
+    let key = ed25519_key();
+
+    let response = get_response(&statsig, &signed_options(key.clone()));
+    let json: Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(json["signature"]["algorithm"], "ed25519");
+
+    let verification_key = key.verification_key().unwrap();
+    assert!(GCIRSigner::verify(&response, &verification_key).unwrap());
+}
+
+#[tokio::test]
+async fn test_tampered_response_fails_verification() {
+    let statsig = setup().await;
+    let key = ed25519_key();
+
+    let response = get_response(&statsig, &signed_options(key.clone()));
+    let mut json: Value = serde_json::from_str(&response).unwrap();
+    assert_eq!(json["feature_gates"]["test_public"]["value"], true);
+    json["feature_gates"]["test_public"]["value"] = json!(false);
+
+    let verification_key = key.verification_key().unwrap();
+    assert!(!GCIRSigner::verify_value(&json, &verification_key).unwrap());
+}
+
+#[tokio::test]
+async fn test_hmac_signed_response_verifies() {
+    let statsig = setup().await;
+
+    let response = get_response(
+        &statsig,
+        &signed_options(GCIRSigningKey::HmacSha256("shared-secret".to_string())),
+    );
+
+    let valid = GCIRVerificationKey::HmacSha256("shared-secret".to_string());
+    let invalid = GCIRVerificationKey::HmacSha256("another-secret".to_string());
+    assert!(GCIRSigner::verify(&response, &valid).unwrap());
+    assert!(!GCIRSigner::verify(&response, &invalid).unwrap());
+}
+
+#[tokio::test]
+async fn test_signed_binary_response_verifies() {
+    let statsig = setup().await;
+    let key = ed25519_key();
+    let options = ClientInitResponseOptions {
+        response_format: Some(GCIRResponseFormat::InitializeCompactBinary),
+        ..signed_options(key.clone())
+    };
+
+    let bytes = statsig.get_client_init_response_with_options_as_bytes(
+        &StatsigUser::with_user_id("a_user"),
+        &options,
+    );
+    let decoded = GCIRBinaryCodec::decode(&bytes).unwrap();
+
+    let verification_key = key.verification_key().unwrap();
+    assert!(GCIRSigner::verify_value(&decoded, &verification_key).unwrap());
+}
+
+#[tokio::test]
+async fn test_verify_errors_on_unsigned_or_mismatched_algorithm() {
+    let statsig = setup().await;
+
+    let unsigned = get_response(
+        &statsig,
+        &ClientInitResponseOptions {
+            hash_algorithm: Some(HashAlgorithm::None),
+            ..Default::default()
+        },
+    );
+    let verification_key = ed25519_key().verification_key().unwrap();
+    assert!(GCIRSigner::verify(&unsigned, &verification_key).is_err());
+
+    let hmac_signed = get_response(
+        &statsig,
+        &signed_options(GCIRSigningKey::HmacSha256("shared-secret".to_string())),
+    );
+    assert!(GCIRSigner::verify(&hmac_signed, &verification_key).is_err());
+}
+
+#[test]
+fn test_signature_does_not_depend_on_key_order() {
+    let key = GCIRSigningKey::HmacSha256("shared-secret".to_string());
+    let verification_key = key.verification_key().unwrap();
+
+    let mut response = json!({"b": {"y": 1, "x": [true, null]}, "a": "value"});
+    GCIRSigner::sign(&mut response, &key).unwrap();
+
+    let reordered = format!(
+        r#"{{"signature": {}, "a": "value", "b": {{"x": [true, null], "y": 1}}}}"#,
+        response["signature"]
+    );
+    assert!(GCIRSigner::verify(&reordered, &verification_key).unwrap());
+}
+
+#[test]
+fn test_signing_key_deserializes_from_options_json() {
+    let options: ClientInitResponseOptions = serde_json::from_str(
+        r#"{"signing_key": {"algorithm": "hmac_sha256", "key": "shared-secret"}}"#,
+    )
+    .unwrap();
+
+    assert!(matches!(
+        options.signing_key,
+        Some(GCIRSigningKey::HmacSha256(ref secret)) if secret == "shared-secret"
+    ));
+}
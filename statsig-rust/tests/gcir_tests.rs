@@ -44,7 +44,11 @@ async fn setup(hash_algorithm: HashAlgorithm) -> Value {
             dynamic_config_filter: None,
             layer_filter: None,
             param_store_filter: None,
+            exclude_filter: None,
+            entity_type_filter: None,
+            target_app_id_filter: None,
             response_format: None,
+            signing_key: None,
         },
     );
     let json = serde_json::to_string(&response).unwrap();
@@ -0,0 +1,180 @@
+mod utils;
+
+use statsig_rust::{
+    dyn_value, DynamicValue, GeoIpProvider, Statsig, StatsigOptions, StatsigUser,
+    StatsigUserBuilder,
+};
+use std::sync::{Arc, Mutex};
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+struct FixedGeoIpProvider {
+    country: &'static str,
+    lookups: Mutex<Vec<(String, String)>>,
+}
+
+impl GeoIpProvider for FixedGeoIpProvider {
+    fn get_value(&self, ip: &str, field: &str) -> Option<DynamicValue> {
+        self.lookups
+            .lock()
+            .unwrap()
+            .push((ip.to_string(), field.to_string()));
+
+        match field {
+            "country" => Some(dyn_value!(self.country)),
+            _ => None,
+        }
+    }
+}
+
+async fn setup(provider: Arc<dyn GeoIpProvider>) -> Statsig {
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            geo_ip_provider: Some(provider),
+            wait_for_country_lookup_init: Some(true),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn user_with_ip(ip: &str) -> StatsigUser {
+    StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .ip(Some(ip.to_string()))
+        .build()
+}
+
+#[tokio::test]
+async fn test_provider_resolves_ip_based_conditions() {
+    let provider = Arc::new(FixedGeoIpProvider {
+        country: "CA",
+        lookups: Mutex::new(vec![]),
+    });
+    let statsig = setup(provider.clone()).await;
+
+    assert!(statsig.check_gate(&user_with_ip("2001:db8::1"), "test_country"));
+    assert_eq!(
+        provider.lookups.lock().unwrap().as_slice(),
+        &[("2001:db8::1".to_string(), "country".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn test_provider_replaces_bundled_lookup() {
+    let provider = Arc::new(FixedGeoIpProvider {
+        country: "NZ",
+        lookups: Mutex::new(vec![]),
+    });
+    let statsig = setup(provider).await;
+
+    // 8.8.8.8 is in the US according to the bundled table
+    assert!(!statsig.check_gate(&user_with_ip("8.8.8.8"), "test_country"));
+}
+
+#[tokio::test]
+async fn test_user_country_takes_precedence_over_provider() {
+    let provider = Arc::new(FixedGeoIpProvider {
+        country: "NZ",
+        lookups: Mutex::new(vec![]),
+    });
+    let statsig = setup(provider.clone()).await;
+
+    let user = StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .ip(Some("8.8.8.8".to_string()))
+        .country(Some("US".to_string()))
+        .build();
+
+    assert!(statsig.check_gate(&user, "test_country"));
+    assert!(provider.lookups.lock().unwrap().is_empty());
+}
+
+#[cfg(feature = "with_mmdb")]
+mod mmdb {
+    use super::*;
+    use statsig_rust::MmdbGeoIpProvider;
+
+    // geo_ip_test.mmdb maps 1.2.3.0/24 to San Francisco, CA, US (AS13335) and 2001:db8::/32 to
+    // Berlin, BE, DE (AS3320)
+    const TEST_DB: &str = "tests/data/geo_ip_test.mmdb";
+
+    fn get_string(provider: &MmdbGeoIpProvider, ip: &str, field: &str) -> Option<String> {
+        provider
+            .get_value(ip, field)
+            .and_then(|v| v.string_value)
+            .map(|s| s.value.to_string())
+    }
+
+    #[test]
+    fn test_mmdb_fields() {
+        let provider = MmdbGeoIpProvider::open(TEST_DB).unwrap();
+
+        assert_eq!(
+            get_string(&provider, "1.2.3.4", "country").as_deref(),
+            Some("US")
+        );
+        assert_eq!(
+            get_string(&provider, "1.2.3.4", "continent").as_deref(),
+            Some("NA")
+        );
+        assert_eq!(
+            get_string(&provider, "1.2.3.4", "region").as_deref(),
+            Some("CA")
+        );
+        assert_eq!(
+            get_string(&provider, "1.2.3.4", "city").as_deref(),
+            Some("San Francisco")
+        );
+        assert_eq!(
+            provider
+                .get_value("1.2.3.4", "asn")
+                .and_then(|v| v.int_value),
+            Some(13335)
+        );
+
+        assert_eq!(
+            get_string(&provider, "2001:db8::1", "country").as_deref(),
+            Some("DE")
+        );
+        assert_eq!(
+            get_string(&provider, "2001:db8::1", "region").as_deref(),
+            Some("BE")
+        );
+        assert_eq!(
+            get_string(&provider, "2001:db8::1", "city").as_deref(),
+            Some("Berlin")
+        );
+    }
+
+    #[test]
+    fn test_mmdb_unknown_addresses_and_fields() {
+        let provider = MmdbGeoIpProvider::open(TEST_DB).unwrap();
+
+        assert_eq!(get_string(&provider, "1.2.4.1", "country"), None);
+        assert_eq!(get_string(&provider, "1.2.3.4", "not_a_field"), None);
+        assert_eq!(get_string(&provider, "not an ip", "country"), None);
+        assert_eq!(
+            get_string(&provider, "::ffff:1.2.3.4", "country").as_deref(),
+            Some("US")
+        );
+    }
+
+    #[test]
+    fn test_mmdb_missing_file() {
+        assert!(MmdbGeoIpProvider::open("tests/data/does_not_exist.mmdb").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_mmdb_provider_evaluation() {
+        let provider = Arc::new(MmdbGeoIpProvider::open(TEST_DB).unwrap());
+        let statsig = setup(provider).await;
+
+        assert!(statsig.check_gate(&user_with_ip("1.2.3.4"), "test_country"));
+        assert!(!statsig.check_gate(&user_with_ip("2001:db8::1"), "test_country"));
+    }
+}
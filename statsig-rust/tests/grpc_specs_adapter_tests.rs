@@ -2,26 +2,26 @@ mod utils;
 
 #[cfg(all(test, feature = "with_grpc"))]
 pub mod specs_adapter_tests {
+    use crate::assert_eventually;
+    use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+    use crate::utils::mock_observability_client::MockObservabilityClient;
+    use crate::utils::mock_scrapi::{Endpoint, EndpointStub, Method, MockScrapi};
     use crate::utils::mock_specs_listener::MockSpecsListener;
     use mock_forward_proxy::{api::ConfigSpecResponse, wait_one_ms, MockForwardProxy};
     use sigstat_grpc::*;
     use statsig_rust::output_logger::{initialize_output_logger, LogLevel};
     use statsig_rust::{
-        SpecAdapterConfig, SpecsAdapter, SpecsAdapterType, SpecsSource, StatsigGrpcSpecsAdapter,
-        StatsigRuntime,
+        GrpcStreamConfig, ObservabilityClient, SpecAdapterConfig, SpecsAdapter, SpecsAdapterType,
+        SpecsSource, Statsig, StatsigGrpcSpecsAdapter, StatsigOptions, StatsigRuntime,
     };
-    use std::sync::Arc;
+    use std::sync::{Arc, Weak};
     use std::time::Duration;
 
-    async fn setup() -> (
-        Arc<MockForwardProxy>,
-        Arc<MockSpecsListener>,
-        Arc<StatsigGrpcSpecsAdapter>,
-    ) {
-        initialize_output_logger(&Some(LogLevel::Debug), None);
-
-        let mock_proxy = MockForwardProxy::spawn().await;
-        let config = SpecAdapterConfig {
+    fn grpc_config(
+        mock_proxy: &MockForwardProxy,
+        grpc_stream_config: Option<GrpcStreamConfig>,
+    ) -> SpecAdapterConfig {
+        SpecAdapterConfig {
             adapter_type: SpecsAdapterType::NetworkGrpcWebsocket,
             specs_url: Some(format!("http://{}", mock_proxy.proxy_address)),
             init_timeout_ms: 3000,
@@ -30,8 +30,49 @@ pub mod specs_adapter_tests {
             client_cert_path: None,
             client_key_path: None,
             domain_name: None,
-        };
-        let adapter = Arc::new(StatsigGrpcSpecsAdapter::new("secret-key", &config, None));
+            grpc_stream_config,
+        }
+    }
+
+    fn fast_reconnect_config() -> GrpcStreamConfig {
+        GrpcStreamConfig {
+            initial_backoff_ms: 10,
+            max_backoff_ms: 50,
+            fallback_to_polling_after_attempts: 2,
+            fallback_polling_interval_ms: 20,
+            ..GrpcStreamConfig::default()
+        }
+    }
+
+    fn config_spec(spec: &str) -> ConfigSpecResponse {
+        ConfigSpecResponse {
+            spec: spec.to_string(),
+            last_updated: 123,
+            zstd_dict_id: None,
+        }
+    }
+
+    async fn setup() -> (
+        Arc<MockForwardProxy>,
+        Arc<MockSpecsListener>,
+        Arc<StatsigGrpcSpecsAdapter>,
+    ) {
+        setup_with(None, None).await
+    }
+
+    async fn setup_with(
+        grpc_stream_config: Option<GrpcStreamConfig>,
+        options: Option<&StatsigOptions>,
+    ) -> (
+        Arc<MockForwardProxy>,
+        Arc<MockSpecsListener>,
+        Arc<StatsigGrpcSpecsAdapter>,
+    ) {
+        initialize_output_logger(&Some(LogLevel::Debug), None);
+
+        let mock_proxy = MockForwardProxy::spawn().await;
+        let config = grpc_config(&mock_proxy, grpc_stream_config);
+        let adapter = Arc::new(StatsigGrpcSpecsAdapter::new("secret-key", &config, options));
         let mock_listener = Arc::new(MockSpecsListener::default());
         adapter.initialize(mock_listener.clone());
         (mock_proxy, mock_listener, adapter)
@@ -106,4 +147,158 @@ pub mod specs_adapter_tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_falls_back_to_polling_and_switches_back() {
+        let statsig_rt = StatsigRuntime::get_runtime();
+        let mock_scrapi = MockScrapi::new().await;
+        // failing polls never reach the listener, so every update it sees came from the stream
+        mock_scrapi
+            .stub(EndpointStub {
+                method: Method::GET,
+                status: 400,
+                ..EndpointStub::with_endpoint(Endpoint::DownloadConfigSpecs)
+            })
+            .await;
+        let options = StatsigOptions {
+            specs_url: Some(mock_scrapi.url_for_endpoint(Endpoint::DownloadConfigSpecs)),
+            ..StatsigOptions::new()
+        };
+        // fails at ~0ms and ~300ms, then waits 3s before a third attempt
+        let stream_config = GrpcStreamConfig {
+            initial_backoff_ms: 300,
+            backoff_multiplier: 10,
+            max_backoff_ms: 60_000,
+            backoff_jitter_ratio: 0.0,
+            ..fast_reconnect_config()
+        };
+        let (mock_proxy, mock_listener, adapter) =
+            setup_with(Some(stream_config), Some(&options)).await;
+
+        mock_proxy
+            .send_stream_update(Ok(config_spec("first_sync")))
+            .await;
+        adapter.clone().start(&statsig_rt).await.unwrap();
+        assert!(mock_listener.nullable_get_most_recent_update().is_some());
+
+        mock_proxy.stop().await;
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert_eq!(
+            mock_scrapi.times_called_for_endpoint(Endpoint::DownloadConfigSpecs),
+            0
+        );
+
+        // polling must start on the second failure, well before the third attempt
+        assert_eventually!(
+            || mock_scrapi.times_called_for_endpoint(Endpoint::DownloadConfigSpecs) > 0,
+            Duration::from_millis(2000)
+        );
+
+        mock_proxy.clone().restart().await;
+        mock_proxy
+            .send_stream_update(Ok(config_spec("recovered_sync")))
+            .await;
+        assert_eventually!(|| mock_listener
+            .nullable_get_most_recent_update()
+            .is_some_and(|update| update.data == b"recovered_sync".to_vec()));
+
+        // allow for a poll that was already in flight
+        let polls_after_recovery =
+            mock_scrapi.times_called_for_endpoint(Endpoint::DownloadConfigSpecs);
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(
+            mock_scrapi.times_called_for_endpoint(Endpoint::DownloadConfigSpecs)
+                <= polls_after_recovery + 1
+        );
+
+        adapter
+            .shutdown(Duration::from_millis(1), &statsig_rt)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reconnects_when_stream_goes_idle() {
+        let statsig_rt = StatsigRuntime::get_runtime();
+        let (mock_proxy, mock_listener, adapter) = setup_with(
+            Some(GrpcStreamConfig {
+                stream_idle_timeout_ms: Some(50),
+                ..fast_reconnect_config()
+            }),
+            None,
+        )
+        .await;
+
+        mock_proxy
+            .send_stream_update(Ok(config_spec("first_sync")))
+            .await;
+        adapter.clone().start(&statsig_rt).await.unwrap();
+        assert!(mock_listener.nullable_get_most_recent_update().is_some());
+
+        // the first stream is never closed by the proxy, only a reconnect will see this update
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        mock_proxy.clone().restart().await;
+        mock_proxy
+            .send_stream_update(Ok(config_spec("idle_sync")))
+            .await;
+
+        assert_eventually!(|| mock_listener
+            .nullable_get_most_recent_update()
+            .is_some_and(|update| update.data == b"idle_sync".to_vec()));
+
+        adapter
+            .shutdown(Duration::from_millis(1), &statsig_rt)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_logs_stream_health_metrics() {
+        let observability_client = Arc::new(MockObservabilityClient::new());
+        let mock_proxy = MockForwardProxy::spawn().await;
+        let dcs = std::fs::read_to_string("tests/data/eval_proj_dcs.json").unwrap();
+        mock_proxy.send_stream_update(Ok(config_spec(&dcs))).await;
+
+        let statsig = Statsig::new(
+            "secret-grpc-metrics",
+            Some(Arc::new(StatsigOptions {
+                spec_adapters_config: Some(vec![grpc_config(
+                    &mock_proxy,
+                    Some(fast_reconnect_config()),
+                )]),
+                event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+                observability_client: Some(
+                    Arc::downgrade(&observability_client) as Weak<dyn ObservabilityClient>
+                ),
+                ..StatsigOptions::new()
+            })),
+        );
+        statsig.initialize().await.unwrap();
+
+        mock_proxy.stop().await;
+
+        let has_dist = |name: &str| {
+            observability_client
+                .dist_calls
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|(metric, _, _)| metric == name)
+        };
+        assert_eventually!(|| has_dist("statsig.sdk.grpc_stream_uptime_ms"));
+        assert!(has_dist("statsig.sdk.grpc_update_latency_ms"));
+
+        let connected_values: Vec<f64> = observability_client
+            .gauge_calls
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(metric, _, _)| metric == "statsig.sdk.grpc_stream_connected")
+            .map(|(_, value, _)| *value)
+            .collect();
+        assert_eq!(connected_values[..2], [1.0, 0.0]);
+
+        statsig.shutdown().await.unwrap();
+    }
 }
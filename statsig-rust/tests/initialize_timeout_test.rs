@@ -2,7 +2,7 @@ mod utils;
 use std::{fs, path::PathBuf, sync::Arc};
 use utils::mock_scrapi::{Endpoint, EndpointStub, Method, MockScrapi};
 
-use statsig_rust::{Statsig, StatsigOptions, StatsigUser};
+use statsig_rust::{Statsig, StatsigErrCode, StatsigOptions, StatsigUser};
 
 const SDK_KEY: &str = "secret-key";
 async fn setup() -> MockScrapi {
@@ -44,6 +44,7 @@ async fn test_initialize_timeout() {
     assert!(res.is_err());
     let err = res.unwrap_err();
     assert!(err.to_string().contains("Initialization timed out"));
+    assert_eq!(err.code(), StatsigErrCode::Timeout);
 
     let result = statsig.get_feature_gate(&user, "public_dev_only");
 
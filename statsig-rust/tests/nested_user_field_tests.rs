@@ -0,0 +1,89 @@
+mod utils;
+
+use serde_json::{json, Value};
+use statsig_rust::{
+    ClientInitResponseOptions, DynamicValue, HashAlgorithm, Statsig, StatsigOptions, StatsigUser,
+    StatsigUserBuilder,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+async fn setup(gcir_cache_max_entries: Option<u32>) -> Statsig {
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/dcs_with_nested_user_fields.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            gcir_cache_max_entries,
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn user_with_custom(custom: Value) -> StatsigUser {
+    let custom: HashMap<String, DynamicValue> = serde_json::from_value(custom).unwrap();
+    StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .custom(Some(custom))
+        .build()
+}
+
+#[tokio::test]
+async fn test_dotted_path_condition() {
+    let statsig = setup(None).await;
+
+    let pro = user_with_custom(json!({ "subscription": { "tier": "pro" } }));
+    let free = user_with_custom(json!({ "subscription": { "tier": "free" } }));
+
+    assert!(statsig.check_gate(&pro, "test_subscription_tier"));
+    assert!(!statsig.check_gate(&free, "test_subscription_tier"));
+}
+
+#[tokio::test]
+async fn test_json_pointer_condition() {
+    let statsig = setup(None).await;
+
+    let large = user_with_custom(json!({ "org": { "plan": { "seats": 120 } } }));
+    let small = user_with_custom(json!({ "org": { "plan": { "seats": 5 } } }));
+
+    assert!(statsig.check_gate(&large, "test_org_seats"));
+    assert!(!statsig.check_gate(&small, "test_org_seats"));
+}
+
+#[tokio::test]
+async fn test_fields_used_reports_paths() {
+    let statsig = setup(None).await;
+
+    assert_eq!(
+        statsig.get_fields_needed_for_gate("test_subscription_tier"),
+        vec!["subscription.tier".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_gcir_cache_keys_on_nested_fields() {
+    let statsig = setup(Some(10)).await;
+    let options = ClientInitResponseOptions {
+        hash_algorithm: Some(HashAlgorithm::None),
+        ..Default::default()
+    };
+
+    let get_gate_value = |user: &StatsigUser| {
+        let response: Value = serde_json::from_str(
+            &statsig.get_client_init_response_with_options_as_string(user, &options),
+        )
+        .unwrap();
+        response["feature_gates"]["test_subscription_tier"]["value"].clone()
+    };
+
+    let pro = user_with_custom(json!({ "subscription": { "tier": "pro" } }));
+    let free = user_with_custom(json!({ "subscription": { "tier": "free" } }));
+
+    assert_eq!(get_gate_value(&pro), json!(true));
+    assert_eq!(get_gate_value(&free), json!(false));
+}
@@ -0,0 +1,193 @@
+#![cfg(feature = "with_opentelemetry")]
+
+mod utils;
+
+use opentelemetry::metrics::MeterProvider;
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::metrics::data::{AggregatedMetrics, Metric, MetricData, ResourceMetrics};
+use opentelemetry_sdk::metrics::{InMemoryMetricExporter, PeriodicReader, SdkMeterProvider};
+use statsig_rust::{
+    ObservabilityClient, OpenTelemetryObservabilityClient, Statsig, StatsigOptions,
+};
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+fn setup() -> (SdkMeterProvider, InMemoryMetricExporter) {
+    let exporter = InMemoryMetricExporter::default();
+    let provider = SdkMeterProvider::builder()
+        .with_reader(PeriodicReader::builder(exporter.clone()).build())
+        .build();
+
+    (provider, exporter)
+}
+
+fn collect_metrics(
+    provider: &SdkMeterProvider,
+    exporter: &InMemoryMetricExporter,
+) -> Vec<ResourceMetrics> {
+    provider.force_flush().unwrap();
+    exporter.get_finished_metrics().unwrap()
+}
+
+fn find_metric<'a>(resource_metrics: &'a [ResourceMetrics], name: &str) -> &'a Metric {
+    resource_metrics
+        .iter()
+        .flat_map(|rm| rm.scope_metrics())
+        .flat_map(|sm| sm.metrics())
+        .find(|m| m.name() == name)
+        .unwrap_or_else(|| panic!("metric {name} was not recorded"))
+}
+
+fn tags(pairs: &[(&str, &str)]) -> Option<HashMap<String, String>> {
+    Some(
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect(),
+    )
+}
+
+fn has_attribute(attributes: Vec<&KeyValue>, key: &str, value: &str) -> bool {
+    attributes
+        .iter()
+        .any(|kv| kv.key.as_str() == key && kv.value.as_str() == value)
+}
+
+#[test]
+fn test_increment_records_counter() {
+    let (provider, exporter) = setup();
+    let client = OpenTelemetryObservabilityClient::new(provider.meter("test"));
+
+    client.increment("my_counter".to_string(), 1.0, tags(&[("env", "prod")]));
+    client.increment("my_counter".to_string(), 2.0, tags(&[("env", "prod")]));
+
+    let metrics = collect_metrics(&provider, &exporter);
+    let AggregatedMetrics::F64(MetricData::Sum(sum)) = find_metric(&metrics, "my_counter").data()
+    else {
+        panic!("expected f64 sum");
+    };
+
+    let point = sum.data_points().next().unwrap();
+    assert_eq!(point.value(), 3.0);
+    assert!(has_attribute(point.attributes().collect(), "env", "prod"));
+}
+
+#[test]
+fn test_gauge_records_gauge_with_unit() {
+    let (provider, exporter) = setup();
+    let client = OpenTelemetryObservabilityClient::new(provider.meter("test"));
+
+    client.gauge("statsig.sdk.specs_age_ms".to_string(), 42.0, None);
+
+    let metrics = collect_metrics(&provider, &exporter);
+    let metric = find_metric(&metrics, "statsig.sdk.specs_age_ms");
+    assert_eq!(metric.unit(), "ms");
+
+    let AggregatedMetrics::F64(MetricData::Gauge(gauge)) = metric.data() else {
+        panic!("expected f64 gauge");
+    };
+    assert_eq!(gauge.data_points().next().unwrap().value(), 42.0);
+}
+
+#[test]
+fn test_dist_records_histogram() {
+    let (provider, exporter) = setup();
+    let client = OpenTelemetryObservabilityClient::new(provider.meter("test"));
+
+    client.dist("latency".to_string(), 10.0, None);
+    client.dist("latency".to_string(), 30.0, None);
+
+    let metrics = collect_metrics(&provider, &exporter);
+    let AggregatedMetrics::F64(MetricData::Histogram(histogram)) =
+        find_metric(&metrics, "latency").data()
+    else {
+        panic!("expected f64 histogram");
+    };
+
+    let point = histogram.data_points().next().unwrap();
+    assert_eq!(point.count(), 2);
+    assert_eq!(point.sum(), 40.0);
+}
+
+#[test]
+fn test_error_records_error_counter() {
+    let (provider, exporter) = setup();
+    let client = OpenTelemetryObservabilityClient::new(provider.meter("test"));
+
+    client.error("SpecStore".to_string(), "Failed to parse".to_string());
+
+    let metrics = collect_metrics(&provider, &exporter);
+    let AggregatedMetrics::U64(MetricData::Sum(sum)) =
+        find_metric(&metrics, "statsig.sdk.error").data()
+    else {
+        panic!("expected u64 sum");
+    };
+
+    let point = sum.data_points().next().unwrap();
+    assert_eq!(point.value(), 1);
+    assert!(has_attribute(
+        point.attributes().collect(),
+        "tag",
+        "SpecStore"
+    ));
+}
+
+#[test]
+fn test_high_cardinality_tags_opt_in() {
+    let (provider, _) = setup();
+
+    let client = OpenTelemetryObservabilityClient::new(provider.meter("test"));
+    assert_eq!(
+        client.should_enable_high_cardinality_for_this_tag("lcut".to_string()),
+        Some(false)
+    );
+
+    let client = client.with_high_cardinality_tags(&["lcut"]);
+    assert_eq!(
+        client.should_enable_high_cardinality_for_this_tag("lcut".to_string()),
+        Some(true)
+    );
+}
+
+#[tokio::test]
+async fn test_statsig_records_initialization_metric() {
+    let (provider, exporter) = setup();
+    let client = Arc::new(OpenTelemetryObservabilityClient::new(
+        provider.meter("test"),
+    ));
+
+    let statsig = Statsig::new(
+        "secret-opentelemetry-init",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            observability_client: Some(Arc::downgrade(&client) as Weak<dyn ObservabilityClient>),
+            ..StatsigOptions::new()
+        })),
+    );
+
+    statsig.initialize().await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+    let metrics = collect_metrics(&provider, &exporter);
+    let metric = find_metric(&metrics, "statsig.sdk.initialization");
+    assert_eq!(metric.unit(), "ms");
+
+    let AggregatedMetrics::F64(MetricData::Histogram(histogram)) = metric.data() else {
+        panic!("expected f64 histogram");
+    };
+
+    let point = histogram.data_points().next().unwrap();
+    assert!(has_attribute(
+        point.attributes().collect(),
+        "success",
+        "true"
+    ));
+    assert!(!point.attributes().any(|kv| kv.key.as_str() == "lcut"));
+
+    statsig.shutdown().await.unwrap();
+}
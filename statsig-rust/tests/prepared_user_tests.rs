@@ -0,0 +1,209 @@
+mod utils;
+
+use statsig_rust::{
+    dyn_value, DynamicValue, GeoIpProvider, ParsedUserAgent, ScopedCustomFields, Statsig,
+    StatsigOptions, StatsigUser, StatsigUserBuilder, UserAgentParserProvider,
+};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+#[derive(Default)]
+struct CountingUserAgentParser {
+    parse_count: AtomicUsize,
+}
+
+impl UserAgentParserProvider for CountingUserAgentParser {
+    fn parse(&self, _user_agent: &str) -> Option<ParsedUserAgent> {
+        self.parse_count.fetch_add(1, Ordering::SeqCst);
+
+        Some(ParsedUserAgent {
+            os_name: Some("iOS".to_string()),
+            os_version: Some("17.0.0".to_string()),
+            browser_name: Some("Chrome Mobile".to_string()),
+            browser_version: Some("118.0.0".to_string()),
+        })
+    }
+}
+
+#[derive(Default)]
+struct CountingGeoIpProvider {
+    lookup_count: AtomicUsize,
+}
+
+impl GeoIpProvider for CountingGeoIpProvider {
+    fn get_value(&self, _ip: &str, field: &str) -> Option<DynamicValue> {
+        self.lookup_count.fetch_add(1, Ordering::SeqCst);
+
+        match field {
+            "country" => Some(dyn_value!("CA")),
+            _ => None,
+        }
+    }
+}
+
+async fn setup(
+    ua_parser: Arc<CountingUserAgentParser>,
+    geo_ip_provider: Arc<CountingGeoIpProvider>,
+) -> Statsig {
+    setup_with_options(StatsigOptions {
+        user_agent_parser_provider: Some(ua_parser),
+        // disable the shared cache so only the prepared user can avoid re-parsing
+        user_agent_cache_max_entries: Some(0),
+        geo_ip_provider: Some(geo_ip_provider),
+        ..StatsigOptions::new()
+    })
+    .await
+}
+
+async fn setup_with_options(options: StatsigOptions) -> Statsig {
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            ..options
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn make_user() -> StatsigUser {
+    let mut custom = std::collections::HashMap::new();
+    custom.insert("level".to_string(), dyn_value!(9));
+
+    StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .user_agent(Some("AcmeApp/4.2 (build 1234)".to_string()))
+        .ip(Some("1.2.3.4".to_string()))
+        .custom(Some(custom))
+        .build()
+}
+
+#[tokio::test]
+async fn test_prepared_user_matches_plain_user() {
+    let statsig = setup(Arc::default(), Arc::default()).await;
+    let user = make_user();
+    let prepared = statsig.prepare_user(&user);
+
+    for gate in ["test_ua", "test_country", "test_custom_gt", "test_public"] {
+        assert_eq!(
+            statsig.check_gate(&prepared, gate),
+            statsig.check_gate(&user, gate),
+            "{gate}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_prepared_user_derives_values_once() {
+    let ua_parser = Arc::new(CountingUserAgentParser::default());
+    let geo_ip_provider = Arc::new(CountingGeoIpProvider::default());
+    let statsig = setup(ua_parser.clone(), geo_ip_provider.clone()).await;
+
+    let prepared = statsig.prepare_user(&make_user());
+    for _ in 0..5 {
+        assert!(statsig.check_gate(&prepared, "test_ua"));
+        assert!(statsig.check_gate(&prepared, "test_country"));
+    }
+
+    assert_eq!(ua_parser.parse_count.load(Ordering::SeqCst), 1);
+    assert_eq!(geo_ip_provider.lookup_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_changing_a_clone_drops_prepared_values() {
+    let ua_parser = Arc::new(CountingUserAgentParser::default());
+    let statsig = setup(ua_parser.clone(), Arc::default()).await;
+
+    let prepared = statsig.prepare_user(&make_user());
+    statsig.check_gate(&prepared, "test_ua");
+
+    let mut changed = prepared.user().clone();
+    changed.set_user_agent("AcmeApp/5.0 (build 2000)");
+
+    let mut changed_data = prepared.user().clone();
+    changed_data.get_user_data_mut().user_agent = Some("AcmeApp/5.0 (build 3000)".into());
+
+    let mut assigned_data = prepared.user().clone();
+    let mut data = (*assigned_data.data).clone();
+    data.user_agent = Some("AcmeApp/5.0 (build 4000)".into());
+    assigned_data.data = Arc::new(data);
+
+    statsig.check_gate(&changed, "test_ua");
+    statsig.check_gate(&changed_data, "test_ua");
+    statsig.check_gate(&assigned_data, "test_ua");
+    statsig.check_gate(&prepared, "test_ua");
+
+    assert_eq!(ua_parser.parse_count.load(Ordering::SeqCst), 4);
+}
+
+#[tokio::test]
+async fn test_prepared_lookups_match_plain_user_for_every_gate() {
+    let statsig = setup_with_options(StatsigOptions {
+        global_custom_fields: Some(HashMap::from([
+            ("newUser".to_string(), dyn_value!(true)),
+            ("level".to_string(), dyn_value!(1)),
+        ])),
+        ..StatsigOptions::new()
+    })
+    .await;
+
+    let users = [
+        make_user(),
+        StatsigUserBuilder::new_with_custom_ids(HashMap::from([
+            ("companyid", "statsig"),
+            ("StableID", "a-stable-id"),
+        ]))
+        .email(Some("daniel@statsig.com".to_string()))
+        .custom(Some(HashMap::from([
+            ("foo".to_string(), dyn_value!("bar")),
+            ("PLAYS_LEAGUE".to_string(), dyn_value!(true)),
+            (
+                "bigint".to_string(),
+                dyn_value!(9_223_372_036_854_775_807_i64),
+            ),
+        ])))
+        .build(),
+        StatsigUserBuilder::new_with_user_id("".to_string())
+            .app_version(Some("1.2.3".to_string()))
+            .locale(Some("en_US".to_string()))
+            .build()
+            .with_scoped_custom_fields(&ScopedCustomFields::new(HashMap::from([
+                ("Level", dyn_value!(9)),
+                ("device_model", dyn_value!("iPhone12,1")),
+            ]))),
+    ];
+
+    for user in &users {
+        let prepared = statsig.prepare_user(user);
+        for gate in statsig.get_feature_gate_list() {
+            assert_eq!(
+                statsig.check_gate(&prepared, &gate),
+                statsig.check_gate(user, &gate),
+                "{gate}"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_prepared_values_are_not_shared_across_instances() {
+    let first_parser = Arc::new(CountingUserAgentParser::default());
+    let second_parser = Arc::new(CountingUserAgentParser::default());
+    let first = setup(first_parser.clone(), Arc::default()).await;
+    let second = setup(second_parser.clone(), Arc::default()).await;
+
+    let prepared = first.prepare_user(&make_user());
+    for _ in 0..3 {
+        first.check_gate(&prepared, "test_ua");
+        second.check_gate(&prepared, "test_ua");
+    }
+
+    assert_eq!(first_parser.parse_count.load(Ordering::SeqCst), 1);
+    assert_eq!(second_parser.parse_count.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,96 @@
+mod utils;
+
+use serde_json::Value;
+use statsig_rust::{
+    dyn_value, DynamicValue, ScopedCustomFields, Statsig, StatsigOptions, StatsigUser,
+    StatsigUserBuilder,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+// test_custom_gt passes when "level" > 8
+async fn setup(
+    global_custom_fields: Option<HashMap<String, DynamicValue>>,
+) -> (Statsig, Arc<MockEventLoggingAdapter>) {
+    let logging_adapter = Arc::new(MockEventLoggingAdapter::new());
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(logging_adapter.clone()),
+            global_custom_fields,
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    (statsig, logging_adapter)
+}
+
+fn tenant(level: i64) -> ScopedCustomFields {
+    ScopedCustomFields::new(HashMap::from([
+        ("level", dyn_value!(level)),
+        ("plan", dyn_value!("enterprise")),
+    ]))
+}
+
+#[tokio::test]
+async fn test_scoped_fields_are_used_in_conditions() {
+    let (statsig, _) = setup(None).await;
+    let user = StatsigUser::with_user_id("a-user");
+
+    assert!(!statsig.check_gate(&user, "test_custom_gt"));
+    assert!(statsig.check_gate(
+        &user.with_scoped_custom_fields(&tenant(9)),
+        "test_custom_gt"
+    ));
+    assert!(!statsig.check_gate(
+        &user.with_scoped_custom_fields(&tenant(1)),
+        "test_custom_gt"
+    ));
+}
+
+#[tokio::test]
+async fn test_user_custom_takes_precedence_over_scoped() {
+    let (statsig, _) = setup(None).await;
+    let user = StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .custom(Some(HashMap::from([("level".to_string(), dyn_value!(1))])))
+        .scoped_custom_fields(Some(tenant(9)))
+        .build();
+
+    assert!(!statsig.check_gate(&user, "test_custom_gt"));
+}
+
+#[tokio::test]
+async fn test_scoped_takes_precedence_over_global() {
+    let (statsig, _) = setup(Some(HashMap::from([("level".to_string(), dyn_value!(1))]))).await;
+    let user = StatsigUser::with_user_id("a-user");
+
+    assert!(!statsig.check_gate(&user, "test_custom_gt"));
+    assert!(statsig.check_gate(
+        &user.with_scoped_custom_fields(&tenant(9)),
+        "test_custom_gt"
+    ));
+}
+
+#[tokio::test]
+async fn test_scoped_fields_are_logged_as_custom() {
+    let (statsig, logging_adapter) = setup(Some(HashMap::from([(
+        "region".to_string(),
+        dyn_value!("us-west"),
+    )])))
+    .await;
+    let user = StatsigUser::with_user_id("a-user").with_scoped_custom_fields(&tenant(9));
+
+    statsig.check_gate(&user, "test_custom_gt");
+    statsig.flush_events().await;
+
+    let event = logging_adapter.force_get_first_event();
+    let custom = &event["user"]["custom"];
+    assert_eq!(custom["plan"], Value::from("enterprise"));
+    assert_eq!(custom["level"], Value::from(9));
+    assert_eq!(custom["region"], Value::from("us-west"));
+}
@@ -0,0 +1,308 @@
+mod utils;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{json, Value};
+use statsig_rust::{
+    SpecStore, SpecsAdapter, SpecsSource, SpecsUpdate, SpecsUpdateListener, Statsig, StatsigErr,
+    StatsigOptions, StatsigRuntime, StatsigSharedSpecsAdapter, StatsigUser,
+};
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use utils::helpers::load_contents;
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+
+const DCS_TIME: &str = "\"time\": 1729873603830";
+
+/// Stands in for the network: sends whatever it is given, counting how often it was started.
+#[derive(Default)]
+struct ManualSpecsAdapter {
+    initial_data: Option<String>,
+    start_count: AtomicUsize,
+    listener: RwLock<Option<Arc<dyn SpecsUpdateListener>>>,
+}
+
+impl ManualSpecsAdapter {
+    fn with_data(data: String) -> Self {
+        Self {
+            initial_data: Some(data),
+            ..Self::default()
+        }
+    }
+
+    fn push(&self, data: String) -> Result<(), StatsigErr> {
+        let listener = self.listener.read().unwrap().clone().unwrap();
+        listener.did_receive_specs_update(SpecsUpdate {
+            data: data.into_bytes(),
+            source: SpecsSource::Network,
+            received_at: Utc::now().timestamp_millis() as u64,
+            source_api: None,
+        })
+    }
+}
+
+#[async_trait]
+impl SpecsAdapter for ManualSpecsAdapter {
+    fn initialize(&self, listener: Arc<dyn SpecsUpdateListener>) {
+        *self.listener.write().unwrap() = Some(listener);
+    }
+
+    async fn start(
+        self: Arc<Self>,
+        _statsig_runtime: &Arc<StatsigRuntime>,
+    ) -> Result<(), StatsigErr> {
+        self.start_count.fetch_add(1, Ordering::SeqCst);
+        match &self.initial_data {
+            Some(data) => self.push(data.clone()),
+            None => Ok(()),
+        }
+    }
+
+    async fn schedule_background_sync(
+        self: Arc<Self>,
+        _statsig_runtime: &Arc<StatsigRuntime>,
+    ) -> Result<(), StatsigErr> {
+        Ok(())
+    }
+
+    async fn shutdown(
+        &self,
+        _timeout: Duration,
+        _statsig_runtime: &Arc<StatsigRuntime>,
+    ) -> Result<(), StatsigErr> {
+        Ok(())
+    }
+
+    fn get_type_name(&self) -> String {
+        stringify!(ManualSpecsAdapter).to_string()
+    }
+}
+
+struct Worker {
+    statsig: Statsig,
+    adapter: Arc<StatsigSharedSpecsAdapter>,
+    source: Arc<ManualSpecsAdapter>,
+}
+
+fn setup_path(test_name: &str) -> String {
+    let test_path = format!("/tmp/{test_name}");
+    if std::path::Path::new(&test_path).exists() {
+        fs::remove_dir_all(&test_path).unwrap();
+    }
+    fs::create_dir_all(&test_path).unwrap();
+    format!("{test_path}/specs")
+}
+
+async fn start_worker(path: &str, source: ManualSpecsAdapter) -> Worker {
+    let source = Arc::new(source);
+    let adapter = Arc::new(
+        StatsigSharedSpecsAdapter::with_source_adapter(path, source.clone())
+            .with_poll_interval_ms(10),
+    );
+
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(adapter.clone()),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            enable_zero_copy_specs: Some(true),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+
+    Worker {
+        statsig,
+        adapter,
+        source,
+    }
+}
+
+fn get_header_text(worker: &Worker) -> String {
+    let user = StatsigUser::with_user_id("a-user");
+    let config = worker
+        .statsig
+        .get_dynamic_config(&user, "test_custom_config");
+    config.get_string("header_text", String::new())
+}
+
+#[tokio::test]
+async fn test_follower_loads_specs_published_by_leader() {
+    let path = setup_path("test_follower_loads_specs_published_by_leader");
+    let leader = start_worker(
+        &path,
+        ManualSpecsAdapter::with_data(load_contents("eval_proj_dcs.json")),
+    )
+    .await;
+    let follower = start_worker(&path, ManualSpecsAdapter::default()).await;
+
+    assert!(leader.adapter.is_leader());
+    assert!(!follower.adapter.is_leader());
+    assert_eq!(follower.source.start_count.load(Ordering::SeqCst), 0);
+
+    let user = StatsigUser::with_user_id("a-user");
+    assert!(follower.statsig.check_gate(&user, "test_public"));
+    assert_eq!(get_header_text(&follower), get_header_text(&leader));
+}
+
+#[tokio::test]
+async fn test_followers_swap_on_new_generation() {
+    let path = setup_path("test_followers_swap_on_new_generation");
+    let leader = start_worker(
+        &path,
+        ManualSpecsAdapter::with_data(load_contents("eval_proj_dcs.json")),
+    )
+    .await;
+    let follower = start_worker(&path, ManualSpecsAdapter::default()).await;
+    assert_eq!(get_header_text(&follower), "old user test");
+
+    let updated = load_contents("eval_proj_dcs.json")
+        .replace(DCS_TIME, "\"time\": 1729873603831")
+        .replace("\"old user test\"", "\"shared update\"");
+    leader.source.push(updated).unwrap();
+
+    assert_eventually_eq!(|| get_header_text(&follower), "shared update");
+    assert!(fs::metadata(format!("{path}.2")).is_ok());
+}
+
+#[tokio::test]
+async fn test_no_update_responses_are_not_published() {
+    let path = setup_path("test_no_update_responses_are_not_published");
+    let leader = start_worker(
+        &path,
+        ManualSpecsAdapter::with_data(load_contents("eval_proj_dcs.json")),
+    )
+    .await;
+
+    leader
+        .source
+        .push(r#"{"has_updates": false}"#.to_string())
+        .unwrap();
+    leader
+        .source
+        .push(load_contents("eval_proj_dcs.json"))
+        .unwrap();
+
+    assert!(fs::metadata(format!("{path}.1")).is_ok());
+    assert!(fs::metadata(format!("{path}.2")).is_err());
+}
+
+#[tokio::test]
+async fn test_follower_takes_over_when_leader_shuts_down() {
+    let path = setup_path("test_follower_takes_over_when_leader_shuts_down");
+    let leader = start_worker(
+        &path,
+        ManualSpecsAdapter::with_data(load_contents("eval_proj_dcs.json")),
+    )
+    .await;
+    let follower = start_worker(&path, ManualSpecsAdapter::default()).await;
+    assert!(!follower.adapter.is_leader());
+
+    leader.statsig.shutdown().await.unwrap();
+    assert!(!leader.adapter.is_leader());
+
+    assert_eventually!(|| follower.adapter.is_leader());
+    assert_eventually_eq!(|| follower.source.start_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_followers_attaching_after_a_delta_load_complete_specs() {
+    let path = setup_path("test_followers_attaching_after_a_delta_load_complete_specs");
+    let leader = start_worker(
+        &path,
+        ManualSpecsAdapter::with_data(load_contents("eval_proj_dcs.json")),
+    )
+    .await;
+
+    let dcs: Value = serde_json::from_str(&load_contents("eval_proj_dcs.json")).unwrap();
+    let mut config = dcs["dynamic_configs"]["test_custom_config"].clone();
+    config["rules"][0]["returnValue"]["header_text"] = json!("delta update");
+    let delta = json!({
+        "is_delta": true,
+        "has_updates": true,
+        "base_time": 1729873603830_u64,
+        "time": 1729873603831_u64,
+        "dynamic_configs": { "test_custom_config": config },
+    });
+    leader.source.push(delta.to_string()).unwrap();
+
+    let follower = start_worker(&path, ManualSpecsAdapter::default()).await;
+
+    let user = StatsigUser::with_user_id("a-user");
+    assert!(follower.statsig.check_gate(&user, "test_public"));
+    assert_eq!(get_header_text(&follower), "delta update");
+}
+
+#[tokio::test]
+async fn test_leader_recreates_an_incomplete_header() {
+    let path = setup_path("test_leader_recreates_an_incomplete_header");
+    fs::write(&path, [0u8; 64]).unwrap(); // as left by a leader that crashed while creating it
+
+    let leader = start_worker(
+        &path,
+        ManualSpecsAdapter::with_data(load_contents("eval_proj_dcs.json")),
+    )
+    .await;
+    let follower = start_worker(&path, ManualSpecsAdapter::default()).await;
+
+    assert!(leader.adapter.is_leader());
+    assert_eq!(get_header_text(&follower), "old user test");
+}
+
+#[tokio::test]
+async fn test_followers_reattach_to_a_replaced_header() {
+    let path = setup_path("test_followers_reattach_to_a_replaced_header");
+    let _leader = start_worker(
+        &path,
+        ManualSpecsAdapter::with_data(load_contents("eval_proj_dcs.json")),
+    )
+    .await;
+    let follower = start_worker(&path, ManualSpecsAdapter::default()).await;
+    assert_eq!(get_header_text(&follower), "old user test");
+
+    // a new header counting from the same generation, renamed into place like create_header does
+    let updated = load_contents("eval_proj_dcs.json")
+        .replace(DCS_TIME, "\"time\": 1729873603831")
+        .replace("\"old user test\"", "\"replaced header\"");
+    fs::write(format!("{path}.1.tmp"), updated).unwrap();
+    fs::rename(format!("{path}.1.tmp"), format!("{path}.1")).unwrap();
+
+    let mut header = [0u8; 64];
+    header[0..8].copy_from_slice(b"1CEPSGTS"); // MAGIC, little endian
+    header[8..16].copy_from_slice(&1u64.to_le_bytes());
+    fs::write(format!("{path}.tmp"), header).unwrap();
+    fs::rename(format!("{path}.tmp"), &path).unwrap();
+
+    assert_eventually_eq!(|| get_header_text(&follower), "replaced header");
+}
+
+#[tokio::test]
+async fn test_followers_wait_for_the_init_timeout() {
+    let path = setup_path("test_followers_wait_for_the_init_timeout");
+    let leader = start_worker(&path, ManualSpecsAdapter::default()).await;
+    assert!(leader.adapter.is_leader());
+
+    let options = StatsigOptions {
+        init_timeout_ms: Some(100),
+        ..StatsigOptions::new()
+    };
+    let follower = Arc::new(StatsigSharedSpecsAdapter::new(
+        "secret-key",
+        &path,
+        Some(&options),
+    ));
+    follower.initialize(Arc::new(SpecStore::new(
+        "secret-key",
+        "secret-key".to_string(),
+        StatsigRuntime::get_runtime(),
+        None,
+    )));
+
+    let started_at = Instant::now();
+    let result = follower.start(&StatsigRuntime::get_runtime()).await;
+
+    assert!(result.is_err());
+    assert!(started_at.elapsed() < Duration::from_millis(2000));
+}
@@ -0,0 +1,228 @@
+mod utils;
+
+use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+use serde_json::Value;
+use statsig_rust::{
+    SpecsAdapter, SpecsExportFilter, Statsig, StatsigBootstrapSpecsAdapter, StatsigOptions,
+    StatsigUser, StatsigUserBuilder,
+};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+async fn setup_statsig(sdk_key: &str, specs_adapter: Arc<dyn SpecsAdapter>) -> Statsig {
+    let statsig = Statsig::new(
+        sdk_key,
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(specs_adapter),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+async fn setup(sdk_key: &str) -> Statsig {
+    let adapter = Arc::new(MockSpecsAdapter::with_data("tests/data/eval_proj_dcs.json"));
+    setup_statsig(sdk_key, adapter).await
+}
+
+async fn bootstrap_from(sdk_key: &str, export: String) -> Statsig {
+    setup_statsig(sdk_key, Arc::new(StatsigBootstrapSpecsAdapter::new(export))).await
+}
+
+fn set(values: &[&str]) -> Option<HashSet<String>> {
+    Some(values.iter().map(|v| (*v).to_string()).collect())
+}
+
+fn only_gates(gates: &[&str]) -> SpecsExportFilter {
+    SpecsExportFilter {
+        feature_gate_filter: set(gates),
+        experiment_filter: set(&[]),
+        dynamic_config_filter: set(&[]),
+        layer_filter: set(&[]),
+        param_store_filter: set(&[]),
+    }
+}
+
+fn names(export: &Value, section: &str) -> HashSet<String> {
+    export[section]
+        .as_object()
+        .map(|o| o.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+fn users() -> Vec<StatsigUser> {
+    vec![
+        StatsigUser::with_user_id("a_user"),
+        StatsigUserBuilder::new_with_user_id("an_engineer".to_string())
+            .email(Some("daniel@statsig.com".to_string()))
+            .build(),
+        StatsigUserBuilder::new_with_user_id("another_user".to_string())
+            .email(Some("someone@example.com".to_string()))
+            .build(),
+    ]
+}
+
+#[tokio::test]
+async fn test_export_includes_nested_gates_and_segments() {
+    let statsig = setup("secret-export-nested").await;
+
+    let export = statsig
+        .export_specs(&only_gates(&["double_nested_gates"]))
+        .unwrap();
+    let json: Value = serde_json::from_str(&export).unwrap();
+
+    assert_eq!(
+        names(&json, "feature_gates"),
+        set(&[
+            "double_nested_gates",
+            "test_segment_condition",
+            "segment:best_engineers",
+            "segment:prod",
+        ])
+        .unwrap()
+    );
+    assert!(names(&json, "dynamic_configs").is_empty());
+    assert!(names(&json, "layer_configs").is_empty());
+    assert!(json.get("checksum").is_none());
+
+    let bootstrapped = bootstrap_from("secret-export-nested-bootstrap", export).await;
+    for user in users() {
+        assert_eq!(
+            statsig.check_gate(&user, "double_nested_gates"),
+            bootstrapped.check_gate(&user, "double_nested_gates")
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_export_includes_layer_delegates() {
+    let statsig = setup("secret-export-layer").await;
+
+    let export = statsig
+        .export_specs(&SpecsExportFilter {
+            layer_filter: set(&["test_layer_with_holdout"]),
+            ..only_gates(&[])
+        })
+        .unwrap();
+    let json: Value = serde_json::from_str(&export).unwrap();
+
+    assert_eq!(
+        names(&json, "layer_configs"),
+        set(&["test_layer_with_holdout"]).unwrap()
+    );
+    assert_eq!(
+        names(&json, "dynamic_configs"),
+        set(&["test_exp_with_layers_targeting_and_holdout"]).unwrap()
+    );
+    assert_eq!(
+        names(&json, "feature_gates"),
+        set(&["layer_holdout", "test_public"]).unwrap()
+    );
+    assert_eq!(
+        names(&json, "experiment_to_layer"),
+        set(&["test_exp_with_layers_targeting_and_holdout"]).unwrap()
+    );
+
+    let bootstrapped = bootstrap_from("secret-export-layer-bootstrap", export).await;
+    for user in users() {
+        let expected = statsig.get_layer(&user, "test_layer_with_holdout");
+        let actual = bootstrapped.get_layer(&user, "test_layer_with_holdout");
+        assert_eq!(expected.rule_id, actual.rule_id);
+        assert_eq!(expected.__value, actual.__value);
+    }
+}
+
+#[tokio::test]
+async fn test_export_includes_param_store_references() {
+    let statsig = setup("secret-export-param-store").await;
+
+    let export = statsig
+        .export_specs(&SpecsExportFilter {
+            param_store_filter: set(&["gateParamsStore"]),
+            ..only_gates(&[])
+        })
+        .unwrap();
+    let json: Value = serde_json::from_str(&export).unwrap();
+
+    assert_eq!(
+        names(&json, "param_stores"),
+        set(&["gateParamsStore"]).unwrap()
+    );
+
+    let gates = names(&json, "feature_gates");
+    assert!(gates.contains("test_heavy_nesting"));
+    assert!(gates.contains("test_disabled_gate"));
+    assert!(gates.contains("segment:heavy_nested_segment_4"));
+    assert!(!gates.contains("test_public"));
+}
+
+#[tokio::test]
+async fn test_export_filters_accept_globs() {
+    let statsig = setup("secret-export-globs").await;
+
+    let export = statsig
+        .export_specs(&SpecsExportFilter {
+            dynamic_config_filter: set(&["operating_*"]),
+            ..only_gates(&[])
+        })
+        .unwrap();
+    let json: Value = serde_json::from_str(&export).unwrap();
+
+    assert_eq!(
+        names(&json, "dynamic_configs"),
+        set(&["operating_system_config"]).unwrap()
+    );
+    assert_eq!(names(&json, "feature_gates"), set(&["test_email"]).unwrap());
+}
+
+#[tokio::test]
+async fn test_gate_filter_alone_exports_only_gates() {
+    let statsig = setup("secret-export-gates-only").await;
+
+    let export = statsig
+        .export_specs(&SpecsExportFilter {
+            feature_gate_filter: set(&["test_public"]),
+            ..SpecsExportFilter::default()
+        })
+        .unwrap();
+    let json: Value = serde_json::from_str(&export).unwrap();
+
+    assert_eq!(
+        names(&json, "feature_gates"),
+        set(&["test_public"]).unwrap()
+    );
+    assert!(names(&json, "dynamic_configs").is_empty());
+    assert!(names(&json, "layer_configs").is_empty());
+    assert!(names(&json, "param_stores").is_empty());
+}
+
+#[tokio::test]
+async fn test_default_filter_exports_everything() {
+    let statsig = setup("secret-export-all").await;
+
+    let export = statsig.export_specs(&SpecsExportFilter::default()).unwrap();
+    let json: Value = serde_json::from_str(&export).unwrap();
+
+    let original: Value =
+        serde_json::from_str(&std::fs::read_to_string("tests/data/eval_proj_dcs.json").unwrap())
+            .unwrap();
+
+    for section in [
+        "feature_gates",
+        "dynamic_configs",
+        "layer_configs",
+        "condition_map",
+        "experiment_to_layer",
+        "param_stores",
+    ] {
+        assert_eq!(
+            names(&json, section),
+            names(&original, section),
+            "{section} differs"
+        );
+    }
+    assert_eq!(json["time"], original["time"]);
+}
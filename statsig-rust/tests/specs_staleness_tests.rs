@@ -0,0 +1,163 @@
+mod utils;
+
+use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use crate::utils::mock_observability_client::MockObservabilityClient;
+use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+use statsig_rust::{
+    sdk_event_emitter::SdkEvent, ObservabilityClient, StaleSpecsPolicy, Statsig, StatsigOptions,
+    StatsigUser,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+fn create_statsig(sdk_key: &str, options: StatsigOptions) -> Statsig {
+    Statsig::new(
+        sdk_key,
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            ..options
+        })),
+    )
+}
+
+#[tokio::test]
+async fn test_fresh_specs_evaluate_normally() {
+    let statsig = create_statsig(
+        "secret-staleness-fresh",
+        StatsigOptions {
+            max_specs_age_ms: Some(60_000),
+            stale_specs_policy: Some(StaleSpecsPolicy::ReturnDefaults),
+            ..StatsigOptions::new()
+        },
+    );
+    statsig.initialize().await.unwrap();
+
+    let gate = statsig.get_feature_gate(&StatsigUser::with_user_id("a_user"), "test_public");
+
+    assert!(gate.value);
+    assert_eq!(gate.details.reason, "Bootstrap:Recognized");
+}
+
+#[tokio::test]
+async fn test_stale_specs_return_defaults() {
+    let statsig = create_statsig(
+        "secret-staleness-defaults",
+        StatsigOptions {
+            max_specs_age_ms: Some(10),
+            stale_specs_policy: Some(StaleSpecsPolicy::ReturnDefaults),
+            ..StatsigOptions::new()
+        },
+    );
+    statsig.initialize().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let gate = statsig.get_feature_gate(&StatsigUser::with_user_id("a_user"), "test_public");
+
+    assert!(!gate.value);
+    assert_eq!(gate.details.reason, "Bootstrap:Stale");
+    assert!(gate.details.lcut.is_some());
+}
+
+#[tokio::test]
+async fn test_stale_specs_continue_with_warning() {
+    let statsig = create_statsig(
+        "secret-staleness-warning",
+        StatsigOptions {
+            max_specs_age_ms: Some(10),
+            ..StatsigOptions::new()
+        },
+    );
+    statsig.initialize().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+
+    let gate = statsig.get_feature_gate(&StatsigUser::with_user_id("a_user"), "test_public");
+
+    assert!(gate.value);
+    assert_eq!(gate.details.reason, "Bootstrap:Recognized");
+}
+
+#[tokio::test]
+async fn test_stale_specs_emit_alert_once() {
+    let statsig = create_statsig(
+        "secret-staleness-alert",
+        StatsigOptions {
+            max_specs_age_ms: Some(50),
+            stale_specs_policy: Some(StaleSpecsPolicy::EmitAlert),
+            ..StatsigOptions::new()
+        },
+    );
+
+    let alert_count = Arc::new(AtomicU64::new(0));
+    let alert_count_clone = alert_count.clone();
+    statsig.subscribe(SdkEvent::SPECS_STALE, move |event| {
+        if let SdkEvent::SpecsStale { age_ms, .. } = event {
+            assert!(age_ms > 50);
+            alert_count_clone.fetch_add(1, Ordering::SeqCst);
+        }
+    });
+
+    statsig.initialize().await.unwrap();
+
+    assert_eventually!(|| alert_count.load(Ordering::SeqCst) == 1);
+
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(alert_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_specs_age_gauge() {
+    let observability_client = Arc::new(MockObservabilityClient::new());
+    let weak_client = Arc::downgrade(&observability_client) as Weak<dyn ObservabilityClient>;
+
+    let statsig = create_statsig(
+        "secret-staleness-gauge",
+        StatsigOptions {
+            max_specs_age_ms: Some(200),
+            observability_client: Some(weak_client),
+            ..StatsigOptions::new()
+        },
+    );
+    statsig.initialize().await.unwrap();
+
+    assert_eventually!(|| observability_client
+        .gauge_calls
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(name, _, _)| name == "statsig.sdk.specs_age_ms"));
+}
+
+#[tokio::test]
+async fn test_specs_age_gauge_without_max_age() {
+    let observability_client = Arc::new(MockObservabilityClient::new());
+    let weak_client = Arc::downgrade(&observability_client) as Weak<dyn ObservabilityClient>;
+
+    let statsig = create_statsig(
+        "secret-staleness-gauge-no-max",
+        StatsigOptions {
+            observability_client: Some(weak_client),
+            ..StatsigOptions::new()
+        },
+    );
+    statsig.initialize().await.unwrap();
+
+    // checked every 10s when no max age is set
+    assert_eventually!(
+        || observability_client
+            .gauge_calls
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|(name, _, _)| name == "statsig.sdk.specs_age_ms"),
+        Duration::from_secs(12)
+    );
+    assert!(!statsig
+        .get_feature_gate(&StatsigUser::with_user_id("a-user"), "test_public")
+        .details
+        .reason
+        .contains("Stale"));
+}
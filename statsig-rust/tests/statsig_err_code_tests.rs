@@ -0,0 +1,95 @@
+use statsig_rust::{
+    networking::NetworkError, StatsigErr, StatsigErrCategory, StatsigErrCode, StatsigErrContext,
+};
+
+#[test]
+fn test_network_error_code_and_context() {
+    let err = StatsigErr::NetworkError(NetworkError::RequestNotRetryable(
+        "https://api.statsig.com/v1/log_event".to_string(),
+        403,
+        "Forbidden".to_string(),
+    ));
+
+    assert_eq!(err.code(), StatsigErrCode::NetworkRequestNotRetryable);
+    assert_eq!(err.category(), StatsigErrCategory::Network);
+    assert_eq!(
+        err.context(),
+        StatsigErrContext {
+            url: Some("https://api.statsig.com/v1/log_event".to_string()),
+            status_code: Some(403),
+            adapter_name: None,
+        }
+    );
+}
+
+#[test]
+fn test_network_error_without_response_has_no_status_code() {
+    let err = StatsigErr::NetworkError(NetworkError::RetriesExhausted(
+        "https://api.statsig.com/v2/download_config_specs".to_string(),
+        0,
+        3,
+        "connection refused".to_string(),
+    ));
+
+    assert_eq!(err.code(), StatsigErrCode::NetworkRetriesExhausted);
+    assert_eq!(err.context().status_code, None);
+}
+
+#[test]
+fn test_error_context_map_matches_serialized_keys() {
+    let err = StatsigErr::NetworkError(NetworkError::RequestFailed(
+        "https://api.statsig.com/v1/log_event".to_string(),
+        500,
+        "Internal Server Error".to_string(),
+    ));
+
+    let map = err.context().to_map();
+    let json = serde_json::to_value(err.context()).unwrap();
+
+    assert_eq!(map.len(), json.as_object().unwrap().len());
+    assert_eq!(map["url"], json["url"]);
+    assert_eq!(map["status_code"], "500");
+}
+
+#[test]
+fn test_error_info_serialization() {
+    let err = StatsigErr::SpecsAdapterSkipPoll("StatsigHttpSpecsAdapter".to_string());
+    let json = serde_json::to_value(err.to_info()).unwrap();
+
+    assert_eq!(json["code"], "SPECS_ADAPTER_SKIP_POLL");
+    assert_eq!(
+        json["code_value"],
+        StatsigErrCode::SpecsAdapterSkipPoll.as_u16()
+    );
+    assert_eq!(json["category"], "adapter");
+    assert_eq!(json["context"]["adapter_name"], "StatsigHttpSpecsAdapter");
+    assert!(json["context"].get("url").is_none());
+}
+
+#[test]
+fn test_codes_are_grouped_by_category() {
+    let codes = [
+        StatsigErrCode::Custom,
+        StatsigErrCode::LockFailure,
+        StatsigErrCode::ThreadFailure,
+        StatsigErrCode::UnstartedAdapter,
+        StatsigErrCode::NetworkRequestFailed,
+        StatsigErrCode::JsonParseFailure,
+        StatsigErrCode::GzipFailure,
+        StatsigErrCode::FileFailure,
+        StatsigErrCode::LogEventFailure,
+        StatsigErrCode::EvaluationFailure,
+        StatsigErrCode::InitializationFailure,
+        StatsigErrCode::Timeout,
+        StatsigErrCode::ShutdownFailure,
+        StatsigErrCode::ClientInitResponseFailure,
+    ];
+
+    for code in codes {
+        let siblings: Vec<_> = codes
+            .iter()
+            .filter(|c| c.as_u16() / 100 == code.as_u16() / 100)
+            .collect();
+        assert!(siblings.iter().all(|c| c.category() == code.category()));
+    }
+}
@@ -38,6 +38,7 @@ fn setup(sdk_key: &str) -> (Statsig, StatsigUser, mpsc::Receiver<ReceivedEvent>)
                 result.spec_name = layer.name.to_string();
                 result.eval_reason = layer.details.reason.to_string();
             }
+            SdkEvent::SpecsStale { .. } => {}
         }
 
         tx.send(result).unwrap();
@@ -0,0 +1,96 @@
+mod utils;
+
+use crate::utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use crate::utils::mock_specs_adapter::MockSpecsAdapter;
+use statsig_rust::{LookupLoadState, SpecsSource, Statsig, StatsigOptions, StatsigUser};
+use std::sync::Arc;
+
+fn create_statsig(options: StatsigOptions) -> Statsig {
+    Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            ..options
+        })),
+    )
+}
+
+#[tokio::test]
+async fn test_health_before_initialize() {
+    let statsig = create_statsig(StatsigOptions::new());
+
+    let health = statsig.health();
+
+    assert!(!health.is_ready);
+    assert!(!health.is_initialized);
+    assert_eq!(health.specs.source, SpecsSource::Uninitialized);
+    assert_eq!(health.specs.lcut, None);
+    assert_eq!(health.specs.age_ms, None);
+}
+
+#[tokio::test]
+async fn test_health_after_initialize() {
+    let statsig = create_statsig(StatsigOptions {
+        wait_for_country_lookup_init: Some(true),
+        disable_user_agent_parsing: Some(true),
+        ..StatsigOptions::new()
+    });
+    statsig.initialize().await.unwrap();
+
+    let health = statsig.health();
+
+    assert!(health.is_ready);
+    assert_eq!(health.specs.source, SpecsSource::Bootstrap);
+    assert!(health.specs.lcut.is_some());
+    assert!(health.specs.age_ms.is_some());
+    assert!(health.specs.received_at.is_some());
+
+    assert!(!health.id_lists.enabled);
+    assert!(!health.id_lists.is_current);
+
+    assert_eq!(health.country_lookup, LookupLoadState::Loaded);
+    assert_eq!(health.user_agent_parser, LookupLoadState::Disabled);
+
+    assert!(health.runtime.active_task_count > 0);
+    assert_eq!(
+        health.runtime.active_task_count,
+        health.runtime.active_tasks.len()
+    );
+}
+
+#[tokio::test]
+async fn test_health_reports_event_queue_depth() {
+    let statsig = create_statsig(StatsigOptions::new());
+    statsig.initialize().await.unwrap();
+
+    for i in 0..10 {
+        let user = StatsigUser::with_user_id(format!("user-{i}"));
+        statsig.log_event(&user, "my_event", None, None);
+    }
+
+    assert!(statsig.health().event_logger.queue_depth >= 10);
+
+    statsig.flush_events().await;
+
+    let health = statsig.health();
+    assert_eq!(health.event_logger.queue_depth, 0);
+    assert_eq!(health.event_logger.dropped_events_count, 0);
+    assert_eq!(health.event_logger.consecutive_flush_failures, 0);
+    assert!(health.event_logger.flush_interval_ms > 0);
+}
+
+#[tokio::test]
+async fn test_health_is_serializable() {
+    let statsig = create_statsig(StatsigOptions::new());
+    statsig.initialize().await.unwrap();
+
+    let json = serde_json::to_value(statsig.health()).unwrap();
+
+    assert_eq!(json["is_ready"], true);
+    assert_eq!(json["specs"]["source"], "Bootstrap");
+    assert!(json["event_logger"]["queue_depth"].is_number());
+    assert!(json["runtime"]["active_tasks"].is_array());
+}
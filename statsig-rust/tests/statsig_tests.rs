@@ -1,5 +1,6 @@
 mod utils;
 use more_asserts::assert_ge;
+use serde_json::json;
 use std::{collections::HashMap, env, sync::Arc, thread::sleep, time::Duration};
 use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
 use utils::mock_specs_adapter::MockSpecsAdapter;
@@ -91,6 +92,37 @@ async fn test_get_experiment_by_group_name() {
     assert_eq!(experiment.value["value"], "control");
 }
 
+#[tokio::test]
+async fn test_get_cmab_ranked_groups() {
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/dcs_with_cmab.json",
+            ))),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+
+    let user = StatsigUser::with_user_id("a-user");
+    let groups = statsig.get_cmab_ranked_groups(&user, "test_cmab");
+
+    let names: Vec<&str> = groups.iter().map(|g| g.variant_name.as_str()).collect();
+    assert_eq!(names, vec!["Green", "Blue"]);
+    assert_eq!(groups[0].score, 2.0);
+    assert_eq!(groups[0].rule_id.as_str(), "green_group_id");
+    assert_eq!(groups[0].cmab_name, "test_cmab");
+    assert_eq!(
+        groups[0].value.as_ref().unwrap()["button_color"],
+        json!("green")
+    );
+
+    assert!(statsig
+        .get_cmab_ranked_groups(&user, "not_a_cmab")
+        .is_empty());
+}
+
 #[tokio::test]
 async fn test_gcir() {
     let user = StatsigUserBuilder::new_with_user_id("a-user".to_string())
@@ -1,5 +1,6 @@
 use statsig_rust::{
-    evaluation::dynamic_string::DynamicString, user::StatsigUserInternal, DynamicValue, StatsigUser,
+    evaluation::dynamic_string::DynamicString, user::StatsigUserInternal, DynamicValue,
+    StatsigUser, StatsigUserBuilder,
 };
 
 #[test]
@@ -15,3 +16,83 @@ fn test_get_user_value() {
 // todo: test get_unit_id
 // todo: test get_value_from_environment
 // todo: test to_loggable
+
+fn user_with_nested_custom() -> StatsigUser {
+    let custom = serde_json::from_value(serde_json::json!({
+        "subscription": { "tier": "pro" },
+        "org": { "plan": { "seats": 50 }, "a/b": { "c~d": true } },
+        "teams": [{ "name": "core" }, { "name": "infra" }],
+        "literal.key": "top-level",
+    }))
+    .unwrap();
+
+    StatsigUserBuilder::new_with_user_id("user1".to_string())
+        .custom(Some(custom))
+        .build()
+}
+
+fn get_value(user: &StatsigUser, field: &str) -> Option<DynamicValue> {
+    let user_internal = StatsigUserInternal::new(user, None);
+    user_internal
+        .get_user_value(&Some(DynamicString::from(field.to_string())))
+        .cloned()
+}
+
+#[test]
+fn test_get_user_value_dotted_path() {
+    let user = user_with_nested_custom();
+    assert_eq!(
+        get_value(&user, "subscription.tier"),
+        Some(DynamicValue::from("pro"))
+    );
+    assert_eq!(
+        get_value(&user, "org.plan.seats"),
+        Some(DynamicValue::from(50))
+    );
+    assert_eq!(
+        get_value(&user, "teams.1.name"),
+        Some(DynamicValue::from("infra"))
+    );
+    assert_eq!(get_value(&user, "org.plan.missing"), None);
+    assert_eq!(get_value(&user, "teams.9.name"), None);
+}
+
+#[test]
+fn test_get_user_value_json_pointer() {
+    let user = user_with_nested_custom();
+    assert_eq!(
+        get_value(&user, "/org/plan/seats"),
+        Some(DynamicValue::from(50))
+    );
+    assert_eq!(
+        get_value(&user, "/org/a~1b/c~0d"),
+        Some(DynamicValue::from(true))
+    );
+    assert_eq!(
+        get_value(&user, "/teams/0/name"),
+        Some(DynamicValue::from("core"))
+    );
+}
+
+#[test]
+fn test_get_user_value_prefers_top_level_keys() {
+    let user = user_with_nested_custom();
+    assert_eq!(
+        get_value(&user, "literal.key"),
+        Some(DynamicValue::from("top-level"))
+    );
+}
+
+#[test]
+fn test_get_user_value_path_in_private_attributes() {
+    let private_attributes =
+        serde_json::from_value(serde_json::json!({ "billing": { "plan": "annual" } })).unwrap();
+    let user = StatsigUserBuilder::new_with_user_id("user1".to_string())
+        .private_attributes(Some(private_attributes))
+        .build();
+
+    assert_eq!(
+        get_value(&user, "billing.plan"),
+        Some(DynamicValue::from("annual"))
+    );
+}
@@ -0,0 +1,126 @@
+mod utils;
+
+use statsig_rust::{
+    ParsedUserAgent, Statsig, StatsigOptions, StatsigUser, StatsigUserBuilder,
+    UserAgentParserProvider, UserAgentParserType,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+const IOS_CHROME_UA: &str = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) CriOS/118.0.5993.92 Mobile/15E148 Safari/604.1";
+
+#[derive(Default)]
+struct InHouseAppParser {
+    parse_count: AtomicUsize,
+}
+
+impl UserAgentParserProvider for InHouseAppParser {
+    fn parse(&self, user_agent: &str) -> Option<ParsedUserAgent> {
+        self.parse_count.fetch_add(1, Ordering::SeqCst);
+
+        if !user_agent.starts_with("AcmeApp/") {
+            return Some(ParsedUserAgent::default());
+        }
+
+        Some(ParsedUserAgent {
+            os_name: Some("iOS".to_string()),
+            os_version: Some("17.0.0".to_string()),
+            browser_name: Some("Chrome Mobile".to_string()),
+            browser_version: Some("118.0.0".to_string()),
+        })
+    }
+}
+
+async fn setup(options: StatsigOptions) -> Statsig {
+    let statsig = Statsig::new(
+        "secret-key",
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            wait_for_user_agent_init: Some(true),
+            ..options
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn user_with_ua(user_agent: &str) -> StatsigUser {
+    StatsigUserBuilder::new_with_user_id("a-user".to_string())
+        .user_agent(Some(user_agent.to_string()))
+        .build()
+}
+
+#[tokio::test]
+async fn test_provider_resolves_ua_based_conditions() {
+    let provider = Arc::new(InHouseAppParser::default());
+    let statsig = setup(StatsigOptions {
+        user_agent_parser_provider: Some(provider.clone()),
+        ..StatsigOptions::new()
+    })
+    .await;
+
+    assert!(statsig.check_gate(&user_with_ua("AcmeApp/4.2 (build 1234)"), "test_ua"));
+    assert!(!statsig.check_gate(&user_with_ua("SomeOtherApp/1.0"), "test_ua"));
+}
+
+#[tokio::test]
+async fn test_parse_results_are_cached_across_evaluations() {
+    let provider = Arc::new(InHouseAppParser::default());
+    let statsig = setup(StatsigOptions {
+        user_agent_parser_provider: Some(provider.clone()),
+        ..StatsigOptions::new()
+    })
+    .await;
+
+    let user = user_with_ua("AcmeApp/4.2 (build 1234)");
+    for _ in 0..5 {
+        assert!(statsig.check_gate(&user, "test_ua"));
+        statsig.check_gate(&user, "test_many_rules");
+    }
+
+    assert_eq!(provider.parse_count.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_cache_can_be_disabled() {
+    let provider = Arc::new(InHouseAppParser::default());
+    let statsig = setup(StatsigOptions {
+        user_agent_parser_provider: Some(provider.clone()),
+        user_agent_cache_max_entries: Some(0),
+        ..StatsigOptions::new()
+    })
+    .await;
+
+    let user = user_with_ua("AcmeApp/4.2 (build 1234)");
+    statsig.check_gate(&user, "test_ua");
+    statsig.check_gate(&user, "test_ua");
+
+    assert_eq!(provider.parse_count.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_experimental_parser_from_options() {
+    let statsig = setup(StatsigOptions {
+        user_agent_parser_type: Some(UserAgentParserType::Experimental),
+        ..StatsigOptions::new()
+    })
+    .await;
+
+    assert!(statsig.check_gate(&user_with_ua(IOS_CHROME_UA), "test_ua"));
+}
+
+#[tokio::test]
+async fn test_third_party_parser_from_options() {
+    let statsig = setup(StatsigOptions {
+        user_agent_parser_type: Some(UserAgentParserType::ThirdParty),
+        ..StatsigOptions::new()
+    })
+    .await;
+
+    assert!(statsig.check_gate(&user_with_ua(IOS_CHROME_UA), "test_ua"));
+}
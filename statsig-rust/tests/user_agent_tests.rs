@@ -2,13 +2,20 @@ mod utils;
 
 use more_asserts::assert_gt;
 use statsig_rust::{
-    evaluation::{dynamic_string::DynamicString, user_agent_parsing::UserAgentParser},
+    evaluation::{
+        dynamic_string::DynamicString,
+        user_agent_parsing::{CachedUserAgentParser, UserAgentParserType},
+    },
     user::StatsigUserInternal,
-    StatsigUser,
+    StatsigOptions, StatsigUser,
 };
 use utils::helpers::load_contents;
 
 lazy_static::lazy_static! {
+    static ref PARSER: CachedUserAgentParser = CachedUserAgentParser::new(&StatsigOptions {
+        user_agent_parser_type: Some(UserAgentParserType::ThirdParty),
+        ..StatsigOptions::new()
+    });
     static ref TEST_CASES: Vec<TestCase> = load_contents("test_user_agents.txt")
         .lines()
         .skip(1) // skip header
@@ -24,7 +31,7 @@ const BROWSER_VERSION_THRESHOLD: f64 = 0.91;
 
 #[test]
 fn test_user_agent_parser_os_name() {
-    UserAgentParser::load_parser();
+    PARSER.load_parser();
 
     let mut hit = 0;
     let mut miss = 0;
@@ -45,7 +52,7 @@ fn test_user_agent_parser_os_name() {
 
 #[test]
 fn test_user_agent_parser_os_version() {
-    UserAgentParser::load_parser();
+    PARSER.load_parser();
 
     let mut hit = 0;
     let mut miss = 0;
@@ -66,7 +73,7 @@ fn test_user_agent_parser_os_version() {
 
 #[test]
 fn test_user_agent_parser_browser_name() {
-    UserAgentParser::load_parser();
+    PARSER.load_parser();
 
     let mut hit = 0;
     let mut miss = 0;
@@ -87,7 +94,7 @@ fn test_user_agent_parser_browser_name() {
 
 #[test]
 fn test_user_agent_parser_browser_version() {
-    UserAgentParser::load_parser();
+    PARSER.load_parser();
 
     let mut hit = 0;
     let mut miss = 0;
@@ -114,11 +121,8 @@ fn extract_field_from_user_agent(user_agent: &str, field: &str) -> Option<String
     let mut dummy_override_reason = None;
     let field = DynamicString::from(field.to_string());
 
-    let result = UserAgentParser::get_value_from_user_agent(
-        &user_internal,
-        &Some(field),
-        &mut dummy_override_reason,
-    );
+    let result =
+        PARSER.get_value_from_user_agent(&user_internal, &Some(field), &mut dummy_override_reason);
 
     match result {
         Some(value) => value.string_value.map(|s| s.value),
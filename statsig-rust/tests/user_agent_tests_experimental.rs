@@ -1,16 +1,21 @@
 mod utils;
 
-use std::env;
-
 use more_asserts::assert_gt;
 use statsig_rust::{
-    evaluation::{dynamic_string::DynamicString, user_agent_parsing::UserAgentParser},
+    evaluation::{
+        dynamic_string::DynamicString,
+        user_agent_parsing::{CachedUserAgentParser, UserAgentParserType},
+    },
     user::StatsigUserInternal,
-    StatsigUser,
+    StatsigOptions, StatsigUser,
 };
 use utils::helpers::load_contents;
 
 lazy_static::lazy_static! {
+    static ref PARSER: CachedUserAgentParser = CachedUserAgentParser::new(&StatsigOptions {
+        user_agent_parser_type: Some(UserAgentParserType::Experimental),
+        ..StatsigOptions::new()
+    });
     static ref TEST_CASES: Vec<TestCase> = load_contents("test_user_agents.txt")
         .lines()
         .skip(1) // skip header
@@ -44,8 +49,6 @@ fn log_failure(test_case: &TestCase, field: &str, expected: Option<&str>, got: O
 
 #[test]
 fn test_user_agent_parser_os_name() {
-    env::set_var("STATSIG_USE_EXPERIMENTAL_UAPARSER", "true");
-
     let mut hit = 0;
     let mut miss = 0;
 
@@ -71,8 +74,6 @@ fn test_user_agent_parser_os_name() {
 
 #[test]
 fn test_user_agent_parser_os_version() {
-    env::set_var("STATSIG_USE_EXPERIMENTAL_UAPARSER", "true");
-
     let mut hit = 0;
     let mut miss = 0;
 
@@ -98,8 +99,6 @@ fn test_user_agent_parser_os_version() {
 
 #[test]
 fn test_user_agent_parser_browser_name() {
-    env::set_var("STATSIG_USE_EXPERIMENTAL_UAPARSER", "true");
-
     let mut hit = 0;
     let mut miss = 0;
 
@@ -125,8 +124,6 @@ fn test_user_agent_parser_browser_name() {
 
 #[test]
 fn test_user_agent_parser_browser_version() {
-    env::set_var("STATSIG_USE_EXPERIMENTAL_UAPARSER", "true");
-
     let mut hit = 0;
     let mut miss = 0;
 
@@ -158,11 +155,8 @@ fn extract_field_from_user_agent(user_agent: &str, field: &str) -> Option<String
     let mut dummy_override_reason = None;
     let field = DynamicString::from(field.to_string());
 
-    let result = UserAgentParser::get_value_from_user_agent(
-        &user_internal,
-        &Some(field),
-        &mut dummy_override_reason,
-    );
+    let result =
+        PARSER.get_value_from_user_agent(&user_internal, &Some(field), &mut dummy_override_reason);
 
     match result {
         Some(value) => value.string_value.map(|s| s.value),
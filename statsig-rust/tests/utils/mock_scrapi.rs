@@ -12,11 +12,12 @@ use uuid;
 use uuid::Uuid;
 use wiremock::{
     http::Method as WiremockMethod,
-    matchers::{method, path, path_regex},
+    matchers::{method, path, path_regex, query_param_is_missing},
     Mock, MockBuilder, MockServer, Request, ResponseTemplate,
 };
 
 #[allow(clippy::upper_case_acronyms)]
+#[derive(Clone)]
 pub enum Method {
     GET,
     POST,
@@ -94,11 +95,21 @@ impl MockScrapi {
     }
 
     pub async fn stub(&self, stub: EndpointStub) {
+        let builder = Mock::given(method(stub.method.clone()));
+        self.mount_stub(builder, stub).await;
+    }
+
+    /// Only matches requests that do not set `param`. Takes precedence over stubs mounted later.
+    pub async fn stub_without_query_param(&self, stub: EndpointStub, param: &str) {
+        let builder = Mock::given(method(stub.method.clone())).and(query_param_is_missing(param));
+        self.mount_stub(builder, stub).await;
+    }
+
+    async fn mount_stub(&self, mut builder: MockBuilder, stub: EndpointStub) {
         let logged_events = self.logged_events.clone();
         let no_diagnostics_logged_events = self.no_diagnostics_logged_events.clone();
         let reqs = self.requests.clone();
 
-        let mut builder = Mock::given(method(stub.method));
         builder = self.set_endpoint_matcher(builder, &stub.endpoint);
 
         builder
@@ -0,0 +1,134 @@
+mod utils;
+
+use statsig_rust::specs_response::specs_payload::SpecsPayload;
+use statsig_rust::{
+    SpecStore, SpecsSource, Statsig, StatsigLocalFileSpecsAdapter, StatsigOptions, StatsigRuntime,
+    StatsigUser,
+};
+use std::fs;
+use std::sync::Arc;
+use utils::helpers::load_contents;
+use utils::mock_event_logging_adapter::MockEventLoggingAdapter;
+use utils::mock_specs_adapter::MockSpecsAdapter;
+
+const SDK_KEY: &str = "server-local-specs-test";
+const SPECS_FILE_NAME: &str = "3099846163_specs.json"; // djb2(SDK_KEY)_specs.json
+
+async fn setup_zero_copy_from_file(test_name: &str) -> Statsig {
+    let test_path = format!("/tmp/{test_name}");
+    if std::path::Path::new(&test_path).exists() {
+        fs::remove_dir_all(&test_path).unwrap();
+    }
+    fs::create_dir_all(&test_path).unwrap();
+    fs::write(
+        format!("{test_path}/{SPECS_FILE_NAME}"),
+        load_contents("eval_proj_dcs.json"),
+    )
+    .unwrap();
+
+    let adapter = StatsigLocalFileSpecsAdapter::new(SDK_KEY, &test_path, None, false, true);
+    let statsig = Statsig::new(
+        SDK_KEY,
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(adapter)),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            enable_zero_copy_specs: Some(true),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+async fn setup_copied() -> Statsig {
+    let statsig = Statsig::new(
+        SDK_KEY,
+        Some(Arc::new(StatsigOptions {
+            specs_adapter: Some(Arc::new(MockSpecsAdapter::with_data(
+                "tests/data/eval_proj_dcs.json",
+            ))),
+            event_logging_adapter: Some(Arc::new(MockEventLoggingAdapter::new())),
+            ..StatsigOptions::new()
+        })),
+    );
+    statsig.initialize().await.unwrap();
+    statsig
+}
+
+fn create_spec_store(sdk_key: &str, zero_copy: bool) -> SpecStore {
+    SpecStore::new(
+        sdk_key,
+        sdk_key.to_string(),
+        StatsigRuntime::get_runtime(),
+        None,
+    )
+    .with_zero_copy_specs(zero_copy)
+}
+
+fn dcs_payload_with_time(time: u64) -> Arc<SpecsPayload> {
+    let dcs = load_contents("eval_proj_dcs.json")
+        .replace("\"time\": 1729873603830", &format!("\"time\": {time}"));
+    Arc::new(SpecsPayload::from(dcs.into_bytes()))
+}
+
+#[tokio::test]
+async fn test_zero_copy_values_match_copied_values() {
+    let zero_copy = setup_zero_copy_from_file("test_zero_copy_values_match_copied_values").await;
+    let copied = setup_copied().await;
+
+    let user = StatsigUser::with_user_id("a-user");
+    let configs = copied.get_dynamic_config_list();
+    assert_eq!(zero_copy.get_dynamic_config_list().len(), configs.len());
+
+    for name in configs {
+        assert_eq!(
+            zero_copy.get_dynamic_config(&user, &name).value,
+            copied.get_dynamic_config(&user, &name).value,
+            "{name}"
+        );
+    }
+
+    for name in copied.get_experiment_list() {
+        assert_eq!(
+            zero_copy.get_experiment(&user, &name).value,
+            copied.get_experiment(&user, &name).value,
+            "{name}"
+        );
+    }
+}
+
+#[test]
+fn test_payload_is_retained_until_replaced() {
+    let spec_store = create_spec_store("secret-key-zero-copy-retained", true);
+
+    let first = dcs_payload_with_time(1729873603830);
+    let first_ref = Arc::downgrade(&first);
+    spec_store
+        .set_values_from_payload(first, SpecsSource::Network, None)
+        .unwrap();
+    assert!(first_ref.upgrade().is_some());
+
+    spec_store
+        .set_values_from_payload(
+            dcs_payload_with_time(1729873603831),
+            SpecsSource::Network,
+            None,
+        )
+        .unwrap();
+    assert!(first_ref.upgrade().is_none());
+    assert_eq!(spec_store.data.read().values().time, 1729873603831);
+}
+
+#[test]
+fn test_payload_is_not_retained_when_disabled() {
+    let spec_store = create_spec_store("secret-key-zero-copy-disabled", false);
+
+    let payload = dcs_payload_with_time(1729873603830);
+    let payload_ref = Arc::downgrade(&payload);
+    spec_store
+        .set_values_from_payload(payload, SpecsSource::Network, None)
+        .unwrap();
+
+    assert!(payload_ref.upgrade().is_none());
+    assert_eq!(spec_store.data.read().values().time, 1729873603830);
+}
@@ -0,0 +1,222 @@
+#!/usr/bin/env python3
+"""
+Builds statsig-rust/resources/ip6_supalite.table from RIR delegation files
+(delegated-<rir>-extended-latest from the APNIC, ARIN, AFRINIC, LACNIC and RIPE NCC ftp servers).
+
+    python3 tools/python/build_ip6_table.py delegated-*-extended-latest
+
+When the RIR servers cannot be reached, the snapshot of the same five files embedded in the MIT
+licensed ip2geo crate (embed/ipentries.bin, see ipdb/sources.txt in the crate) can be used instead:
+
+    python3 tools/python/build_ip6_table.py --ip2geo ip2geo-0.1.0/embed/ipentries.bin
+
+The registry, serial date and sha256 of every input file are written to ip6_table_sources.txt next
+to this script, so the committed table can be traced back to the files it was built from.
+
+The table has the same layout as ip_supalite.table, except that ranges are counted in /48 blocks:
+a list of two character country codes terminated by "**", followed by (block count, country code
+index) pairs. Counts below 240 are a single byte, larger counts are prefixed with 242, 243, 244 or
+245 and followed by 2, 3, 4 or 8 little endian bytes.
+"""
+
+import hashlib
+import ipaddress
+import os
+import sys
+
+BLOCK_BITS = 48
+TOTAL_BLOCKS = 1 << BLOCK_BITS
+UNKNOWN = "--"
+
+OUTPUT = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    "../../statsig-rust/resources/ip6_supalite.table",
+)
+SOURCES = os.path.join(os.path.dirname(os.path.abspath(__file__)), "ip6_table_sources.txt")
+SOURCES_HEADER = """\
+Sources of statsig-rust/resources/ip6_supalite.table, written by build_ip6_table.py.
+Review each registry's terms for its statistics files before committing a refreshed table.
+
+"""
+
+
+def read_allocations(paths):
+    allocations = []
+    for path in paths:
+        with open(path, "r") as file:
+            for line in file:
+                parts = line.strip().split("|")
+                if len(parts) < 7 or parts[2] != "ipv6" or parts[1] in ("", "*"):
+                    continue
+                if parts[6] not in ("allocated", "assigned"):
+                    continue
+
+                prefix_len = min(int(parts[4]), BLOCK_BITS)
+                start = int(ipaddress.IPv6Address(parts[3])) >> (128 - BLOCK_BITS)
+                allocations.append((start, 1 << (BLOCK_BITS - prefix_len), parts[1].upper()))
+
+    return sorted(allocations)
+
+
+def read_ip2geo_allocations(path):
+    # ipentries.bin is a MessagePack array of [start address, country code] pairs sorted by start.
+    # Each entry covers the addresses up to the next start. Gaps between allocations are entries
+    # with an empty code, reserved and available space is "ZZ".
+    with open(path, "rb") as file:
+        entries = MessagePackReader(file.read()).read()
+
+    ipv6 = [
+        (int.from_bytes(bytes(address["V6"]), "big"), cc)
+        for address, cc in entries
+        if "V6" in address
+    ]
+
+    shift = 128 - BLOCK_BITS
+    allocations = []
+    for index, (start, cc) in enumerate(ipv6):
+        if len(cc) != 2 or cc == "ZZ":
+            continue
+
+        end = ipv6[index + 1][0] if index + 1 < len(ipv6) else 1 << 128
+        start_block = start >> shift
+        end_block = max(-(-end >> shift), start_block + 1)  # ranges below /48 keep their block
+        allocations.append((start_block, end_block - start_block, cc.upper()))
+
+    return sorted(allocations)
+
+
+class MessagePackReader:
+    # only the types rmp_serde writes for ip2geo's Vec<IPEntry>
+    def __init__(self, data):
+        self.data = data
+        self.pos = 0
+
+    def take(self, n):
+        value = self.data[self.pos : self.pos + n]
+        self.pos += n
+        return value
+
+    def read(self):
+        marker = self.take(1)[0]
+        if marker <= 0x7F:
+            return marker
+        if 0x80 <= marker <= 0x8F:
+            return {self.read(): self.read() for _ in range(marker & 0x0F)}
+        if 0x90 <= marker <= 0x9F:
+            return [self.read() for _ in range(marker & 0x0F)]
+        if 0xA0 <= marker <= 0xBF:
+            return self.take(marker & 0x1F).decode("ascii")
+        if marker == 0xCC:
+            return self.take(1)[0]
+        if marker in (0xDC, 0xDD):
+            width = 2 if marker == 0xDC else 4
+            count = int.from_bytes(self.take(width), "big")
+            return [self.read() for _ in range(count)]
+        raise ValueError(f"unsupported MessagePack marker {marker:#x} at {self.pos - 1}")
+
+
+def to_ranges(allocations):
+    # (block count, country code) pairs covering the whole address space
+    ranges = []
+    cursor = 0
+
+    def push(count, cc):
+        if count <= 0:
+            return
+        if ranges and ranges[-1][1] == cc:
+            ranges[-1] = (ranges[-1][0] + count, cc)
+        else:
+            ranges.append((count, cc))
+
+    for start, count, cc in allocations:
+        end = start + count
+        if end <= cursor:
+            continue
+        start = max(start, cursor)
+        push(start - cursor, UNKNOWN)
+        push(end - start, cc)
+        cursor = end
+
+    push(TOTAL_BLOCKS - cursor, UNKNOWN)
+    return ranges
+
+
+def encode_count(count):
+    if count < 240:
+        return bytes([count])
+    for marker, width in ((242, 2), (243, 3), (244, 4), (245, 8)):
+        if count < (1 << (width * 8)):
+            return bytes([marker]) + count.to_bytes(width, "little")
+    raise ValueError(f"count too large: {count}")
+
+
+def encode(ranges):
+    codes = ["/~", UNKNOWN]
+    for _, cc in ranges:
+        if cc not in codes:
+            codes.append(cc)
+
+    out = bytearray("".join(codes).encode("ascii"))
+    out += b"**"
+    for count, cc in ranges:
+        out += encode_count(count)
+        out.append(codes.index(cc))
+
+    return bytes(out)
+
+
+def describe_source(path):
+    # the version line is "version|registry|serial|records|startdate|enddate|UTCoffset"
+    with open(path, "rb") as file:
+        data = file.read()
+
+    registry, serial = "unknown", "unknown"
+    for line in data.decode("utf-8", "replace").splitlines():
+        if line.startswith("#") or not line.strip():
+            continue
+        parts = line.split("|")
+        if len(parts) >= 3:
+            registry, serial = parts[1], parts[2]
+        break
+
+    digest = hashlib.sha256(data).hexdigest()
+    return f"{os.path.basename(path)}\tregistry={registry}\tserial={serial}\tsha256={digest}"
+
+
+def describe_ip2geo_source(path):
+    with open(path, "rb") as file:
+        digest = hashlib.sha256(file.read()).hexdigest()
+
+    # keeps the crate version, e.g. ip2geo-0.1.0/embed/ipentries.bin
+    name = "/".join(os.path.abspath(path).split(os.sep)[-3:])
+    return (
+        f"{name}\tip2geo snapshot of the AFRINIC, APNIC, ARIN, LACNIC and RIPE NCC"
+        f" delegated-*-extended-latest files\tsha256={digest}"
+    )
+
+
+def main():
+    args = sys.argv[1:]
+    if args[:1] == ["--ip2geo"] and len(args) == 2:
+        allocations = read_ip2geo_allocations(args[1])
+        sources = [describe_ip2geo_source(args[1])]
+    elif args and not args[0].startswith("--"):
+        allocations = read_allocations(args)
+        sources = [describe_source(path) for path in sorted(args)]
+    else:
+        sys.exit(__doc__)
+
+    ranges = to_ranges(allocations)
+    with open(OUTPUT, "wb") as file:
+        file.write(encode(ranges))
+
+    with open(SOURCES, "w") as file:
+        file.write(SOURCES_HEADER)
+        for source in sources:
+            file.write(source + "\n")
+
+    print(f"Wrote {len(ranges)} ranges to {os.path.normpath(OUTPUT)}")
+
+
+if __name__ == "__main__":
+    main()